@@ -576,27 +576,51 @@ export interface RawCacheOptions {
   version: string
 }
 
+/**
+ * Flags set on the info of the copied assets, `minimized` keeps the minimizers away from
+ * already minified files
+ */
+export interface RawCopyAssetInfo {
+  immutable?: boolean
+  minimized?: boolean
+  development?: boolean
+  hotModuleReplacement?: boolean
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
   ignore?: Array<string>
+  followSymbolicLinks?: boolean
 }
 
 export interface RawCopyPattern {
   from: string
-  to?: string
+  to?: string | ((ctx: RawCopyToFnCtx) => string | Promise<string>)
   context?: string
   toType?: string
   noErrorOnMissing: boolean
+  info?: RawCopyAssetInfo
   force: boolean
   priority: number
   globOptions: RawCopyGlobOptions
+  transform?: (ctx: RawCopyTransformCtx) => string | Buffer | Promise<string | Buffer>
 }
 
 export interface RawCopyRspackPluginOptions {
   patterns: Array<RawCopyPattern>
 }
 
+export interface RawCopyToFnCtx {
+  context: string
+  absoluteFilename: string
+}
+
+export interface RawCopyTransformCtx {
+  input: Buffer
+  absoluteFilename: string
+}
+
 export interface RawCrossOriginLoading {
   type: "bool" | "string"
   stringPayload?: string
@@ -742,6 +766,12 @@ export interface RawIncrementalRebuild {
   emitAsset: boolean
 }
 
+export interface RawJavascriptParserOptions {
+  dynamicImportMode?: "lazy" | "eager"
+  worker?: Array<string>
+  exprContextCritical?: boolean
+}
+
 export interface RawLibraryAuxiliaryComment {
   root?: string
   commonjs?: string
@@ -833,12 +863,14 @@ export interface RawOptimizationOptions {
   splitChunks?: RawSplitChunksOptions
   moduleIds: string
   chunkIds: string
+  workerChunkIds: string
   removeAvailableModules: boolean
   removeEmptyChunks: boolean
   sideEffects: string
   usedExports: string
   providedExports: boolean
   realContentHash: boolean
+  exportsUsageReport?: string
 }
 
 export interface RawOptions {
@@ -900,8 +932,9 @@ export interface RawOutputOptions {
 }
 
 export interface RawParserOptions {
-  type: "asset" | "unknown"
+  type: "asset" | "javascript" | "unknown"
   asset?: RawAssetParserOptions
+  javascript?: RawJavascriptParserOptions
 }
 
 export interface RawPluginImportConfig {
@@ -1023,15 +1056,25 @@ export interface RawStyleConfig {
 export interface RawSwcJsMinimizerRspackPluginOptions {
   passes: number
   dropConsole: boolean
+  dropDebugger: boolean
   keepClassNames: boolean
   keepFnNames: boolean
   comments: "all" | "some" | "false"
-  asciiOnly: boolean
+  asciiOnly: "true" | "false" | "smart"
+  beautify: boolean
   pureFuncs: Array<string>
   extractComments?: string
   test?: RawSwcJsMinimizerRules
   include?: RawSwcJsMinimizerRules
   exclude?: RawSwcJsMinimizerRules
+  externalSources?: RawSwcJsMinimizerRules
+  parallelism?: number
+  banner?: string
+  topLevel?: boolean
+  reserved: Array<string>
+  inlineSourcesContentMaxBytes?: number
+  ecma?: number
+  downlevel: boolean
 }
 
 export interface RawSwcJsMinimizerRule {
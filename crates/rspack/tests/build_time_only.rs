@@ -0,0 +1,45 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::{AddDependencyPlugin, TestDependency};
+use rspack_core::{rspack_sources::Source, Compiler};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test]
+async fn build_time_only_dependency_is_not_bundled() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/build-time-only");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.push(Box::new(AddDependencyPlugin {
+    issuer: "index.js",
+    dependency: TestDependency {
+      build_time_only: true,
+      ..TestDependency::new("./macro.js", "build time only")
+    },
+    built: Default::default(),
+  }));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let compilation = &compiler.compilation;
+  assert!(compilation.get_stats().get_errors().is_empty());
+
+  // resolved and built like any other module
+  assert!(compilation
+    .module_graph
+    .modules()
+    .keys()
+    .any(|identifier| identifier.ends_with("macro.js")));
+  // but neither required nor rendered
+  for (filename, asset) in compilation.assets() {
+    let content = asset.get_source().expect("should have source").source();
+    assert!(
+      !content.contains("macro.js"),
+      "{filename} requires macro.js"
+    );
+    assert!(
+      !content.contains("BUILD_TIME_ONLY"),
+      "{filename} renders macro.js"
+    );
+  }
+}
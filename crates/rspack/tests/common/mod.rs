@@ -0,0 +1,163 @@
+//! Scaffolding shared by the tests which add dependencies a parser doesn't produce
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::BoxSource, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ErrorSpan, GenerateContext, Module, ModuleDependency, ParseContext, ParseResult,
+  ParserAndGenerator, Plugin, SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+
+/// Resolved and built like an import, the flags are returned by the matching [Dependency] methods
+#[derive(Debug, Clone)]
+pub struct TestDependency {
+  id: DependencyId,
+  request: String,
+  dependency_type: DependencyType,
+  pub cacheable: bool,
+  pub build_time_only: bool,
+}
+
+impl TestDependency {
+  pub fn new(request: &str, dependency_type: &str) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request: request.to_string(),
+      dependency_type: DependencyType::Custom(dependency_type.into()),
+      cacheable: true,
+      build_time_only: false,
+    }
+  }
+}
+
+impl Dependency for TestDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &self.dependency_type
+  }
+
+  fn cacheable(&self) -> bool {
+    self.cacheable
+  }
+
+  fn build_time_only(&self) -> bool {
+    self.build_time_only
+  }
+}
+
+impl ModuleDependency for TestDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for TestDependency {}
+
+/// Adds a copy of the dependency with a new id to the parse result of the wrapped parser
+#[derive(Debug)]
+struct WithDependency {
+  inner: Option<Box<dyn ParserAndGenerator>>,
+  dependency: TestDependency,
+}
+
+impl WithDependency {
+  fn inner(&self) -> &dyn ParserAndGenerator {
+    self.inner.as_deref().expect("should wrap a parser")
+  }
+}
+
+impl ParserAndGenerator for WithDependency {
+  fn source_types(&self) -> &[SourceType] {
+    self.inner().source_types()
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    let (mut result, diagnostics) = self
+      .inner
+      .as_mut()
+      .expect("should wrap a parser")
+      .parse(parse_context)?
+      .split_into_parts();
+    result.dependencies.push(Box::new(TestDependency {
+      id: DependencyId::new(),
+      ..self.dependency.clone()
+    }));
+    Ok(result.with_diagnostic(diagnostics))
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    self.inner().size(module, source_type)
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    self.inner().generate(source, module, generate_context)
+  }
+}
+
+/// Adds the dependency to the modules whose resource path ends with `issuer` and records the
+/// modules it builds, only modules which are not restored from the cache are built
+#[derive(Debug)]
+pub struct AddDependencyPlugin {
+  pub issuer: &'static str,
+  pub dependency: TestDependency,
+  pub built: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Plugin for AddDependencyPlugin {
+  fn name(&self) -> &'static str {
+    "AddDependencyPlugin"
+  }
+
+  async fn build_module(&self, module: &mut dyn Module) -> Result<()> {
+    if let Some(module) = module.as_normal_module_mut() {
+      let resource_path = module.resource_resolved_data().resource_path.clone();
+      self
+        .built
+        .lock()
+        .expect("should lock")
+        .push(resource_path.to_string_lossy().to_string());
+      if resource_path.ends_with(self.issuer) {
+        let parser_and_generator = module.parser_and_generator_mut();
+        let inner = std::mem::replace(
+          parser_and_generator,
+          Box::new(WithDependency {
+            inner: None,
+            dependency: self.dependency.clone(),
+          }),
+        );
+        *parser_and_generator = Box::new(WithDependency {
+          inner: Some(inner),
+          dependency: self.dependency.clone(),
+        });
+      }
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,3 @@
+import { a } from "./a.js";
+import("./b.js");
+console.log(a);
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use rspack_core::{Compiler, DependencyId};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+/// The ids of the dependencies of all modules, sorted as modules are built concurrently
+async fn build_dependency_ids() -> Vec<DependencyId> {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/dependency-ids");
+  let (options, plugins) = apply_from_fixture(&fixture_path);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let module_graph = &compiler.compilation.module_graph;
+  let mut ids = module_graph
+    .modules()
+    .keys()
+    .flat_map(|identifier| {
+      module_graph
+        .module_graph_module_by_identifier(identifier)
+        .expect("should have module graph module")
+        .dependencies
+        .iter()
+        .copied()
+    })
+    .collect::<Vec<_>>();
+  ids.sort_unstable();
+  ids
+}
+
+#[tokio::test]
+async fn sequential_builds_get_identical_dependency_ids() {
+  let first = build_dependency_ids().await;
+  assert!(!first.is_empty());
+
+  // without a reset the next build continues where this one stopped
+  let continued = build_dependency_ids().await;
+  assert!(continued.iter().all(|id| !first.contains(id)));
+
+  DependencyId::reset_allocation();
+  let first = build_dependency_ids().await;
+  DependencyId::reset_allocation();
+  let second = build_dependency_ids().await;
+  assert_eq!(first, second);
+}
@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use rspack_core::{rspack_sources::Source, Compiler, TreeShaking, UsedExportsOption};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_javascript::{FlagDependencyExportsPlugin, FlagDependencyUsagePlugin};
+use rspack_testing::apply_from_fixture;
+use serde_json::Value;
+
+#[tokio::test]
+async fn exports_usage_report_is_emitted() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/exports-usage-report");
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  options.experiments.rspack_future.new_treeshaking = true;
+  options.optimization.provided_exports = true;
+  options.optimization.used_exports = UsedExportsOption::True;
+  options.optimization.exports_usage_report = Some("exports-usage.json".to_string());
+  options.builtins.tree_shaking = TreeShaking::False;
+  plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+  plugins.push(Box::new(FlagDependencyUsagePlugin::new(
+    options.optimization.exports_usage_report.clone(),
+  )));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_stats().get_errors().is_empty());
+
+  let source = compiler
+    .compilation
+    .assets()
+    .get("exports-usage.json")
+    .and_then(|asset| asset.get_source())
+    .expect("should emit the report")
+    .source();
+  let report: Value = serde_json::from_str(&source).expect("should be json");
+  let modules = report.as_array().expect("should list modules");
+  let lib = modules
+    .iter()
+    .find(|module| {
+      module["module"]
+        .as_str()
+        .is_some_and(|module| module.ends_with("lib.js"))
+    })
+    .expect("should report lib.js");
+  let exports = lib["exports"].as_array().expect("should list exports");
+  assert_eq!(exports.len(), 2);
+  assert_eq!(exports[0]["name"], "a");
+  assert_eq!(exports[0]["used"], true);
+  let referenced_by = exports[0]["referencedBy"]
+    .as_array()
+    .expect("should list references");
+  assert!(referenced_by.iter().any(|reference| reference["module"]
+    .as_str()
+    .is_some_and(|module| module.ends_with("index.js"))));
+  assert_eq!(exports[1]["name"], "b");
+  assert_eq!(exports[1]["used"], false);
+  assert!(exports[1]["referencedBy"]
+    .as_array()
+    .is_some_and(|references| references.is_empty()));
+}
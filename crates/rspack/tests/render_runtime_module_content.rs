@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use rspack_core::rspack_sources::{ConcatSource, RawSource, Source, SourceExt};
+use rspack_core::{
+  Compiler, Plugin, PluginContext, PluginRenderRuntimeModuleContentOutput,
+  RenderRuntimeModuleContentArgs,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[derive(Debug)]
+struct JsonpRuntimeCommentPlugin;
+
+impl Plugin for JsonpRuntimeCommentPlugin {
+  fn render_runtime_module_content<'a>(
+    &'a self,
+    _ctx: PluginContext,
+    mut args: RenderRuntimeModuleContentArgs<'a>,
+  ) -> PluginRenderRuntimeModuleContentOutput<'a> {
+    if args.runtime_module_name.as_str() == "webpack/runtime/jsonp_chunk_loading" {
+      args.runtime_module_source = ConcatSource::new([
+        args.runtime_module_source,
+        RawSource::from("\n// patched jsonp chunk loading").boxed(),
+      ])
+      .boxed();
+    }
+    Ok(args)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plugins_can_patch_runtime_modules() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/runtime-module-content");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.push(Box::new(JsonpRuntimeCommentPlugin));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let runtime = compiler
+    .compilation
+    .assets()
+    .get("runtime.js")
+    .and_then(|asset| asset.get_source())
+    .expect("should emit the runtime chunk")
+    .source()
+    .to_string();
+  let jsonp_runtime = runtime
+    .find("// webpack/runtime/jsonp_chunk_loading")
+    .expect("should render the jsonp chunk loading runtime");
+  assert!(runtime[jsonp_runtime..].contains("// patched jsonp chunk loading"));
+  // other runtime modules are left alone
+  assert_eq!(runtime.matches("// patched jsonp chunk loading").count(), 1);
+}
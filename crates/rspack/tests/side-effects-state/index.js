@@ -0,0 +1,2 @@
+import { value } from "./reexport.js";
+console.log(value);
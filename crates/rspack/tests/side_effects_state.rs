@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use rspack_core::{Compiler, ConnectionState, DependencyId, DependencyType, Module};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test]
+async fn side_effects_states_are_cached_unless_cut_off_by_a_cycle() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/side-effects-state");
+  let (options, plugins) = apply_from_fixture(&fixture_path);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_stats().get_errors().is_empty());
+
+  // nothing marks modules as side effect free from their source yet, so do it by hand
+  let module_graph = &mut compiler.compilation.module_graph;
+  let identifiers = module_graph.modules().keys().copied().collect::<Vec<_>>();
+  for identifier in &identifiers {
+    let mgm = module_graph
+      .module_graph_module_by_identifier_mut(identifier)
+      .expect("should have module graph module");
+    mgm.factory_meta = None;
+    if let Some(build_meta) = mgm.build_meta.as_mut() {
+      build_meta.side_effect_free = Some(true);
+    }
+  }
+  let module_graph = &compiler.compilation.module_graph;
+
+  // the cache does not outlive the optimization passes
+  let dependencies = identifiers
+    .iter()
+    .flat_map(|identifier| {
+      module_graph
+        .module_graph_module_by_identifier(identifier)
+        .expect("should have module graph module")
+        .dependencies
+        .iter()
+        .copied()
+    })
+    .collect::<Vec<_>>();
+  assert!(dependencies.iter().all(|dependency_id| module_graph
+    .cached_side_effects_state(dependency_id)
+    .is_none()));
+
+  let reexport_identifier = identifiers
+    .iter()
+    .find(|identifier| identifier.ends_with("reexport.js"))
+    .expect("should have reexport.js");
+  let reexport = module_graph
+    .module_by_identifier(reexport_identifier)
+    .expect("should have reexport.js");
+  let (reexport_dependencies, import_dependencies): (Vec<DependencyId>, Vec<DependencyId>) =
+    module_graph
+      .module_graph_module_by_identifier(reexport_identifier)
+      .expect("should have module graph module")
+      .dependencies
+      .iter()
+      .copied()
+      .filter(|dependency_id| {
+        module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dependency| dependency.as_module_dependency())
+          .is_some()
+      })
+      .partition(|dependency_id| {
+        matches!(
+          module_graph
+            .dependency_by_id(dependency_id)
+            .expect("should have dependency")
+            .dependency_type(),
+          DependencyType::EsmExportImportedSpecifier
+        )
+      });
+  assert_eq!(reexport_dependencies.len(), 1);
+  assert!(!import_dependencies.is_empty());
+
+  let circular_visits = module_graph.circular_side_effects_visits();
+  let state = reexport.get_side_effects_connection_state(module_graph, &mut Default::default());
+  // the import side effects of a module are evaluated from the module itself
+  assert!(matches!(state, ConnectionState::CircularConnection));
+  assert!(module_graph.circular_side_effects_visits() > circular_visits);
+  // cut off by the cycle guard, so it depends on the module chain
+  for dependency_id in &import_dependencies {
+    assert_eq!(module_graph.cached_side_effects_state(dependency_id), None);
+  }
+  // leaf.js is side effect free and has no dependencies
+  assert_eq!(
+    module_graph.cached_side_effects_state(&reexport_dependencies[0]),
+    Some(false)
+  );
+
+  // a cached state is used instead of evaluating the dependency again
+  module_graph.clear_side_effects_state_cache();
+  assert_eq!(
+    module_graph.cached_side_effects_state(&reexport_dependencies[0]),
+    None
+  );
+  module_graph.cache_side_effects_state(reexport_dependencies[0], true);
+  let state = reexport.get_side_effects_connection_state(module_graph, &mut Default::default());
+  assert!(matches!(state, ConnectionState::Bool(true)));
+}
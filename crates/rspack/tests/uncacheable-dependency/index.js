@@ -0,0 +1,2 @@
+import { value } from "./a.js";
+console.log(value);
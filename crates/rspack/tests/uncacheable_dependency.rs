@@ -0,0 +1,48 @@
+mod common;
+
+use std::{
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use common::{AddDependencyPlugin, TestDependency};
+use rspack_core::{CacheOptions, Compiler};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test]
+async fn module_with_uncacheable_dependency_is_built_again() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/uncacheable-dependency");
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  options.cache = CacheOptions::Memory(Default::default());
+  let built = Arc::new(Mutex::new(Vec::new()));
+  plugins.push(Box::new(AddDependencyPlugin {
+    issuer: "index.js",
+    dependency: TestDependency {
+      cacheable: false,
+      ..TestDependency::new("./a.js", "volatile")
+    },
+    built: built.clone(),
+  }));
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let built_count = |file: &str| {
+    built
+      .lock()
+      .expect("should lock")
+      .iter()
+      .filter(|path| path.ends_with(file))
+      .count()
+  };
+
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_stats().get_errors().is_empty());
+  assert_eq!(built_count("index.js"), 1);
+  assert_eq!(built_count("a.js"), 1);
+
+  compiler.build().await.expect("should build again");
+  assert!(compiler.compilation.get_stats().get_errors().is_empty());
+  // stored without a snapshot, so never restored from the cache
+  assert_eq!(built_count("index.js"), 2);
+  // restored from the cache
+  assert_eq!(built_count("a.js"), 1);
+}
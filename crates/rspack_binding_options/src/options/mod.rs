@@ -164,7 +164,9 @@ impl RawOptionsApply for RawOptions {
         plugins.push(FlagDependencyExportsPlugin::default().boxed());
       }
       if optimization.used_exports.is_enable() {
-        plugins.push(FlagDependencyUsagePlugin::default().boxed());
+        plugins.push(
+          FlagDependencyUsagePlugin::new(optimization.exports_usage_report.clone()).boxed(),
+        );
       }
     }
 
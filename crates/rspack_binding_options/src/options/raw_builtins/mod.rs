@@ -167,8 +167,10 @@ impl RawOptionsApply for BuiltinPlugin {
       }
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
-          CopyRspackPluginOptions::from(downcast_into::<RawCopyRspackPluginOptions>(self.options)?)
-            .patterns,
+          CopyRspackPluginOptions::try_from(downcast_into::<RawCopyRspackPluginOptions>(
+            self.options,
+          )?)?
+          .patterns,
         )
         .boxed();
         plugins.push(plugin);
@@ -1,21 +1,87 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
+use derivative::Derivative;
+use napi::{
+  bindgen_prelude::{Buffer, Either},
+  Env, JsFunction,
+};
 use napi_derive::napi;
-use rspack_plugin_copy::{CopyGlobOptions, CopyPattern, CopyRspackPluginOptions, ToType};
+use rspack_core::{rspack_sources::RawSource, AssetInfo};
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_copy::{
+  CopyGlobOptions, CopyPattern, CopyRspackPluginOptions, ToFnCtx, ToOption, ToType, Transformer,
+};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize, Clone)]
+#[napi(object)]
+pub struct RawCopyToFnCtx {
+  pub context: String,
+  pub absolute_filename: String,
+}
+
+impl<'a> From<ToFnCtx<'a>> for RawCopyToFnCtx {
+  fn from(value: ToFnCtx) -> Self {
+    Self {
+      context: value.context.to_string_lossy().to_string(),
+      absolute_filename: value.absolute_filename.to_string_lossy().to_string(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct RawCopyTransformCtx {
+  pub input: Buffer,
+  pub absolute_filename: String,
+}
+
+#[derive(Derivative, Deserialize)]
+#[derivative(Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawCopyPattern {
   pub from: String,
-  pub to: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | ((ctx: RawCopyToFnCtx) => string | Promise<string>)")]
+  pub to: Option<Either<String, JsFunction>>,
   pub context: Option<String>,
   pub to_type: Option<String>,
   pub no_error_on_missing: bool,
+  pub info: Option<RawCopyAssetInfo>,
   pub force: bool,
   pub priority: i32,
   pub glob_options: RawCopyGlobOptions,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(ctx: RawCopyTransformCtx) => string | Buffer | Promise<string | Buffer>")]
+  pub transform: Option<JsFunction>,
+}
+
+/// Flags set on the info of the copied assets, `minimized` keeps the minimizers away from
+/// already minified files
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawCopyAssetInfo {
+  pub immutable: Option<bool>,
+  pub minimized: Option<bool>,
+  pub development: Option<bool>,
+  pub hot_module_replacement: Option<bool>,
+}
+
+impl From<RawCopyAssetInfo> for AssetInfo {
+  fn from(value: RawCopyAssetInfo) -> Self {
+    let mut info = AssetInfo::default()
+      .with_minimized(value.minimized.unwrap_or_default())
+      .with_development(value.development.unwrap_or_default())
+      .with_hot_module_replacement(value.hot_module_replacement.unwrap_or_default());
+    info.set_immutable(value.immutable.unwrap_or_default());
+    info
+  }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -25,6 +91,7 @@ pub struct RawCopyGlobOptions {
   pub case_sensitive_match: Option<bool>,
   pub dot: Option<bool>,
   pub ignore: Option<Vec<String>>,
+  pub follow_symbolic_links: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -34,22 +101,82 @@ pub struct RawCopyRspackPluginOptions {
   pub patterns: Vec<RawCopyPattern>,
 }
 
-impl From<RawCopyPattern> for CopyPattern {
-  fn from(value: RawCopyPattern) -> Self {
+fn to_fn_into_to_option(to: JsFunction) -> rspack_error::Result<ToOption> {
+  let func: ThreadsafeFunction<RawCopyToFnCtx, String> =
+    NAPI_ENV.with(|env| -> anyhow::Result<_> {
+      let env = env.borrow().expect("Failed to get env with external");
+      let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(to, &Env::from(env));
+      Ok(func_use)
+    })?;
+  let func = Arc::new(func);
+  Ok(ToOption::Fn(Arc::new(move |ctx: ToFnCtx| {
+    let func = func.clone();
+    let ctx = RawCopyToFnCtx::from(ctx);
+    Box::pin(async move {
+      func
+        .call(ctx, ThreadsafeFunctionCallMode::NonBlocking)
+        .into_rspack_result()?
+        .await
+        .map_err(|err| internal_error!("Failed to call copy pattern to: {err}"))?
+    })
+  })))
+}
+
+fn transform_into_transformer(transform: JsFunction) -> rspack_error::Result<Transformer> {
+  let func: ThreadsafeFunction<RawCopyTransformCtx, Either<String, Buffer>> =
+    NAPI_ENV.with(|env| -> anyhow::Result<_> {
+      let env = env.borrow().expect("Failed to get env with external");
+      let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(transform, &Env::from(env));
+      Ok(func_use)
+    })?;
+  let func = Arc::new(func);
+  Ok(Transformer::Fn(Arc::new(
+    move |input: Vec<u8>, absolute_filename: &str| {
+      let func = func.clone();
+      let ctx = RawCopyTransformCtx {
+        input: input.into(),
+        absolute_filename: absolute_filename.to_string(),
+      };
+      Box::pin(async move {
+        let result = func
+          .call(ctx, ThreadsafeFunctionCallMode::NonBlocking)
+          .into_rspack_result()?
+          .await
+          .map_err(|err| internal_error!("Failed to call copy pattern transform: {err}"))??;
+        Ok(match result {
+          Either::A(s) => RawSource::Source(s),
+          Either::B(b) => RawSource::Buffer(b.into()),
+        })
+      })
+    },
+  )))
+}
+
+impl TryFrom<RawCopyPattern> for CopyPattern {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCopyPattern) -> rspack_error::Result<Self> {
     let RawCopyPattern {
       from,
       to,
       context,
       to_type,
       no_error_on_missing,
+      info,
       force,
       priority,
       glob_options,
+      transform,
     } = value;
 
-    Self {
+    Ok(Self {
       from,
-      to,
+      to: to
+        .map(|to| match to {
+          Either::A(s) => Ok(ToOption::String(s)),
+          Either::B(f) => to_fn_into_to_option(f),
+        })
+        .transpose()?,
       context: context.map(PathBuf::from),
       to_type: if let Some(to_type) = to_type {
         match to_type.to_lowercase().as_str() {
@@ -65,7 +192,7 @@ impl From<RawCopyPattern> for CopyPattern {
         None
       },
       no_error_on_missing,
-      info: None,
+      info: info.map(Into::into),
       force,
       priority,
       glob_options: CopyGlobOptions {
@@ -77,15 +204,23 @@ impl From<RawCopyPattern> for CopyPattern {
             .map(|filter| glob::Pattern::new(filter.as_ref()).expect("Invalid pattern option"))
             .collect()
         }),
+        follow_symbolic_links: glob_options.follow_symbolic_links,
       },
-    }
+      transform: transform.map(transform_into_transformer).transpose()?,
+    })
   }
 }
 
-impl From<RawCopyRspackPluginOptions> for CopyRspackPluginOptions {
-  fn from(val: RawCopyRspackPluginOptions) -> Self {
-    Self {
-      patterns: val.patterns.into_iter().map(Into::into).collect(),
-    }
+impl TryFrom<RawCopyRspackPluginOptions> for CopyRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(val: RawCopyRspackPluginOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      patterns: val
+        .patterns
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<Vec<_>>>()?,
+    })
   }
 }
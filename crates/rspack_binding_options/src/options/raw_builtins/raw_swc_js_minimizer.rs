@@ -32,16 +32,27 @@ pub struct RawSwcJsMinimizerRules {
 pub struct RawSwcJsMinimizerRspackPluginOptions {
   pub passes: u32,
   pub drop_console: bool,
+  pub drop_debugger: bool,
   pub keep_class_names: bool,
   pub keep_fn_names: bool,
   #[napi(ts_type = r#""all" | "some" | "false""#)]
   pub comments: String,
-  pub ascii_only: bool,
+  #[napi(ts_type = r#""true" | "false" | "smart""#)]
+  pub ascii_only: String,
+  pub beautify: bool,
   pub pure_funcs: Vec<String>,
   pub extract_comments: Option<String>,
   pub test: Option<RawSwcJsMinimizerRules>,
   pub include: Option<RawSwcJsMinimizerRules>,
   pub exclude: Option<RawSwcJsMinimizerRules>,
+  pub external_sources: Option<RawSwcJsMinimizerRules>,
+  pub parallelism: Option<u32>,
+  pub banner: Option<String>,
+  pub top_level: Option<bool>,
+  pub reserved: Vec<String>,
+  pub inline_sources_content_max_bytes: Option<u32>,
+  pub ecma: Option<u32>,
+  pub downlevel: bool,
 }
 
 impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for SwcJsMinimizerRspackPluginOptions {
@@ -63,15 +74,25 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for SwcJsMinimizerRspackPlugi
     Ok(Self {
       passes: value.passes as usize,
       drop_console: value.drop_console,
+      drop_debugger: Some(value.drop_debugger),
       keep_class_names: value.keep_class_names,
       keep_fn_names: value.keep_fn_names,
       pure_funcs: value.pure_funcs,
       ascii_only: value.ascii_only,
+      beautify: value.beautify,
       comments: value.comments,
       extract_comments: value.extract_comments,
       test: try_condition(value.test)?,
       include: try_condition(value.include)?,
       exclude: try_condition(value.exclude)?,
+      external_sources: try_condition(value.external_sources)?,
+      parallelism: value.parallelism.map(|p| p as usize),
+      banner: value.banner,
+      top_level: value.top_level,
+      reserved: value.reserved,
+      inline_sources_content_max_bytes: value.inline_sources_content_max_bytes.map(|n| n as usize),
+      ecma: value.ecma.map(|n| n as usize),
+      downlevel: value.downlevel,
     })
   }
 }
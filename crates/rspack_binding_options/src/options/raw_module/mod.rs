@@ -10,9 +10,10 @@ use napi_derive::napi;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions, AssetGeneratorOptions,
   AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
-  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, FuncUseCtx, GeneratorOptions,
-  GeneratorOptionsByModuleType, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
-  ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
+  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, DynamicImportMode, FuncUseCtx,
+  GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions, ModuleOptions,
+  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, ParserOptions,
+  ParserOptionsByModuleType,
 };
 use rspack_error::internal_error;
 use rspack_loader_sass::SASS_LOADER_IDENTIFIER;
@@ -284,26 +285,62 @@ pub struct RawModuleRule {
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawParserOptions {
-  #[napi(ts_type = r#""asset" | "unknown""#)]
+  #[napi(ts_type = r#""asset" | "javascript" | "unknown""#)]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
+  pub javascript: Option<RawJavascriptParserOptions>,
 }
 
-impl From<RawParserOptions> for ParserOptions {
-  fn from(value: RawParserOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawParserOptions> for ParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawParserOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
           .expect("should have an \"asset\" when RawParserOptions.type is \"asset\"")
           .into(),
       ),
+      "javascript" => Self::Javascript(
+        value
+          .javascript
+          .expect("should have a \"javascript\" when RawParserOptions.type is \"javascript\"")
+          .try_into()?,
+      ),
       "unknown" => Self::Unknown,
       _ => panic!(
-        "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"unknown\".",
+        "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"javascript\", \"unknown\".",
         value.r#type
       ),
-    }
+    })
+  }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawJavascriptParserOptions {
+  #[napi(ts_type = r#""lazy" | "eager""#)]
+  pub dynamic_import_mode: Option<String>,
+  pub worker: Option<Vec<String>>,
+  pub expr_context_critical: Option<bool>,
+}
+
+impl TryFrom<RawJavascriptParserOptions> for JavascriptParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawJavascriptParserOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      dynamic_import_mode: value
+        .dynamic_import_mode
+        .as_deref()
+        .map(DynamicImportMode::try_from)
+        .transpose()?
+        .unwrap_or_default(),
+      worker: value.worker.unwrap_or_default(),
+      expr_context_critical: value.expr_context_critical.unwrap_or_default(),
+    })
   }
 }
 
@@ -656,7 +693,7 @@ impl RawOptionsApply for RawModuleRule {
       description_data,
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
-      parser: self.parser.map(|raw| raw.into()),
+      parser: self.parser.map(|raw| raw.try_into()).transpose()?,
       generator: self.generator.map(|raw| raw.into()),
       resolve: self.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: self.side_effects,
@@ -689,7 +726,7 @@ impl RawOptionsApply for RawModuleOptions {
         .parser
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.into())))
+            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.try_into()?)))
             .collect::<std::result::Result<ParserOptionsByModuleType, rspack_error::Error>>()
         })
         .transpose()?,
@@ -4,7 +4,7 @@ use rspack_core::{Optimization, PluginExt, SideEffectOption, UsedExportsOption};
 use rspack_error::internal_error;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  NamedModuleIdsPlugin, UrlWorkerChunkIdsPlugin,
 };
 use rspack_plugin_split_chunks::SplitChunksPlugin;
 use serde::Deserialize;
@@ -20,12 +20,14 @@ pub struct RawOptimizationOptions {
   pub split_chunks: Option<RawSplitChunksOptions>,
   pub module_ids: String,
   pub chunk_ids: String,
+  pub worker_chunk_ids: String,
   pub remove_available_modules: bool,
   pub remove_empty_chunks: bool,
   pub side_effects: String,
   pub used_exports: String,
   pub provided_exports: bool,
   pub real_content_hash: bool,
+  pub exports_usage_report: Option<String>,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -46,6 +48,15 @@ impl RawOptionsApply for RawOptimizationOptions {
 
       plugins.push(split_chunks_plugin);
     }
+    match self.worker_chunk_ids.as_ref() {
+      "url" => plugins.push(UrlWorkerChunkIdsPlugin.boxed()),
+      "default" => {}
+      _ => {
+        return Err(internal_error!(
+          "'worker_chunk_ids' should be 'default' or 'url'."
+        ))
+      }
+    }
     let chunk_ids_plugin = match self.chunk_ids.as_ref() {
       "named" => NamedChunkIdsPlugin::new(None, None).boxed(),
       "deterministic" => DeterministicChunkIdsPlugin::default().boxed(),
@@ -75,6 +86,7 @@ impl RawOptionsApply for RawOptimizationOptions {
       side_effects: SideEffectOption::from(self.side_effects.as_str()),
       provided_exports: self.provided_exports,
       used_exports: UsedExportsOption::from(self.used_exports.as_str()),
+      exports_usage_report: self.exports_usage_report,
     })
   }
 }
@@ -89,7 +89,7 @@ impl TryFrom<RawResolveOptions> for Resolve {
         i.into_iter()
           .map(|(k, v)| {
             let v = v.try_into()?;
-            Ok((DependencyCategory::from(k.as_str()), v))
+            Ok((DependencyCategory::try_from(k.as_str())?, v))
           })
           .collect::<Result<ByDependency, Self::Error>>()
       })
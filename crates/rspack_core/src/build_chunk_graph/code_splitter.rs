@@ -480,7 +480,12 @@ impl<'me> CodeSplitter<'me> {
           .chunk_reasons
           .push(format!("DynamicImport({module_identifier})"));
         ChunkGroup::new(
-          ChunkGroupKind::Normal { options: ChunkGroupOptions::default().name_optional(group_options.as_ref().and_then(|x| x.name())) },
+          ChunkGroupKind::Normal {
+            options: match group_options {
+              Some(ChunkGroupOptionsKindRef::Normal(options)) => options.clone(),
+              _ => ChunkGroupOptions::default(),
+            },
+          },
           item_chunk_group.runtime.clone(),
           ChunkGroupInfo {
             chunk_loading: item_chunk_group.info.chunk_loading,
@@ -115,7 +115,18 @@ impl BuildModuleOccasion {
         return Ok((Ok(data), false));
       }
 
-      if data.inner.build_info.cacheable {
+      let cacheable = data.inner.build_info.cacheable
+        && data
+          .inner
+          .dependencies
+          .iter()
+          .all(|dependency| dependency.cacheable())
+        && module
+          .code_generation_dependencies()
+          .iter()
+          .flatten()
+          .all(|dependency| dependency.cacheable());
+      if cacheable {
         let mut paths: Vec<&Path> = Vec::new();
         paths.extend(
           data
@@ -5,8 +5,8 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, EntryOptions, Filename,
-  ModuleGraph, RuntimeSpec, SourceType,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey, Compilation,
+  EntryOptions, Filename, ModuleGraph, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -313,6 +313,75 @@ impl Chunk {
 
   // pub fn get_all_referenced_async_entry_points() -> HashSet<ChunkUkey> {}
 
+  /// Ids of the chunks to prefetch or preload once this chunk is loaded. Chunks that are
+  /// already loaded together with this chunk are skipped.
+  pub fn get_child_ids_by_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Vec<String> {
+    let mut ids = vec![];
+    for group in self
+      .groups
+      .iter()
+      .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+    {
+      // only the last chunk of a group triggers the loading of its children
+      if group.chunks.last() != Some(&self.ukey) {
+        continue;
+      }
+      for child_group in group
+        .get_children_by_order(order_key, &compilation.chunk_group_by_ukey)
+        .iter()
+        .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+      {
+        for chunk in child_group
+          .chunks
+          .iter()
+          .filter(|chunk_ukey| !group.chunks.contains(chunk_ukey))
+          .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(chunk_ukey))
+        {
+          if let Some(id) = &chunk.id
+            && !ids.contains(id)
+          {
+            ids.push(id.clone());
+          }
+        }
+      }
+    }
+    ids
+  }
+
+  /// Chunk id to the ids of its children to prefetch or preload, for all the async chunks
+  /// of this chunk, and this chunk itself when `include_direct_children` is set.
+  pub fn get_child_ids_by_orders_map(
+    &self,
+    include_direct_children: bool,
+    order_key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> HashMap<String, Vec<String>> {
+    let mut map = HashMap::default();
+    let mut add_child_ids_by_order = |chunk: &Chunk| {
+      let child_ids = chunk.get_child_ids_by_order(order_key, compilation);
+      if let Some(id) = &chunk.id
+        && !child_ids.is_empty()
+      {
+        map.insert(id.clone(), child_ids);
+      }
+    };
+    if include_direct_children {
+      add_child_ids_by_order(self);
+    }
+    for chunk in self
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .filter_map(|ukey| compilation.chunk_by_ukey.get(ukey))
+    {
+      add_child_ids_by_order(chunk);
+    }
+    map
+  }
+
   pub fn get_render_hash(&self, length: usize) -> Option<&str> {
     self.hash.as_ref().map(|hash| hash.rendered(length))
   }
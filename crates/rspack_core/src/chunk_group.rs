@@ -187,6 +187,30 @@ impl ChunkGroup {
       })
       .join("+")
   }
+
+  /// The children of this chunk group that should be prefetched or preloaded, the ones with
+  /// a higher order come first.
+  pub fn get_children_by_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> Vec<ChunkGroupUkey> {
+    self
+      .children
+      .iter()
+      .filter_map(|child_ukey| {
+        let order = chunk_group_by_ukey
+          .get(child_ukey)?
+          .kind
+          .get_order(order_key)?;
+        Some((order, *child_ukey))
+      })
+      .sorted_by(|(a_order, a_ukey), (b_order, b_ukey)| {
+        b_order.cmp(a_order).then_with(|| a_ukey.cmp(b_ukey))
+      })
+      .map(|(_, ukey)| ukey)
+      .collect()
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -222,6 +246,16 @@ impl ChunkGroupKind {
       ChunkGroupKind::Normal { options } => options.name.as_deref(),
     }
   }
+
+  pub fn get_order(&self, order_key: &ChunkGroupOrderKey) -> Option<i32> {
+    match self {
+      ChunkGroupKind::Entrypoint { .. } => None,
+      ChunkGroupKind::Normal { options } => match order_key {
+        ChunkGroupOrderKey::Prefetch => options.prefetch_order,
+        ChunkGroupOrderKey::Preload => options.preload_order,
+      },
+    }
+  }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -235,9 +269,13 @@ pub struct EntryOptions {
   pub filename: Option<Filename>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
+  /// `import(/* webpackPrefetch: true */ "./a")`, `true` is the same as `0`
+  pub prefetch_order: Option<i32>,
+  /// `import(/* webpackPreload: true */ "./a")`, `true` is the same as `0`
+  pub preload_order: Option<i32>,
 }
 
 impl ChunkGroupOptions {
@@ -250,6 +288,22 @@ impl ChunkGroupOptions {
     self.name = v.map(|v| v.into());
     self
   }
+
+  pub fn prefetch_order(mut self, v: Option<i32>) -> Self {
+    self.prefetch_order = v;
+    self
+  }
+
+  pub fn preload_order(mut self, v: Option<i32>) -> Self {
+    self.preload_order = v;
+    self
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkGroupOrderKey {
+  Prefetch,
+  Preload,
 }
 
 pub enum ChunkGroupOptionsKindRef<'a> {
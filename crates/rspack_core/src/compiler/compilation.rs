@@ -41,9 +41,9 @@ use crate::{
   CompilationLogger, CompilationLogging, CompilerOptions, ContentHashArgs, DependencyId, Entry,
   EntryData, EntryOptions, Entrypoint, FactorizeQueue, FactorizeTask, FactorizeTaskResult,
   Filename, Logger, Module, ModuleGraph, ModuleIdentifier, ModuleProfile, ModuleType, PathData,
-  ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask,
-  RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec,
-  SharedPluginDriver, SourceType, Stats, TaskResult, WorkerTask,
+  PluginDriver, ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult,
+  ProcessDependenciesTask, RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals,
+  RuntimeModule, RuntimeSpec, SharedPluginDriver, SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -1146,16 +1146,65 @@ impl Compilation {
     plugin_driver.finish_modules(self).await?;
     logger.time_end(start);
 
+    let start = logger.time("report dependency errors and warnings");
+    self.report_dependency_errors_and_warnings();
+    if plugin_driver.strict_custom_dependency_types {
+      self.report_unregistered_custom_dependency_types(&plugin_driver);
+    }
+    logger.time_end(start);
+
     Ok(())
   }
 
+  fn report_dependency_errors_and_warnings(&mut self) {
+    let diagnostics = self
+      .module_graph
+      .module_graph_modules()
+      .values()
+      .sorted_unstable_by_key(|mgm| mgm.module_identifier.as_str())
+      .flat_map(|mgm| mgm.dependencies.iter())
+      .filter_map(|dependency_id| self.module_graph.dependency_by_id(dependency_id))
+      .filter_map(|dependency| dependency.get_diagnostics(&self.module_graph))
+      .flatten()
+      .collect::<Vec<_>>();
+    self.push_batch_diagnostic(diagnostics);
+  }
+
+  // A typo in the name of a custom dependency type silently creates a distinct type
+  fn report_unregistered_custom_dependency_types(&mut self, plugin_driver: &PluginDriver) {
+    let registered = &plugin_driver.registered_custom_dependency_types;
+    let diagnostics = self
+      .module_graph
+      .unregistered_custom_dependency_types(|ty| registered.contains(ty))
+      .into_iter()
+      .map(|(ty, module_identifier)| {
+        Diagnostic::warn(
+          "Unregistered dependency type".to_string(),
+          format!(
+            "Custom dependency type \"{ty}\" in {module_identifier} is not registered by any plugin"
+          ),
+          0,
+          0,
+        )
+      })
+      .collect::<Vec<_>>();
+    self.push_batch_diagnostic(diagnostics);
+  }
+
   #[instrument(name = "compilation:seal", skip_all)]
   pub async fn seal(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let logger = self.get_logger("rspack.Compilation");
 
     let start = logger.time("optimize dependencies");
     // https://github.com/webpack/webpack/blob/d15c73469fd71cf98734685225250148b68ddc79/lib/Compilation.js#L2812-L2814
-    while plugin_driver.optimize_dependencies(self).await?.is_some() {}
+    // side effects states are only reused within a single pass, as every pass may change the graph
+    loop {
+      self.module_graph.clear_side_effects_state_cache();
+      if plugin_driver.optimize_dependencies(self).await?.is_none() {
+        break;
+      }
+    }
+    self.module_graph.clear_side_effects_state_cache();
     logger.time_end(start);
 
     // if self.options.is_new_tree_shaking() {
@@ -82,7 +82,8 @@ impl WorkerTask for FactorizeTask {
     let (result, diagnostics) = match *dependency.dependency_type() {
       DependencyType::ImportContext
       | DependencyType::CommonJSRequireContext
-      | DependencyType::RequireContext => {
+      | DependencyType::RequireContext
+      | DependencyType::ImportMetaContext => {
         let factory = ContextModuleFactory::new(self.plugin_driver, self.cache);
         factory
           .create(ModuleFactoryCreateData {
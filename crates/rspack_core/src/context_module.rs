@@ -16,11 +16,12 @@ use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  contextify, get_exports_type_with_strict, stringify_map, BoxDependency, BuildContext, BuildInfo,
-  BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation, ContextElementDependency,
-  DependencyCategory, DependencyId, DependencyType, ExportsType, FakeNamespaceObjectMode,
-  LibIdentOptions, Module, ModuleType, Resolve, ResolveInnerOptions,
-  ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, SourceType,
+  calc_hash, contextify, get_exports_type_with_strict, render_chunk_name_template, stringify_map,
+  BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph, ChunkGroupOptions,
+  CodeGenerationResult, Compilation, ContextElementDependency, DependencyCategory, DependencyId,
+  DependencyType, ExportsType, FakeNamespaceObjectMode, LibIdentOptions, Module, ModuleGraph,
+  ModuleType, Resolve, ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
+  SourceType,
 };
 
 #[derive(Debug, Clone)]
@@ -70,6 +71,9 @@ pub struct ContextOptions {
   pub category: DependencyCategory,
   pub request: String,
   pub namespace_object: ContextNameSpaceObject,
+  /// Name of the async chunks of `lazy` and `lazy-once` contexts, supports `[index]`, `[request]`
+  /// and `[name]`
+  pub chunk_name_template: Option<String>,
 }
 
 impl Display for ContextOptions {
@@ -85,7 +89,11 @@ impl Display for ContextOptions {
       self.category,
       self.request,
       self.namespace_object
-    )
+    )?;
+    if let Some(chunk_name_template) = &self.chunk_name_template {
+      write!(f, " {chunk_name_template}")?;
+    }
+    Ok(())
   }
 }
 
@@ -99,6 +107,7 @@ impl PartialEq for ContextOptions {
       && self.category == other.category
       && self.request == other.request
       && self.namespace_object == other.namespace_object
+      && self.chunk_name_template == other.chunk_name_template
   }
 }
 
@@ -114,6 +123,7 @@ impl Hash for ContextOptions {
     self.category.hash(state);
     self.request.hash(state);
     self.namespace_object.hash(state);
+    self.chunk_name_template.hash(state);
   }
 }
 
@@ -393,7 +403,15 @@ impl ContextModule {
         "#,
       ));
     }
-    source.add(RawSource::from(format!("\n{return_module_object}\n")));
+    if matches!(mode, ContextMode::LazyOnce) {
+      // all elements live in the same async chunk, loading it for any of them loads them all
+      source.add(RawSource::from(format!(
+        "return {}(id).then(function() {{\n{return_module_object}\n}});\n",
+        RuntimeGlobals::LOAD_CHUNK_WITH_MODULE
+      )));
+    } else {
+      source.add(RawSource::from(format!("\n{return_module_object}\n")));
+    }
     if is_async {
       source.add(RawSource::from("\n});\n"));
     }
@@ -560,7 +578,7 @@ impl ContextModule {
     fn visit_dirs(
       ctx: &str,
       dir: &Path,
-      dependencies: &mut Vec<BoxDependency>,
+      dependencies: &mut Vec<ContextElementDependency>,
       options: &ContextModuleOptions,
       resolve_options: &ResolveInnerOptions,
     ) -> Result<()> {
@@ -578,6 +596,8 @@ impl ContextModule {
           {
             // ignore hidden files
             continue;
+          } else if !is_included(&path.to_string_lossy(), &options.context_options) {
+            continue;
           } else {
             // FIXME: nodejs resolver return path of context, sometimes is '/a/b', sometimes is '/a/b/'
             let relative_path = {
@@ -600,7 +620,7 @@ impl ContextModule {
 
             requests.iter().for_each(|r| {
               if options.context_options.reg_exp.test(&r.request) {
-                dependencies.push(Box::new(ContextElementDependency {
+                dependencies.push(ContextElementDependency {
                   id: DependencyId::new(),
                   request: format!(
                     "{}{}{}",
@@ -618,7 +638,8 @@ impl ContextModule {
                     path.to_string_lossy()
                   ),
                   referenced_exports: None,
-                }));
+                  group_options: None,
+                });
               }
             })
           }
@@ -641,6 +662,11 @@ impl ContextModule {
       &self.options,
       &resolver.options(),
     )?;
+    self.assign_group_options(&mut dependencies);
+    let dependencies = dependencies
+      .into_iter()
+      .map(|dependency| Box::new(dependency) as BoxDependency)
+      .collect::<Vec<_>>();
 
     tracing::trace!("resolving dependencies for {:?}", dependencies);
 
@@ -668,6 +694,50 @@ impl ContextModule {
   }
 }
 
+impl ContextModule {
+  // `lazy-once` puts every element into one shared async chunk, while `lazy` splits each of them
+  // and only names the chunks when a chunk name template is given
+  fn assign_group_options(&self, dependencies: &mut [ContextElementDependency]) {
+    let context_options = &self.options.context_options;
+    match context_options.mode {
+      ContextMode::LazyOnce => {
+        let name = match &context_options.chunk_name_template {
+          Some(template) => render_chunk_name_template(template, 0, &context_options.request, None),
+          None => format!("lazy-once-{:x}", calc_hash(&self.identifier)),
+        };
+        let group_options = ChunkGroupOptions::default().name(name);
+        for dependency in dependencies.iter_mut() {
+          dependency.group_options = Some(group_options.clone());
+        }
+      }
+      ContextMode::Lazy => {
+        let Some(template) = &context_options.chunk_name_template else {
+          return;
+        };
+        // every element needs its own chunk, so the name has to vary per element
+        let template = if template.contains("[index]") || template.contains("[request]") {
+          template.clone()
+        } else {
+          format!("{template}[index]")
+        };
+        dependencies.sort_by(|a, b| a.user_request.cmp(&b.user_request));
+        for (index, dependency) in dependencies.iter_mut().enumerate() {
+          dependency.group_options = Some(ChunkGroupOptions::default().name(
+            render_chunk_name_template(&template, index, &dependency.user_request, None),
+          ));
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+// `include` and `exclude` are matched against the absolute path of each file in the context
+fn is_included(path: &str, options: &ContextOptions) -> bool {
+  let matches = |reg: &String| RspackRegex::new(reg).map_or(false, |reg| reg.test(path));
+  options.include.as_ref().map_or(true, matches) && !options.exclude.as_ref().is_some_and(matches)
+}
+
 fn create_identifier(options: &ContextModuleOptions) -> Identifier {
   Identifier::from(format!("{options}"))
 }
@@ -752,3 +822,20 @@ fn alternative_requests(
 pub fn create_resource_identifier_for_context_dependency(options: &ContextOptions) -> String {
   format!("{options}")
 }
+
+/// The number of modules matched by the context module that `dependency_id` resolves to
+pub fn context_dependency_modules_count(
+  dependency_id: &DependencyId,
+  module_graph: &ModuleGraph,
+) -> usize {
+  module_graph
+    .module_graph_module_by_dependency_id(dependency_id)
+    .map(|context_module| {
+      context_module
+        .dependencies
+        .iter()
+        .filter(|id| module_graph.module_identifier_by_dependency_id(id).is_some())
+        .count()
+    })
+    .unwrap_or_default()
+}
@@ -1,3 +1,5 @@
+use rspack_sources::Source;
+
 use crate::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
 
 #[derive(Debug, Clone)]
@@ -6,6 +8,9 @@ pub struct ConstDependency {
   pub end: u32,
   pub content: Box<str>,
   pub runtime_requirements: Option<RuntimeGlobals>,
+  /// Also remove the `;` and line break right after the replaced span, used when a whole
+  /// statement is replaced with nothing so no stray `;` or blank line is left behind.
+  pub trim_trailing: bool,
 }
 
 impl ConstDependency {
@@ -20,8 +25,39 @@ impl ConstDependency {
       end,
       content,
       runtime_requirements,
+      trim_trailing: false,
     }
   }
+
+  /// Replaces the span with code that uses `runtime_requirements`, e.g. `__webpack_require__.p`
+  /// for `RuntimeGlobals::PUBLIC_PATH`, which are added to the runtime of the chunk.
+  pub fn with_runtime(
+    start: u32,
+    end: u32,
+    content: Box<str>,
+    runtime_requirements: RuntimeGlobals,
+  ) -> Self {
+    Self::new(start, end, content, Some(runtime_requirements))
+  }
+
+  pub fn trim_trailing(mut self, trim_trailing: bool) -> Self {
+    self.trim_trailing = trim_trailing;
+    self
+  }
+}
+
+fn trailing_len(rest: &str) -> usize {
+  let mut len = 0;
+  if rest.starts_with(';') {
+    len += 1;
+  }
+  let rest = &rest[len..];
+  if rest.starts_with("\r\n") {
+    len += 2;
+  } else if rest.starts_with('\n') {
+    len += 1;
+  }
+  len
 }
 
 impl DependencyTemplate for ConstDependency {
@@ -35,6 +71,28 @@ impl DependencyTemplate for ConstDependency {
         .runtime_requirements
         .insert(*runtime_requirements);
     }
-    source.replace(self.start, self.end, self.content.as_ref(), None);
+    let end = if self.trim_trailing {
+      let original = source.original().source();
+      let rest = original.get(self.end as usize..).unwrap_or_default();
+      self.end + trailing_len(rest) as u32
+    } else {
+      self.end
+    };
+    source.replace(self.start, end, self.content.as_ref(), None);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::trailing_len;
+
+  #[test]
+  fn test_trailing_len() {
+    assert_eq!(trailing_len(";\nfoo"), 2);
+    assert_eq!(trailing_len(";\r\nfoo"), 3);
+    assert_eq!(trailing_len("\nfoo"), 1);
+    assert_eq!(trailing_len("; foo"), 1);
+    assert_eq!(trailing_len(" foo"), 0);
+    assert_eq!(trailing_len(""), 0);
   }
 }
@@ -1,9 +1,10 @@
 use swc_core::ecma::atoms::JsWord;
 
 use crate::{
-  AsDependencyTemplate, Context, ContextMode, ContextOptions, Dependency, DependencyCategory,
-  DependencyId, DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph,
-  ReferencedExport, RuntimeSpec,
+  AsDependencyTemplate, ChunkGroupOptions, ChunkGroupOptionsKindRef, ConnectionState, Context,
+  ContextMode, ContextOptions, Dependency, DependencyCategory, DependencyCondition, DependencyId,
+  DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph, ReferencedExport,
+  RuntimeSpec,
 };
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -17,6 +18,8 @@ pub struct ContextElementDependency {
   pub context: Context,
   pub resource_identifier: String,
   pub referenced_exports: Option<Vec<JsWord>>,
+  /// Shared by all elements of a `lazy-once` context so they end up in the same chunk
+  pub group_options: Option<ChunkGroupOptions>,
 }
 
 impl Dependency for ContextElementDependency {
@@ -80,6 +83,28 @@ impl ModuleDependency for ContextElementDependency {
       vec![ExtendedReferencedExport::Array(vec![])]
     }
   }
+
+  fn group_options(&self) -> Option<ChunkGroupOptionsKindRef> {
+    self
+      .group_options
+      .as_ref()
+      .map(ChunkGroupOptionsKindRef::Normal)
+  }
+
+  fn get_condition(&self) -> Option<DependencyCondition> {
+    // Only the dependency id is captured, the regex is looked up from the module graph when the
+    // connection is evaluated, so cloning the condition stays cheap.
+    let id = self.id;
+    Some(DependencyCondition::Fn(Box::new(
+      move |_mc, _runtime, module_graph: &ModuleGraph| {
+        let dep = module_graph
+          .dependency_by_id(&id)
+          .and_then(|dep| dep.downcast_ref::<ContextElementDependency>())
+          .expect("should be ContextElementDependency");
+        ConnectionState::Bool(dep.options.reg_exp.test(&dep.user_request))
+      },
+    )))
+  }
 }
 
 impl AsDependencyTemplate for ContextElementDependency {}
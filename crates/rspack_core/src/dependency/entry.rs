@@ -1,23 +1,45 @@
 use crate::{
-  AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId, DependencyType,
-  ErrorSpan, ModuleDependency,
+  create_exports_object_referenced, AsDependencyTemplate, Context, Dependency, DependencyCategory,
+  DependencyId, DependencyType, EntryOptions, ErrorSpan, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, RuntimeSpec,
 };
 
+/// The entry a dependency is created for, see [Dependency::entry_meta]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryDependencyMeta<'a> {
+  pub name: Option<&'a str>,
+  pub runtime: Option<&'a str>,
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub struct EntryDependency {
   id: DependencyId,
   request: String,
   context: Context,
+  name: Option<String>,
+  runtime: Option<String>,
+  /// The exports of the entry module exposed by `output.library`, an empty path for the whole
+  /// exports object, `None` if the entry is not a library
+  exports: Option<Vec<String>>,
 }
 
 impl EntryDependency {
-  pub fn new(request: String, context: Context) -> Self {
+  pub fn new(request: String, context: Context, options: &EntryOptions) -> Self {
     Self {
       request,
       context,
+      name: options.name.clone(),
+      runtime: options.runtime.clone(),
+      exports: None,
       id: DependencyId::new(),
     }
   }
+
+  /// `library.export: "default"` exposes `["default"]`, no `library.export` exposes `[]`
+  pub fn with_library_export(mut self, export: Vec<String>) -> Self {
+    self.exports = Some(export);
+    self
+  }
 }
 
 impl Dependency for EntryDependency {
@@ -36,6 +58,17 @@ impl Dependency for EntryDependency {
   fn get_context(&self) -> Option<&Context> {
     Some(&self.context)
   }
+
+  fn is_entry(&self) -> bool {
+    true
+  }
+
+  fn entry_meta(&self) -> Option<EntryDependencyMeta<'_>> {
+    Some(EntryDependencyMeta {
+      name: self.name.as_deref(),
+      runtime: self.runtime.as_deref(),
+    })
+  }
 }
 
 impl ModuleDependency for EntryDependency {
@@ -54,6 +87,20 @@ impl ModuleDependency for EntryDependency {
   fn set_request(&mut self, request: String) {
     self.request = request;
   }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    match &self.exports {
+      None => vec![],
+      Some(export) if export.is_empty() => create_exports_object_referenced(),
+      Some(export) => vec![ExtendedReferencedExport::Array(
+        export.iter().map(|name| name.as_str().into()).collect(),
+      )],
+    }
+  }
 }
 
 impl AsDependencyTemplate for EntryDependency {}
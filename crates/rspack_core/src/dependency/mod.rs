@@ -1,10 +1,11 @@
 mod entry;
 mod span;
-use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering::Relaxed;
 
 pub use entry::*;
 use once_cell::sync::Lazy;
+use rspack_error::{internal_error, Diagnostic};
 use rspack_util::ext::AsAny;
 use rustc_hash::FxHashSet as HashSet;
 use serde::Serialize;
@@ -28,11 +29,13 @@ pub use const_dependency::ConstDependency;
 mod dependency_template;
 pub use dependency_template::*;
 use dyn_clone::{clone_trait_object, DynClone};
+use indexmap::IndexMap;
 
 use crate::{
-  ChunkGroupOptionsKindRef, ConnectionState, Context, ContextMode, ContextOptions, ErrorSpan,
-  ExtendedReferencedExport, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport,
-  RuntimeSpec,
+  create_exports_object_referenced, create_no_exports_referenced, ChunkGroupOptionsKindRef,
+  ConnectionState, Context, ContextMode, ContextOptions, ErrorSpan, ExtendedReferencedExport,
+  ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport, RuntimeSpec,
+  RuntimeSpecSet,
 };
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
@@ -46,12 +49,18 @@ pub enum DependencyType {
   // Harmony import
   EsmImport,
   EsmImportSpecifier,
+  // import with { type: "json" }
+  JsonImport,
   // Harmony export
   EsmExport,
   EsmExportImportedSpecifier,
   EsmExportSpecifier,
   // import()
   DynamicImport,
+  // import() of a module in the chunk of the importer
+  DynamicImportEager,
+  // System.import()
+  SystemImport,
   // cjs require
   CjsRequire,
   // new URL("./foo", import.meta.url)
@@ -66,12 +75,16 @@ pub enum DependencyType {
   ModuleHotAccept,
   // module.hot.decline
   ModuleHotDecline,
+  // __webpack_require__.nmd(module) or __webpack_require__.hmd(module)
+  ModuleDecorator,
   // css url()
   CssUrl,
   // css @import
   CssImport,
   // css modules compose
   CssCompose,
+  /// the locals and `:export` values of a css module
+  CssModuleExport,
   // context element
   ContextElement,
   // import context
@@ -80,14 +93,24 @@ pub enum DependencyType {
   CommonJSRequireContext,
   // require.context
   RequireContext,
+  // import.meta.webpackContext
+  ImportMetaContext,
   // require.resolve
   RequireResolve,
+  // require.resolveWeak
+  RequireResolveWeak,
+  // require.ensure
+  RequireEnsure,
+  // the modules loaded by require.ensure
+  RequireEnsureItem,
   /// wasm import
   WasmImport,
   /// wasm export import
   WasmExportImported,
   /// static exports
   StaticExports,
+  /// top level keys of a json module
+  JsonExports,
   Custom(Box<str>), // TODO it will increase large layout size
 }
 
@@ -101,7 +124,10 @@ impl Display for DependencyType {
       DependencyType::EsmExportSpecifier => write!(f, "esm export specifier"),
       DependencyType::EsmExportImportedSpecifier => write!(f, "esm export import specifier"),
       DependencyType::EsmImportSpecifier => write!(f, "esm import specifier"),
+      DependencyType::JsonImport => write!(f, "json import"),
       DependencyType::DynamicImport => write!(f, "dynamic import"),
+      DependencyType::DynamicImportEager => write!(f, "dynamic import eager"),
+      DependencyType::SystemImport => write!(f, "System.import"),
       DependencyType::CjsRequire => write!(f, "cjs require"),
       DependencyType::NewUrl => write!(f, "new URL()"),
       DependencyType::NewWorker => write!(f, "new Worker()"),
@@ -109,48 +135,165 @@ impl Display for DependencyType {
       DependencyType::ImportMetaHotDecline => write!(f, "import.meta.webpackHot.decline"),
       DependencyType::ModuleHotAccept => write!(f, "module.hot.accept"),
       DependencyType::ModuleHotDecline => write!(f, "module.hot.decline"),
+      DependencyType::ModuleDecorator => write!(f, "module decorator"),
       DependencyType::CssUrl => write!(f, "css url"),
       DependencyType::CssImport => write!(f, "css import"),
       DependencyType::CssCompose => write!(f, "css compose"),
+      DependencyType::CssModuleExport => write!(f, "css module export"),
       DependencyType::ContextElement => write!(f, "context element"),
       DependencyType::ImportContext => write!(f, "import context"),
       DependencyType::CommonJSRequireContext => write!(f, "commonjs require context"),
       DependencyType::RequireContext => write!(f, "require.context"),
+      DependencyType::ImportMetaContext => write!(f, "import.meta.webpackContext"),
       DependencyType::RequireResolve => write!(f, "require.resolve"),
+      DependencyType::RequireResolveWeak => write!(f, "require.resolveWeak"),
+      DependencyType::RequireEnsure => write!(f, "require.ensure"),
+      DependencyType::RequireEnsureItem => write!(f, "require.ensure item"),
       DependencyType::WasmImport => write!(f, "wasm import"),
       DependencyType::WasmExportImported => write!(f, "wasm export imported"),
       DependencyType::StaticExports => write!(f, "static exports"),
+      DependencyType::JsonExports => write!(f, "json exports"),
       DependencyType::Custom(ty) => write!(f, "custom {ty}"),
       DependencyType::ExportInfoApi => write!(f, "export info api"),
     }
   }
 }
 
+impl DependencyType {
+  /// All dependency types defined by rspack itself, `Custom` is not included.
+  pub fn all_builtin() -> &'static [DependencyType] {
+    static ALL_BUILTIN: [DependencyType; 37] = [
+      DependencyType::Unknown,
+      DependencyType::ExportInfoApi,
+      DependencyType::Entry,
+      DependencyType::EsmImport,
+      DependencyType::EsmImportSpecifier,
+      DependencyType::JsonImport,
+      DependencyType::EsmExport,
+      DependencyType::EsmExportImportedSpecifier,
+      DependencyType::EsmExportSpecifier,
+      DependencyType::DynamicImport,
+      DependencyType::DynamicImportEager,
+      DependencyType::SystemImport,
+      DependencyType::CjsRequire,
+      DependencyType::NewUrl,
+      DependencyType::NewWorker,
+      DependencyType::ImportMetaHotAccept,
+      DependencyType::ImportMetaHotDecline,
+      DependencyType::ModuleHotAccept,
+      DependencyType::ModuleHotDecline,
+      DependencyType::ModuleDecorator,
+      DependencyType::CssUrl,
+      DependencyType::CssImport,
+      DependencyType::CssCompose,
+      DependencyType::CssModuleExport,
+      DependencyType::ContextElement,
+      DependencyType::ImportContext,
+      DependencyType::CommonJSRequireContext,
+      DependencyType::RequireContext,
+      DependencyType::ImportMetaContext,
+      DependencyType::RequireResolve,
+      DependencyType::RequireResolveWeak,
+      DependencyType::RequireEnsure,
+      DependencyType::RequireEnsureItem,
+      DependencyType::WasmImport,
+      DependencyType::WasmExportImported,
+      DependencyType::StaticExports,
+      DependencyType::JsonExports,
+    ];
+    &ALL_BUILTIN
+  }
+
+  /// The category that dependencies of this type are resolved with.
+  ///
+  /// `ContextElement` inherits the category of its context, so it maps to `Unknown` here.
+  pub fn as_category(&self) -> DependencyCategory {
+    match self {
+      DependencyType::Entry
+      | DependencyType::EsmImport
+      | DependencyType::EsmImportSpecifier
+      | DependencyType::JsonImport
+      | DependencyType::EsmExport
+      | DependencyType::EsmExportImportedSpecifier
+      | DependencyType::EsmExportSpecifier
+      | DependencyType::DynamicImport
+      | DependencyType::DynamicImportEager
+      | DependencyType::SystemImport
+      | DependencyType::ImportMetaHotAccept
+      | DependencyType::ImportMetaHotDecline
+      | DependencyType::ImportContext
+      | DependencyType::ImportMetaContext => DependencyCategory::Esm,
+      DependencyType::CjsRequire
+      | DependencyType::ModuleHotAccept
+      | DependencyType::ModuleHotDecline
+      | DependencyType::ModuleDecorator
+      | DependencyType::CommonJSRequireContext
+      | DependencyType::RequireContext
+      | DependencyType::RequireResolve
+      | DependencyType::RequireResolveWeak
+      | DependencyType::RequireEnsure
+      | DependencyType::RequireEnsureItem => DependencyCategory::CommonJS,
+      DependencyType::NewUrl => DependencyCategory::Url,
+      DependencyType::CssUrl => DependencyCategory::Asset,
+      DependencyType::NewWorker => DependencyCategory::Worker,
+      DependencyType::CssImport => DependencyCategory::CssImport,
+      DependencyType::CssCompose => DependencyCategory::CssCompose,
+      DependencyType::WasmImport | DependencyType::WasmExportImported => DependencyCategory::Wasm,
+      DependencyType::Unknown
+      | DependencyType::ExportInfoApi
+      | DependencyType::ContextElement
+      | DependencyType::StaticExports
+      | DependencyType::JsonExports
+      | DependencyType::CssModuleExport
+      | DependencyType::Custom(_) => DependencyCategory::Unknown,
+    }
+  }
+}
+
 #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum DependencyCategory {
   #[default]
   Unknown,
   Esm,
   CommonJS,
+  /// `new URL()`, which can point to any kind of module
   Url,
+  /// Dependencies that always resolve to an asset module, e.g. `url()` in css
+  Asset,
   CssImport,
   CssCompose,
   Wasm,
   Worker,
 }
 
-impl From<&str> for DependencyCategory {
-  fn from(value: &str) -> Self {
+impl DependencyCategory {
+  /// The broader category whose `resolve.byDependency` options and `rule.dependency`
+  /// conditions also apply, so configs written before `asset` existed keep working.
+  pub fn parent(&self) -> Option<DependencyCategory> {
+    match self {
+      DependencyCategory::Asset => Some(DependencyCategory::Url),
+      _ => None,
+    }
+  }
+}
+
+impl TryFrom<&str> for DependencyCategory {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "esm" => Self::Esm,
-      "commonjs" => Self::CommonJS,
-      "url" => Self::Url,
-      "wasm" => Self::Wasm,
-      "css-import" => Self::CssImport,
-      "css-compose" => Self::CssCompose,
-      "worker" => Self::Worker,
-      "unknown" => Self::Unknown,
-      _ => unimplemented!("DependencyCategory {}", value),
+      "esm" => Ok(Self::Esm),
+      "commonjs" => Ok(Self::CommonJS),
+      "url" => Ok(Self::Url),
+      "asset" => Ok(Self::Asset),
+      "wasm" => Ok(Self::Wasm),
+      "css-import" => Ok(Self::CssImport),
+      "css-compose" => Ok(Self::CssCompose),
+      "worker" => Ok(Self::Worker),
+      "unknown" => Ok(Self::Unknown),
+      _ => Err(internal_error!(
+        "Unknown dependency category \"{value}\", expected one of \"esm\", \"commonjs\", \"url\", \"asset\", \"wasm\", \"css-import\", \"css-compose\", \"worker\" or \"unknown\""
+      )),
     }
   }
 }
@@ -162,6 +305,7 @@ impl Display for DependencyCategory {
       DependencyCategory::Esm => write!(f, "esm"),
       DependencyCategory::CommonJS => write!(f, "commonjs"),
       DependencyCategory::Url => write!(f, "url"),
+      DependencyCategory::Asset => write!(f, "asset"),
       DependencyCategory::CssImport => write!(f, "css-import"),
       DependencyCategory::CssCompose => write!(f, "css-compose"),
       DependencyCategory::Wasm => write!(f, "wasm"),
@@ -190,6 +334,40 @@ pub trait Dependency:
   fn get_exports(&self) -> Option<ExportsSpec> {
     None
   }
+
+  /// Warnings or errors about this dependency, collected once all modules are built
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    None
+  }
+
+  fn is_entry(&self) -> bool {
+    false
+  }
+
+  /// Name and runtime of the entry, only for entry dependencies
+  fn entry_meta(&self) -> Option<EntryDependencyMeta<'_>> {
+    None
+  }
+
+  /// The dependency is resolved and built like any other, but its connection stays
+  /// inactive, it's never rendered and the module it points to isn't added to chunks.
+  fn build_time_only(&self) -> bool {
+    false
+  }
+
+  /// How many modules the dependency brings in, e.g. a context dependency brings in every
+  /// module its context matches
+  fn modules_count(&self, _module_graph: &ModuleGraph) -> usize {
+    1
+  }
+
+  /// Whether the module the dependency belongs to can be reused from the build cache. A
+  /// dependency that holds data which may change while the module's files don't, e.g. a
+  /// timestamp, returns false. The module is then never stored in the cache and is built again
+  /// on every rebuild, a cached module without some of its dependencies would be wrong.
+  fn cacheable(&self) -> bool {
+    true
+  }
 }
 
 #[derive(Debug, Default)]
@@ -252,6 +430,8 @@ pub enum ExportsReferencedType {
   Object, // EXPORTS_OBJECT_REFERENCED
   String(Box<Vec<Vec<JsWord>>>),
   Value(Box<Vec<ReferencedExport>>),
+  /// Only these paths of the namespace object are referenced, the rest may be dropped
+  Partial(Box<Vec<Vec<JsWord>>>),
 }
 
 impl From<JsWord> for ExportsReferencedType {
@@ -278,6 +458,27 @@ impl From<Vec<ReferencedExport>> for ExportsReferencedType {
   }
 }
 
+impl From<ExportsReferencedType> for Vec<ExtendedReferencedExport> {
+  fn from(value: ExportsReferencedType) -> Self {
+    match value {
+      ExportsReferencedType::No => create_no_exports_referenced(),
+      ExportsReferencedType::Object => create_exports_object_referenced(),
+      ExportsReferencedType::String(paths) => paths
+        .into_iter()
+        .map(ExtendedReferencedExport::Array)
+        .collect(),
+      ExportsReferencedType::Value(exports) => exports
+        .into_iter()
+        .map(ExtendedReferencedExport::Export)
+        .collect(),
+      ExportsReferencedType::Partial(paths) => paths
+        .into_iter()
+        .map(|path| ExtendedReferencedExport::Export(ReferencedExport::new(path, false)))
+        .collect(),
+    }
+  }
+}
+
 pub trait AsModuleDependency {
   fn as_module_dependency(&self) -> Option<&dyn ModuleDependency> {
     None
@@ -328,6 +529,7 @@ impl Clone for Box<dyn Function> {
 
 #[derive(Clone)]
 pub enum DependencyCondition {
+  True,
   False,
   Fn(DependencyConditionFn),
 }
@@ -336,12 +538,151 @@ impl Debug for DependencyCondition {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
       // Self::Nil => write!(f, "Nil"),
+      Self::True => write!(f, "True"),
       Self::False => write!(f, "False"),
       Self::Fn(_) => write!(f, "Fn"),
     }
   }
 }
 
+impl DependencyCondition {
+  pub fn from_fn<F>(f: F) -> Self
+  where
+    F: 'static
+      + Fn(&ModuleGraphConnection, Option<&RuntimeSpec>, &ModuleGraph) -> ConnectionState
+      + Send
+      + Sync
+      + Clone,
+  {
+    Self::Fn(Box::new(f))
+  }
+
+  /// Active only when every condition is, stops at the first inactive one.
+  pub fn all(conditions: impl IntoIterator<Item = DependencyCondition>) -> Self {
+    let mut conditions = conditions
+      .into_iter()
+      .filter(|condition| !matches!(condition, Self::True))
+      .collect::<Vec<_>>();
+    if conditions
+      .iter()
+      .any(|condition| matches!(condition, Self::False))
+    {
+      return Self::False;
+    }
+    if conditions.len() <= 1 {
+      return conditions.pop().unwrap_or(Self::True);
+    }
+    Self::from_fn(move |connection, runtime, module_graph| {
+      let mut state = ConnectionState::Bool(true);
+      for condition in &conditions {
+        let current = condition.get_state(connection, runtime, module_graph);
+        if matches!(current, ConnectionState::Bool(false)) {
+          return current;
+        }
+        if connection_state_rank(&current) < connection_state_rank(&state) {
+          state = current;
+        }
+      }
+      state
+    })
+  }
+
+  /// Active when any condition is, stops at the first active one.
+  pub fn any(conditions: impl IntoIterator<Item = DependencyCondition>) -> Self {
+    let mut conditions = conditions
+      .into_iter()
+      .filter(|condition| !matches!(condition, Self::False))
+      .collect::<Vec<_>>();
+    if conditions
+      .iter()
+      .any(|condition| matches!(condition, Self::True))
+    {
+      return Self::True;
+    }
+    if conditions.len() <= 1 {
+      return conditions.pop().unwrap_or(Self::False);
+    }
+    Self::from_fn(move |connection, runtime, module_graph| {
+      let mut state = ConnectionState::Bool(false);
+      for condition in &conditions {
+        let current = condition.get_state(connection, runtime, module_graph);
+        if current.is_true() {
+          return current;
+        }
+        if connection_state_rank(&current) > connection_state_rank(&state) {
+          state = current;
+        }
+      }
+      state
+    })
+  }
+
+  /// Active when the condition is inactive. A condition that is only transitively or circularly
+  /// active is still active, so its negation is inactive.
+  pub fn not(condition: DependencyCondition) -> Self {
+    match condition {
+      Self::True => Self::False,
+      Self::False => Self::True,
+      Self::Fn(f) => Self::from_fn(move |connection, runtime, module_graph| {
+        ConnectionState::Bool(!f(connection, runtime, module_graph).is_not_false())
+      }),
+    }
+  }
+
+  pub fn get_state(
+    &self,
+    connection: &ModuleGraphConnection,
+    runtime: Option<&RuntimeSpec>,
+    module_graph: &ModuleGraph,
+  ) -> ConnectionState {
+    match self {
+      Self::True => ConnectionState::Bool(true),
+      Self::False => ConnectionState::Bool(false),
+      Self::Fn(f) => f(connection, runtime, module_graph),
+    }
+  }
+
+  /// Evaluates the condition in each of the runtimes of e.g. a chunk shared by several entries,
+  /// the dependency is as active as in the runtime it is most active in.
+  pub fn get_state_in_runtimes(
+    &self,
+    connection: &ModuleGraphConnection,
+    runtimes: &RuntimeSpecSet,
+    module_graph: &ModuleGraph,
+  ) -> ConnectionState {
+    let Self::Fn(f) = self else {
+      return self.get_state(connection, None, module_graph);
+    };
+    if runtimes.is_empty() {
+      return f(connection, None, module_graph);
+    }
+    let mut state = ConnectionState::Bool(false);
+    for runtime in runtimes.values() {
+      let current = f(connection, Some(runtime), module_graph);
+      if current.is_true() {
+        return current;
+      }
+      if connection_state_rank(&current) > connection_state_rank(&state) {
+        state = current;
+      }
+    }
+    state
+  }
+}
+
+/// Orders the states the same way webpack does, from inactive to active.
+fn connection_state_rank(state: &ConnectionState) -> u8 {
+  match state {
+    ConnectionState::Bool(false) => 0,
+    ConnectionState::TransitiveOnly => 1,
+    ConnectionState::CircularConnection => 2,
+    ConnectionState::Bool(true) => 3,
+  }
+}
+
+/// Import attributes of `import x from "./x" with { type: "css" }`, in source order.
+pub type ImportAttributes = IndexMap<JsWord, JsWord>;
+
 pub trait ModuleDependency: Dependency {
   fn request(&self) -> &str;
   fn user_request(&self) -> &str;
@@ -359,6 +700,10 @@ pub trait ModuleDependency: Dependency {
     false
   }
 
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    None
+  }
+
   // TODO: wired to place ChunkGroupOptions on dependency, should place on AsyncDependenciesBlock
   fn group_options(&self) -> Option<ChunkGroupOptionsKindRef> {
     None
@@ -407,7 +752,12 @@ pub type BoxModuleDependency = Box<dyn ModuleDependency>;
 pub type BoxDependency = Box<dyn Dependency>;
 
 pub fn is_async_dependency(dep: &dyn ModuleDependency) -> bool {
-  if matches!(dep.dependency_type(), DependencyType::DynamicImport) {
+  if matches!(
+    dep.dependency_type(),
+    DependencyType::DynamicImport
+      | DependencyType::SystemImport
+      | DependencyType::RequireEnsureItem
+  ) {
     return true;
   }
   if matches!(dep.dependency_type(), DependencyType::NewWorker) {
@@ -422,14 +772,26 @@ pub fn is_async_dependency(dep: &dyn ModuleDependency) -> bool {
 }
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize)]
-pub struct DependencyId(u32);
+pub struct DependencyId(u64);
 
-pub static DEPENDENCY_ID: Lazy<AtomicU32> = Lazy::new(|| AtomicU32::new(0));
+/// Dependency ids are allocated process wide, so the same build gets different ids each time it
+/// runs in a long-lived process, see [`DependencyId::reset_allocation`]. The counter is a `u64`
+/// so a process that keeps rebuilding for weeks never wraps around and reuses an id.
+pub static DEPENDENCY_ID: Lazy<AtomicU64> = Lazy::new(|| AtomicU64::new(0));
 
 impl DependencyId {
   pub fn new() -> Self {
     Self(DEPENDENCY_ID.fetch_add(1, Relaxed))
   }
+
+  /// Restarts the allocation of dependency ids from 0, so two sequential builds in one process
+  /// get identical ids.
+  ///
+  /// Only call this when no compilation is alive, including the one kept by a compiler for
+  /// rebuilds, their dependencies would share ids with the newly created ones.
+  pub fn reset_allocation() {
+    DEPENDENCY_ID.store(0, Relaxed);
+  }
 }
 impl Default for DependencyId {
   fn default() -> Self {
@@ -438,7 +800,7 @@ impl Default for DependencyId {
 }
 
 impl std::ops::Deref for DependencyId {
-  type Target = u32;
+  type Target = u64;
 
   fn deref(&self) -> &Self::Target {
     &self.0
@@ -447,6 +809,12 @@ impl std::ops::Deref for DependencyId {
 
 impl From<u32> for DependencyId {
   fn from(id: u32) -> Self {
+    Self(id.into())
+  }
+}
+
+impl From<u64> for DependencyId {
+  fn from(id: u64) -> Self {
     Self(id)
   }
 }
@@ -455,15 +823,17 @@ impl From<u32> for DependencyId {
 pub mod needs_refactor {
   use once_cell::sync::Lazy;
   use regex::Regex;
+  use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
   use swc_core::{
-    common::{EqIgnoreSpan, Spanned, SyntaxContext, DUMMY_SP},
+    common::{EqIgnoreSpan, Span, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
       ast::{
-        Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp, MetaPropExpr,
-        MetaPropKind, ModuleExportName, NewExpr,
+        AssignExpr, AssignPatProp, BindingIdent, Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit,
+        MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, ModuleExportName, NewExpr, Pat,
+        PatOrExpr, UpdateExpr, VarDeclarator,
       },
       atoms::{js_word, JsWord},
-      visit::Visit,
+      visit::{Visit, VisitWith},
     },
   };
 
@@ -507,6 +877,17 @@ pub mod needs_refactor {
   pub struct WorkerSyntaxList {
     variables: Vec<WorkerSyntax>,
     globals: Vec<WorkerSyntax>,
+    /// `const url = new URL("./foo.worker.js", import.meta.url)` bindings that are passed to
+    /// `new Worker(url)` and never reassigned
+    worker_urls: HashMap<Id, WorkerUrl>,
+  }
+
+  #[derive(Debug, Clone)]
+  struct WorkerUrl {
+    span: Span,
+    start: u32,
+    end: u32,
+    request: String,
   }
 
   impl WorkerSyntaxList {
@@ -525,6 +906,23 @@ pub mod needs_refactor {
     pub fn match_new_worker(&self, new_expr: &NewExpr) -> bool {
       matches!(&*new_expr.callee, Expr::Ident(ident) if self.find_worker_syntax(ident).is_some())
     }
+
+    /// Same as [`match_new_url`] for the `new URL()` that `ident` is bound to, if it is the url
+    /// of a worker.
+    pub fn match_worker_url_variable(&self, ident: &Ident) -> Option<(u32, u32, String)> {
+      self
+        .worker_urls
+        .get(&ident.to_id())
+        .map(|url| (url.start, url.end, url.request.clone()))
+    }
+
+    /// Whether `new_expr` is a `new URL()` bound to a variable that is the url of a worker.
+    pub fn is_worker_url(&self, new_expr: &NewExpr) -> bool {
+      self
+        .worker_urls
+        .values()
+        .any(|url| url.span == new_expr.span)
+    }
   }
 
   impl Extend<WorkerSyntax> for WorkerSyntaxList {
@@ -537,7 +935,17 @@ pub mod needs_refactor {
 
   impl From<WorkerSyntaxScanner<'_>> for WorkerSyntaxList {
     fn from(value: WorkerSyntaxScanner) -> Self {
-      value.result
+      let mut result = value.result;
+      // `Worker` may be imported after it is used, so the callee is only checked here
+      for (callee, arg) in &value.new_expr_ident_args {
+        if value.reassigned.contains(arg) || result.find_worker_syntax(callee).is_none() {
+          continue;
+        }
+        if let Some(url) = value.url_variables.get(arg) {
+          result.worker_urls.insert(arg.clone(), url.clone());
+        }
+      }
+      result
     }
   }
 
@@ -565,6 +973,10 @@ pub mod needs_refactor {
   pub struct WorkerSyntaxScanner<'a> {
     result: WorkerSyntaxList,
     caps: Vec<(&'a str, &'a str)>,
+    url_variables: HashMap<Id, WorkerUrl>,
+    reassigned: HashSet<Id>,
+    /// `new X(ident)`, the callee and the first argument
+    new_expr_ident_args: Vec<(Ident, Id)>,
   }
 
   pub const DEFAULT_WORKER_SYNTAX: &[&str] =
@@ -583,17 +995,53 @@ pub mod needs_refactor {
           result.push(WorkerSyntax::new(JsWord::from(*s), None))
         }
       }
-      Self { result, caps }
+      Self {
+        result,
+        caps,
+        url_variables: Default::default(),
+        reassigned: Default::default(),
+        new_expr_ident_args: Default::default(),
+      }
+    }
+  }
+
+  /// Collects the bindings written by the left hand side of an assignment.
+  struct AssignedIdents<'a>(&'a mut HashSet<Id>);
+
+  impl Visit for AssignedIdents<'_> {
+    fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+      self.0.insert(ident.id.to_id());
+    }
+
+    fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp) {
+      self.0.insert(prop.key.to_id());
+    }
+
+    fn visit_pat(&mut self, pat: &Pat) {
+      match pat {
+        Pat::Expr(box Expr::Ident(ident)) => {
+          self.0.insert(ident.to_id());
+        }
+        _ => pat.visit_children_with(self),
+      }
     }
+
+    // default values and computed keys are not written
+    fn visit_expr(&mut self, _: &Expr) {}
+  }
+
+  /// `node:worker_threads` is the same module as `worker_threads`
+  fn strip_node_prefix(source: &str) -> &str {
+    source.strip_prefix("node:").unwrap_or(source)
   }
 
   impl Visit for WorkerSyntaxScanner<'_> {
     fn visit_import_decl(&mut self, decl: &ImportDecl) {
-      let source = &*decl.src.value;
+      let source = strip_node_prefix(&decl.src.value);
       let found = self
         .caps
         .iter()
-        .filter(|cap| cap.1 == source)
+        .filter(|cap| strip_node_prefix(cap.1) == source)
         .flat_map(|cap| {
           if cap.0 == "default" {
             decl
@@ -626,5 +1074,130 @@ pub mod needs_refactor {
         .map(|pair| WorkerSyntax::new(pair.0, Some(pair.1)));
       self.result.extend(found);
     }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+      if let Pat::Ident(ident) = &declarator.name
+      && let Some(box Expr::New(new_expr)) = &declarator.init
+      && let Some((start, end, request)) = match_new_url(new_expr) {
+        let url = WorkerUrl {
+          span: new_expr.span,
+          start,
+          end,
+          request,
+        };
+        // `var url` declared twice is as good as reassigned
+        if self.url_variables.insert(ident.id.to_id(), url).is_some() {
+          self.reassigned.insert(ident.id.to_id());
+        }
+      }
+      declarator.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+      match &assign_expr.left {
+        PatOrExpr::Expr(box Expr::Ident(ident)) => {
+          self.reassigned.insert(ident.to_id());
+        }
+        PatOrExpr::Pat(pat) => pat.visit_with(&mut AssignedIdents(&mut self.reassigned)),
+        PatOrExpr::Expr(_) => {}
+      }
+      assign_expr.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update_expr: &UpdateExpr) {
+      if let Expr::Ident(ident) = &*update_expr.arg {
+        self.reassigned.insert(ident.to_id());
+      }
+      update_expr.visit_children_with(self);
+    }
+
+    fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+      if let Expr::Ident(callee) = &*new_expr.callee
+      && let Some(args) = &new_expr.args
+      && let Some(ExprOrSpread { spread: None, expr: box Expr::Ident(arg) }) = args.first() {
+        self.new_expr_ident_args.push((callee.clone(), arg.to_id()));
+      }
+      new_expr.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{DependencyCategory, DependencyCondition, DependencyId};
+  use crate::{ConnectionState, ModuleGraph, ModuleGraphConnection, RuntimeSpec, RuntimeSpecSet};
+
+  fn test_connection() -> ModuleGraphConnection {
+    ModuleGraphConnection::new(None, DependencyId::new(), "module".into(), true, true)
+  }
+
+  fn condition_state(condition: &DependencyCondition, runtime: Option<&RuntimeSpec>) -> bool {
+    condition
+      .get_state(&test_connection(), runtime, &ModuleGraph::default())
+      .is_true()
+  }
+
+  #[test]
+  fn test_dependency_category_try_from() {
+    for category in [
+      "esm",
+      "commonjs",
+      "url",
+      "asset",
+      "wasm",
+      "css-import",
+      "css-compose",
+      "worker",
+    ] {
+      let parsed = DependencyCategory::try_from(category).expect("should be a known category");
+      assert_eq!(parsed.to_string(), category);
+    }
+    assert!(DependencyCategory::try_from("common-js").is_err());
+  }
+
+  #[test]
+  fn test_dependency_condition_combinators() {
+    let in_main = DependencyCondition::from_fn(|_, runtime, _| {
+      ConnectionState::Bool(runtime.is_some_and(|runtime| runtime.contains("main")))
+    });
+    let weak = DependencyCondition::False;
+    let condition =
+      DependencyCondition::all([in_main.clone(), DependencyCondition::not(weak.clone())]);
+    let main = RuntimeSpec::from_iter(["main".into()]);
+    let other = RuntimeSpec::from_iter(["other".into()]);
+    assert!(condition_state(&condition, Some(&main)));
+    assert!(!condition_state(&condition, Some(&other)));
+    assert!(condition_state(
+      &DependencyCondition::any([weak.clone(), DependencyCondition::not(in_main.clone())]),
+      Some(&other)
+    ));
+    assert!(matches!(
+      DependencyCondition::all([in_main.clone(), weak]),
+      DependencyCondition::False
+    ));
+    assert!(matches!(
+      DependencyCondition::any([in_main, DependencyCondition::True]),
+      DependencyCondition::True
+    ));
+    assert!(matches!(DependencyCondition::all([]), DependencyCondition::True));
+  }
+
+  #[test]
+  fn test_dependency_condition_in_runtimes() {
+    let in_modern = DependencyCondition::from_fn(|_, runtime, _| {
+      ConnectionState::Bool(runtime.is_some_and(|runtime| runtime.contains("modern")))
+    });
+    let state_in = |names: &[&str]| {
+      let mut runtimes = RuntimeSpecSet::default();
+      for name in names {
+        runtimes.set(RuntimeSpec::from_iter([(*name).into()]));
+      }
+      in_modern
+        .get_state_in_runtimes(&test_connection(), &runtimes, &ModuleGraph::default())
+        .is_true()
+    };
+    assert!(state_in(&["legacy", "modern"]));
+    assert!(!state_in(&["legacy"]));
+    assert!(!state_in(&[]));
   }
 }
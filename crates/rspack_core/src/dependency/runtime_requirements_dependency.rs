@@ -23,4 +23,10 @@ impl RuntimeRequirementsDependency {
       runtime_requirements,
     }
   }
+
+  /// Several runtime globals needed by one feature in a single dependency, they are all added
+  /// to the runtime requirements of the module
+  pub fn from_set(runtime_requirements: impl IntoIterator<Item = RuntimeGlobals>) -> Self {
+    Self::new(runtime_requirements.into_iter().collect())
+  }
 }
@@ -232,11 +232,13 @@ pub fn import_statement(
   (import_content, "".to_string())
 }
 
+/// `eager` modules are in the chunk of the importer, the promise resolves without loading a chunk
 pub fn module_namespace_promise(
   code_generatable_context: &mut TemplateContext,
   id: &DependencyId,
   request: &str,
   weak: bool,
+  eager: bool,
 ) -> String {
   let TemplateContext {
     runtime_requirements,
@@ -318,10 +320,12 @@ pub fn module_namespace_promise(
     }
   }
 
-  format!(
-    "{}{appending}",
+  let promise = if eager {
+    "Promise.resolve()".to_string()
+  } else {
     block_promise(&module_id_expr, runtime_requirements)
-  )
+  };
+  format!("{promise}{appending}")
 }
 
 pub fn block_promise(module_id_str: &str, runtime_requirements: &mut RuntimeGlobals) -> String {
@@ -811,9 +811,33 @@ impl ExportInfo {
     }
   }
 
-  // TODO
-  pub fn get_used(&self, _runtime: Option<&RuntimeSpec>) -> UsageState {
-    UsageState::Unused
+  pub fn get_used(&self, runtime: Option<&RuntimeSpec>) -> UsageState {
+    if !self.has_use_in_runtime_info {
+      return UsageState::NoInfo;
+    }
+    if let Some(global_used) = self.global_used {
+      return global_used;
+    }
+    let Some(used_in_runtime) = &self.used_in_runtime else {
+      return UsageState::Unused;
+    };
+    let values: Vec<UsageState> = match runtime {
+      Some(runtime) => runtime
+        .iter()
+        .filter_map(|item| used_in_runtime.get(item.as_ref()).copied())
+        .collect(),
+      None => used_in_runtime.values().copied().collect(),
+    };
+    let mut max = UsageState::Unused;
+    for value in values {
+      if value == UsageState::Used {
+        return UsageState::Used;
+      }
+      if max < value {
+        max = value;
+      }
+    }
+    max
   }
 
   /// Webpack returns `false | string`, we use `Option<JsWord>` to avoid declare a redundant enum
@@ -849,6 +873,77 @@ impl ExportInfo {
       .map(|id| module_graph.get_exports_info(&id))
   }
 
+  pub fn has_target(&self, key: &DependencyId) -> bool {
+    self.target.contains_key(key)
+  }
+
+  /// Read only counterpart of `get_max_target`, `None` if the targets with the highest
+  /// priority point to different places.
+  fn get_unique_max_target(&self) -> Option<ResolvedExportInfoTarget> {
+    let max_priority = self.target.values().map(|value| value.priority).max()?;
+    let mut values = self
+      .target
+      .values()
+      .filter(|value| value.priority == max_priority);
+    let first = values.next()?;
+    let connection = first.connection?;
+    if values.any(|value| {
+      value.connection.map(|c| c.module_identifier) != Some(connection.module_identifier)
+        || value.exports != first.exports
+    }) {
+      return None;
+    }
+    Some(ResolvedExportInfoTarget {
+      module: connection.module_identifier,
+      exports: first.exports.clone(),
+      connection,
+    })
+  }
+
+  /// Follow the re-exports of this export, e.g. `a -> b -> c`, until an export that is a
+  /// terminal binding or has no target anymore. Unlike `get_target`, this does not need a
+  /// mutable module graph.
+  pub fn get_terminal_binding(
+    &self,
+    mg: &ModuleGraph,
+  ) -> Option<ResolvedExportInfoTargetWithCircular> {
+    let mut already_visited = HashSet::default();
+    already_visited.insert(self.id);
+    let mut target = self.get_unique_max_target()?;
+    loop {
+      // `export * as ns from './b'` ends at the namespace object of `b`
+      let Some(name) = target.exports.as_ref().and_then(|exports| exports.first()) else {
+        return Some(ResolvedExportInfoTargetWithCircular::Target(target));
+      };
+      let export_info = mg
+        .get_exports_info(&target.module)
+        .id
+        .get_read_only_export_info(name, mg);
+      if export_info.terminal_binding {
+        return Some(ResolvedExportInfoTargetWithCircular::Target(target));
+      }
+      if !already_visited.insert(export_info.id) {
+        return Some(ResolvedExportInfoTargetWithCircular::Circular);
+      }
+      let Some(next) = export_info.get_unique_max_target() else {
+        return Some(ResolvedExportInfoTargetWithCircular::Target(target));
+      };
+      // SAFETY: `name` is the first item, so `exports` is not empty
+      let rest = &target.exports.as_ref().expect("should have exports")[1..];
+      target = ResolvedExportInfoTarget {
+        exports: match next.exports {
+          Some(mut exports) => {
+            exports.extend_from_slice(rest);
+            Some(exports)
+          }
+          None if rest.is_empty() => None,
+          None => Some(rest.to_vec()),
+        },
+        ..next
+      };
+    }
+  }
+
   pub fn unuset_target(&mut self, key: &DependencyId) -> bool {
     if self.target.is_empty() {
       false
@@ -890,6 +985,8 @@ impl ExportInfo {
         map.insert(*k, v.clone());
       }
     }
+    self.max_target_is_set = true;
+    self.max_target = map;
     &self.max_target
   }
 
@@ -955,6 +1052,11 @@ impl ExportInfo {
           if already_visited.contains(&export_info_id) {
             return Some(ResolvedExportInfoTargetWithCircular::Circular);
           }
+          // the chain ends at a terminal binding even when it re-exports another module, e.g. the
+          // export of a shim module a plugin injects in place of the original one
+          if mg.get_export_info_by_id(&export_info_id).terminal_binding {
+            return Some(ResolvedExportInfoTargetWithCircular::Target(target));
+          }
           let mut export_info = mg
             .export_info_map
             .get_mut(&export_info_id)
@@ -1069,6 +1171,8 @@ impl ExportInfo {
           priority: normalized_priority,
         },
       );
+      self.max_target.clear();
+      self.max_target_is_set = false;
       return true;
     }
     if let Some(old_target) = self.target.get_mut(key) {
@@ -1336,3 +1440,236 @@ pub fn debug_exports_info(module_graph: &ModuleGraph) {
     }
   }
 }
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReferenceReport {
+  pub module: Option<String>,
+  pub dependency_id: DependencyId,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportUsageReport {
+  pub name: String,
+  pub used: bool,
+  pub referenced_by: Vec<ExportReferenceReport>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleExportsUsageReport {
+  pub module: String,
+  pub exports: Vec<ExportUsageReport>,
+}
+
+/// Used and unused exports of every module together with the dependencies referencing them,
+/// modules, exports and references are sorted so the result can be diffed across builds.
+pub fn get_exports_usage_report(module_graph: &ModuleGraph) -> Vec<ModuleExportsUsageReport> {
+  let mut report = module_graph
+    .module_graph_modules()
+    .values()
+    .map(|mgm| {
+      let exports_info = module_graph.get_exports_info_by_id(&mgm.exports);
+      let mut referenced_by: HashMap<JsWord, Vec<ExportReferenceReport>> = HashMap::default();
+      for connection in mgm
+        .incoming_connections
+        .iter()
+        .filter_map(|id| module_graph.connection_by_connection_id(id))
+      {
+        let Some(dependency) = module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dep| dep.as_module_dependency())
+        else {
+          continue;
+        };
+        for referenced in dependency.get_referenced_exports(module_graph, None) {
+          let name = match &referenced {
+            ExtendedReferencedExport::Array(name) => name.first(),
+            ExtendedReferencedExport::Export(export) => export.name.first(),
+          };
+          // an empty path references the exports object, so every export is referenced
+          let names = match name {
+            Some(name) => vec![name.clone()],
+            None => exports_info.exports.keys().cloned().collect(),
+          };
+          for name in names {
+            referenced_by
+              .entry(name)
+              .or_default()
+              .push(ExportReferenceReport {
+                module: connection
+                  .original_module_identifier
+                  .map(|identifier| identifier.to_string()),
+                dependency_id: connection.dependency_id,
+              });
+          }
+        }
+      }
+      let mut exports = exports_info
+        .exports
+        .iter()
+        .map(|(name, id)| {
+          let mut referenced_by = referenced_by.remove(name).unwrap_or_default();
+          referenced_by.sort_by(|a, b| {
+            a.module
+              .cmp(&b.module)
+              .then_with(|| a.dependency_id.cmp(&b.dependency_id))
+          });
+          referenced_by.dedup_by(|a, b| a.dependency_id == b.dependency_id);
+          ExportUsageReport {
+            name: name.to_string(),
+            used: module_graph.get_export_info_by_id(id).get_used(None) != UsageState::Unused,
+            referenced_by,
+          }
+        })
+        .collect::<Vec<_>>();
+      exports.sort_by(|a, b| a.name.cmp(&b.name));
+      ModuleExportsUsageReport {
+        module: mgm.module_identifier.to_string(),
+        exports,
+      }
+    })
+    .collect::<Vec<_>>();
+  report.sort_by(|a, b| a.module.cmp(&b.module));
+  report
+}
+
+#[cfg(test)]
+mod test {
+  use super::{ExportInfo, ExportsInfo, UsageState};
+  use crate::{
+    DependencyId, ModuleGraph, ModuleGraphConnection, ModuleGraphModule, ModuleIdentifier,
+    ModuleType, RuntimeSpec,
+  };
+
+  #[test]
+  fn test_get_used() {
+    let mut export_info = ExportInfo::new(Some("x".into()), UsageState::Unknown, None);
+    // usage has not been flagged yet
+    assert_eq!(export_info.get_used(None), UsageState::NoInfo);
+
+    export_info.has_use_in_runtime_info = true;
+    assert_eq!(export_info.get_used(None), UsageState::Unused);
+
+    export_info.used_in_runtime = Some(
+      [
+        ("main".to_string(), UsageState::OnlyPropertiesUsed),
+        ("worker".to_string(), UsageState::Used),
+      ]
+      .into_iter()
+      .collect(),
+    );
+    let runtime = |names: &[&str]| RuntimeSpec::from_iter(names.iter().map(|name| (*name).into()));
+    assert_eq!(
+      export_info.get_used(Some(&runtime(&["main"]))),
+      UsageState::OnlyPropertiesUsed
+    );
+    assert_eq!(
+      export_info.get_used(Some(&runtime(&["main", "worker"]))),
+      UsageState::Used
+    );
+    assert_eq!(
+      export_info.get_used(Some(&runtime(&["other"]))),
+      UsageState::Unused
+    );
+    // without a runtime, the usage in any runtime counts
+    assert_eq!(export_info.get_used(None), UsageState::Used);
+
+    // usage flagged without a runtime takes precedence
+    export_info.global_used = Some(UsageState::Unused);
+    assert_eq!(
+      export_info.get_used(Some(&runtime(&["worker"]))),
+      UsageState::Unused
+    );
+  }
+
+  fn set_reexport_target(
+    export_info: &mut ExportInfo,
+    module_identifier: &str,
+    priority: Option<u8>,
+  ) -> ModuleIdentifier {
+    let dependency_id = DependencyId::new();
+    let module_identifier = ModuleIdentifier::from(module_identifier);
+    export_info.set_target(
+      &dependency_id,
+      Some(ModuleGraphConnection::new(
+        None,
+        dependency_id,
+        module_identifier,
+        true,
+        false,
+      )),
+      Some(&vec!["x".into()]),
+      priority,
+    );
+    module_identifier
+  }
+
+  #[test]
+  fn test_reexport_target_priority() {
+    let mut mg = ModuleGraph::default();
+    let mut export_info = ExportInfo::new(Some("x".into()), UsageState::Unknown, None);
+    set_reexport_target(&mut export_info, "low", None);
+    let high = set_reexport_target(&mut export_info, "high", Some(1));
+    // do not follow the targets, the modules are not in the module graph
+    let target = export_info
+      .get_target(&mut mg, Some(Box::new(|_: &_| false)))
+      .expect("should have a target");
+    assert_eq!(target.module, high);
+
+    // different modules with the same priority make the export ambiguous
+    let mut export_info = ExportInfo::new(Some("x".into()), UsageState::Unknown, None);
+    set_reexport_target(&mut export_info, "a", Some(1));
+    set_reexport_target(&mut export_info, "b", Some(1));
+    assert!(export_info
+      .get_target(&mut mg, Some(Box::new(|_: &_| false)))
+      .is_none());
+  }
+
+  fn add_module_graph_module(mg: &mut ModuleGraph, module_identifier: &str) -> ModuleIdentifier {
+    let other_exports_info = ExportInfo::new(None, UsageState::Unknown, None);
+    let side_effects_only_info = ExportInfo::new(
+      Some("*side effects only*".into()),
+      UsageState::Unknown,
+      None,
+    );
+    let exports_info = ExportsInfo::new(other_exports_info.id, side_effects_only_info.id);
+    let module_identifier = ModuleIdentifier::from(module_identifier);
+    mg.add_module_graph_module(ModuleGraphModule::new(
+      module_identifier,
+      ModuleType::Js,
+      exports_info.id,
+    ));
+    mg.export_info_map
+      .insert(other_exports_info.id, other_exports_info);
+    mg.export_info_map
+      .insert(side_effects_only_info.id, side_effects_only_info);
+    mg.exports_info_map.insert(exports_info.id, exports_info);
+    module_identifier
+  }
+
+  #[test]
+  fn test_reexport_target_stops_at_terminal_binding() {
+    let mut mg = ModuleGraph::default();
+    let shim = add_module_graph_module(&mut mg, "shim");
+    add_module_graph_module(&mut mg, "original");
+
+    // the shim re-exports `x` of the original module, but a plugin marks it as a terminal binding
+    let shim_exports_info_id = mg.get_exports_info(&shim).id;
+    let shim_export_info_id = shim_exports_info_id.get_export_info(&"x".into(), &mut mg);
+    let mut shim_export_info = mg.get_export_info_by_id(&shim_export_info_id).clone();
+    set_reexport_target(&mut shim_export_info, "original", None);
+    shim_export_info.terminal_binding = true;
+    mg.export_info_map
+      .insert(shim_export_info_id, shim_export_info);
+
+    let mut export_info = ExportInfo::new(Some("x".into()), UsageState::Unknown, None);
+    set_reexport_target(&mut export_info, "shim", None);
+    let target = export_info
+      .get_target(&mut mg, None)
+      .expect("should have a target");
+    assert_eq!(target.module, shim);
+    assert_eq!(target.exports, Some(vec!["x".into()]));
+  }
+}
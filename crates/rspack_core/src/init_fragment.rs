@@ -1,6 +1,9 @@
+use std::hash::BuildHasherDefault;
+
+use indexmap::IndexMap;
 use rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_util::ext::AsAny;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::atoms::JsWord;
 
 use crate::{ExportsArgument, RuntimeGlobals};
@@ -13,6 +16,9 @@ pub type BoxInitFragment = Box<dyn InitFragment>;
 pub struct NormalInitFragment {
   pub content: String,
   pub stage: InitFragmentStage,
+  /// Orders fragments within the same stage, lower first, fragments with the same position
+  /// keep their insertion order
+  pub position: i32,
   pub end_content: Option<Box<String>>,
 }
 
@@ -21,9 +27,15 @@ impl NormalInitFragment {
     NormalInitFragment {
       content,
       stage,
+      position: 0,
       end_content,
     }
   }
+
+  pub fn with_position(mut self, position: i32) -> Self {
+    self.position = position;
+    self
+  }
 }
 
 impl InitFragment for NormalInitFragment {}
@@ -72,16 +84,20 @@ pub enum InitFragmentStage {
   StageAsyncHarmonyImports,
 }
 
-pub type ChunkInitFragments = HashMap<String, NormalInitFragment>;
+/// Keeps the insertion order, so fragments of the same stage are rendered in module order
+pub type ChunkInitFragments = IndexMap<String, NormalInitFragment, BuildHasherDefault<FxHasher>>;
 
 pub fn render_init_fragments(
   source: BoxSource,
   fragments: &mut Vec<&mut NormalInitFragment>,
 ) -> BoxSource {
   // here use sort_by_key because need keep order equal stage fragments
-  fragments.sort_by_key(|m| m.stage);
-  // merge same init fragments
-  fragments.dedup();
+  fragments.sort_by_key(|m| (m.stage, m.position));
+  // merge same init fragments, e.g. `import "x"` and `export { a } from "x"` both require "x",
+  // fragments with the same content but different keys or stages are only rendered once, at the
+  // earliest stage
+  let mut seen = HashSet::default();
+  fragments.retain(|f| seen.insert((f.content.clone(), f.end_content.clone())));
 
   let mut sources = vec![];
 
@@ -163,3 +179,108 @@ pub fn format_exports(exports: &[(JsWord, JsWord)]) -> String {
       .join(",\n  ")
   )
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_sources::Source;
+
+  use super::*;
+
+  #[test]
+  fn same_content_is_rendered_once_at_the_earliest_stage() {
+    let create_require = "import { createRequire } from 'module';\n";
+    let mut chunk_init_fragments = ChunkInitFragments::default();
+    for (key, content, stage) in [
+      ("b", "var b;\n", InitFragmentStage::StageHarmonyImports),
+      (
+        "api plugin",
+        create_require,
+        InitFragmentStage::StageHarmonyImports,
+      ),
+      ("a", "var a;\n", InitFragmentStage::StageHarmonyImports),
+      (
+        "external module node-commonjs",
+        create_require,
+        InitFragmentStage::StageConstants,
+      ),
+    ] {
+      chunk_init_fragments.insert(
+        key.to_string(),
+        NormalInitFragment::new(content.to_string(), stage, None),
+      );
+    }
+
+    let mut fragments = chunk_init_fragments.into_values().collect::<Vec<_>>();
+    let source = render_init_fragments(
+      RawSource::from("main();\n").boxed(),
+      &mut fragments.iter_mut().collect::<Vec<_>>(),
+    );
+    assert_eq!(
+      source.source(),
+      format!("{create_require}var b;\nvar a;\nmain();\n")
+    );
+  }
+
+  #[test]
+  fn position_orders_fragments_within_a_stage() {
+    let mut fragments = vec![
+      NormalInitFragment::new(
+        "import 'a';\n".to_string(),
+        InitFragmentStage::StageHarmonyImports,
+        None,
+      ),
+      NormalInitFragment::new(
+        "import 'b';\n".to_string(),
+        InitFragmentStage::StageHarmonyImports,
+        None,
+      ),
+      NormalInitFragment::new(
+        "import 'polyfill';\n".to_string(),
+        InitFragmentStage::StageHarmonyImports,
+        None,
+      )
+      .with_position(-1),
+      NormalInitFragment::new(
+        "var c;\n".to_string(),
+        InitFragmentStage::StageConstants,
+        None,
+      )
+      .with_position(1),
+    ];
+    let source = render_init_fragments(
+      RawSource::from("").boxed(),
+      &mut fragments.iter_mut().collect::<Vec<_>>(),
+    );
+    assert_eq!(
+      source.source(),
+      "var c;\nimport 'polyfill';\nimport 'a';\nimport 'b';\n"
+    );
+  }
+
+  #[test]
+  fn end_content_wraps_the_module_body() {
+    let fragments: Vec<BoxInitFragment> = vec![
+      Box::new(NormalInitFragment::new(
+        "try{\n".to_string(),
+        InitFragmentStage::StageConstants,
+        Some(Box::new("}catch(e){console.error(e)}\n".to_string())),
+      )),
+      Box::new(NormalInitFragment::new(
+        "var a = require('a');\n".to_string(),
+        InitFragmentStage::StageHarmonyImports,
+        Some(Box::new("a.dispose();\n".to_string())),
+      )),
+    ];
+    let source = render_box_init_fragments(
+      fragments,
+      RawSource::from("main();\n").boxed(),
+      ExportsArgument::Exports,
+      &mut RuntimeGlobals::default(),
+    );
+    // epilogues are emitted in reverse stage order
+    assert_eq!(
+      source.source(),
+      "try{\nvar a = require('a');\nmain();\na.dispose();\n}catch(e){console.error(e)}\n"
+    );
+  }
+}
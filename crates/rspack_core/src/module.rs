@@ -1,15 +1,16 @@
 use std::fmt::Display;
-use std::hash::Hash;
+use std::hash::{BuildHasherDefault, Hash};
 use std::path::PathBuf;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
+use indexmap::IndexSet;
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::atoms::JsWord;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
@@ -44,9 +45,12 @@ pub struct BuildInfo {
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
-  pub harmony_named_exports: HashSet<JsWord>,
+  /// Named exports in declaration order, kept ordered so reported exports are stable.
+  pub harmony_named_exports: IndexSet<JsWord, BuildHasherDefault<FxHasher>>,
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
+  pub need_dirname: bool,
+  pub need_filename: bool,
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use crate::{DependencyCondition, DependencyId, ModuleGraph, ModuleIdentifier, RuntimeSpec};
+use crate::{DependencyId, ModuleGraph, ModuleIdentifier, RuntimeSpec, RuntimeSpecSet};
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct ConnectionId(usize);
@@ -111,6 +111,22 @@ impl ModuleGraphConnection {
     self.get_condition_state(module_graph, runtime)
   }
 
+  /// The active state of the connection in any of the `runtimes`.
+  pub fn get_active_state_in_runtimes(
+    &self,
+    module_graph: &ModuleGraph,
+    runtimes: &RuntimeSpecSet,
+  ) -> ConnectionState {
+    if !self.conditional {
+      return ConnectionState::Bool(self.active);
+    }
+    module_graph
+      .connection_to_condition
+      .get(self)
+      .expect("should have condition")
+      .get_state_in_runtimes(self, runtimes, module_graph)
+  }
+
   /// ## Panic
   /// This function will panic if we don't have condition, make sure you checked if `condition`
   /// exists before you invoke this function
@@ -120,14 +136,11 @@ impl ModuleGraphConnection {
     module_graph: &ModuleGraph,
     runtime: Option<&RuntimeSpec>,
   ) -> ConnectionState {
-    match module_graph
+    module_graph
       .connection_to_condition
       .get(self)
       .expect("should have condition")
-    {
-      DependencyCondition::False => ConnectionState::Bool(false),
-      DependencyCondition::Fn(f) => f(self, runtime, module_graph),
-    }
+      .get_state(self, runtime, module_graph)
   }
 }
 
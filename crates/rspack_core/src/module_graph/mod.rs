@@ -1,19 +1,25 @@
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
+use std::hash::BuildHasherDefault;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use dashmap::DashMap;
+use itertools::Itertools;
 use rspack_error::{internal_error, Result};
 use rspack_hash::RspackHashDigest;
 use rspack_identifier::IdentifierMap;
-use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
+use swc_core::ecma::atoms::JsWord;
 
 mod connection;
 pub use connection::*;
 
 use crate::{
-  to_identifier, BoxDependency, BoxModule, BuildDependency, BuildInfo, BuildMeta,
-  DependencyCondition, DependencyId, ExportInfo, ExportInfoId, ExportsInfo, ExportsInfoId, Module,
-  ModuleGraphModule, ModuleIdentifier, ModuleProfile,
+  is_async_dependency, to_identifier, BoxDependency, BoxModule, BuildDependency, BuildInfo,
+  BuildMeta, DependencyCondition, DependencyId, DependencyType, ExportInfo, ExportInfoId,
+  ExportsInfo, ExportsInfoId, Module, ModuleGraphModule, ModuleIdentifier, ModuleProfile,
+  ResolvedExportInfoTargetWithCircular,
 };
 
 // TODO Here request can be used JsWord
@@ -47,9 +53,40 @@ pub struct ModuleGraph {
   pub exports_info_map: HashMap<ExportsInfoId, ExportsInfo>,
   pub export_info_map: HashMap<ExportInfoId, ExportInfo>,
   connection_to_condition: HashMap<ModuleGraphConnection, DependencyCondition>,
+
+  /// Resolved `get_module_evaluation_side_effects_state` of dependencies, only valid within a
+  /// single optimization pass
+  side_effects_state_cache: DashMap<DependencyId, bool, BuildHasherDefault<FxHasher>>,
+  /// How many times the side effects analysis was cut off by a module already in the chain
+  circular_side_effects_visits: AtomicUsize,
 }
 
 impl ModuleGraph {
+  pub fn cached_side_effects_state(&self, dependency_id: &DependencyId) -> Option<bool> {
+    self
+      .side_effects_state_cache
+      .get(dependency_id)
+      .map(|state| *state)
+  }
+
+  pub fn cache_side_effects_state(&self, dependency_id: DependencyId, state: bool) {
+    self.side_effects_state_cache.insert(dependency_id, state);
+  }
+
+  pub fn circular_side_effects_visits(&self) -> usize {
+    self.circular_side_effects_visits.load(Ordering::Relaxed)
+  }
+
+  pub fn mark_circular_side_effects_visit(&self) {
+    self
+      .circular_side_effects_visits
+      .fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn clear_side_effects_state_cache(&self) {
+    self.side_effects_state_cache.clear();
+  }
+
   /// Return an unordered iterator of modules
   pub fn modules(&self) -> &IdentifierMap<BoxModule> {
     &self.module_identifier_to_module
@@ -120,6 +157,7 @@ impl ModuleGraph {
     let condition = dependency
       .as_module_dependency()
       .and_then(|dep| dep.get_condition());
+    let build_time_only = dependency.build_time_only();
     self.add_dependency(dependency);
     self
       .dependency_id_to_module_identifier
@@ -128,8 +166,9 @@ impl ModuleGraph {
       return Ok(());
     }
 
-    let active = !matches!(condition, Some(DependencyCondition::False));
-    let conditional = condition.is_some();
+    let active = !build_time_only && !matches!(condition, Some(DependencyCondition::False));
+    // `True` is always active, no need to evaluate it for each runtime
+    let conditional = condition.is_some() && !matches!(condition, Some(DependencyCondition::True));
     // TODO: just a placeholder here, finish this when we have basic `getCondition` logic
     let new_connection = ModuleGraphConnection::new(
       original_module_identifier,
@@ -581,6 +620,110 @@ impl ModuleGraph {
       .expect("should have export info");
     exports_info
   }
+
+  /// Resolve the export `name` re-exported by `dependency_id` down to its terminal binding,
+  /// following `a -> b -> c` re-export chains. Returns `None` if `dependency_id` is not the
+  /// re-export of `name` or the chain can not be statically resolved.
+  pub fn get_terminal_binding(
+    &self,
+    dependency_id: &DependencyId,
+    name: &JsWord,
+  ) -> Option<ResolvedExportInfoTargetWithCircular> {
+    let module_identifier = self.parent_module_by_dependency_id(dependency_id)?;
+    let export_info = self
+      .get_exports_info(&module_identifier)
+      .id
+      .get_read_only_export_info(name, self);
+    if !export_info.has_target(dependency_id) {
+      return None;
+    }
+    export_info.get_terminal_binding(self)
+  }
+
+  /// The async dependencies of a module, i.e. dynamic imports, workers and lazy context elements,
+  /// grouped by the chunk group they load, in source order.
+  pub fn get_async_blocks(&self, module_identifier: &ModuleIdentifier) -> Vec<AsyncBlock<'_>> {
+    let Some(mgm) = self.module_graph_module_by_identifier(module_identifier) else {
+      return vec![];
+    };
+    let mut blocks: Vec<AsyncBlock> = vec![];
+    for dependency_id in &mgm.dependencies {
+      let Some(dependency) = self
+        .dependency_by_id(dependency_id)
+        .and_then(|dependency| dependency.as_module_dependency())
+      else {
+        continue;
+      };
+      if !is_async_dependency(dependency) || dependency.build_time_only() {
+        continue;
+      }
+      let group_name = dependency
+        .group_options()
+        .and_then(|group_options| group_options.name());
+      let block_dependency = AsyncBlockDependency {
+        id: *dependency_id,
+        request: dependency.request(),
+        module_identifier: self
+          .module_identifier_by_dependency_id(dependency_id)
+          .copied(),
+      };
+      // dependencies with the same chunk name share a chunk group
+      match group_name.and_then(|name| {
+        blocks
+          .iter()
+          .position(|block| block.group_name == Some(name))
+      }) {
+        Some(index) => blocks[index].dependencies.push(block_dependency),
+        None => blocks.push(AsyncBlock {
+          group_name,
+          dependencies: vec![block_dependency],
+        }),
+      }
+    }
+    blocks
+  }
+
+  /// The `DependencyType::Custom` names not accepted by `is_registered`, with the modules they
+  /// are used in, sorted by module and deduplicated per module.
+  pub fn unregistered_custom_dependency_types(
+    &self,
+    is_registered: impl Fn(&str) -> bool,
+  ) -> Vec<(&str, ModuleIdentifier)> {
+    let is_registered = &is_registered;
+    self
+      .module_graph_modules()
+      .values()
+      .sorted_unstable_by_key(|mgm| mgm.module_identifier.as_str())
+      .flat_map(|mgm| {
+        mgm.dependencies.iter().filter_map(move |dependency_id| {
+          match self.dependency_by_id(dependency_id)?.dependency_type() {
+            DependencyType::Custom(ty) if !is_registered(ty) => {
+              Some((&**ty, mgm.module_identifier))
+            }
+            _ => None,
+          }
+        })
+      })
+      .unique()
+      .collect()
+  }
+}
+
+/// The async dependencies of a module that load the same chunk group.
+#[derive(Debug)]
+pub struct AsyncBlock<'a> {
+  /// The chunk group name, e.g. from `webpackChunkName`, `None` if the block gets an unnamed
+  /// chunk group of its own
+  pub group_name: Option<&'a str>,
+  pub dependencies: Vec<AsyncBlockDependency<'a>>,
+}
+
+#[derive(Debug)]
+pub struct AsyncBlockDependency<'a> {
+  pub id: DependencyId,
+  pub request: &'a str,
+  /// The module the request is resolved to
+  pub module_identifier: Option<ModuleIdentifier>,
 }
 
 #[cfg(test)]
@@ -592,8 +735,9 @@ mod test {
   use rspack_sources::Source;
 
   use crate::{
-    BoxDependency, BuildContext, BuildResult, CodeGenerationResult, Compilation, Context,
-    Dependency, DependencyId, ExportInfo, ExportsInfo, Module, ModuleDependency, ModuleGraph,
+    ApplyContext, BoxDependency, BuildContext, BuildResult, ChunkGroupOptions,
+    ChunkGroupOptionsKindRef, CodeGenerationResult, Compilation, Context, Dependency, DependencyId,
+    DependencyType, ExportInfo, ExportsInfo, Module, ModuleDependency, ModuleGraph,
     ModuleGraphModule, ModuleIdentifier, ModuleType, SourceType, UsageState,
   };
 
@@ -653,10 +797,17 @@ mod test {
 
   macro_rules! impl_noop_trait_dep_type {
     ($ident:ident) => {
+      impl_noop_trait_dep_type!($ident, false);
+    };
+    ($ident:ident, $build_time_only:literal) => {
       impl Dependency for $ident {
         fn id(&self) -> &DependencyId {
           &self.2
         }
+
+        fn build_time_only(&self) -> bool {
+          $build_time_only
+        }
       }
 
       impl ModuleDependency for $ident {
@@ -683,6 +834,81 @@ mod test {
 
   impl_noop_trait_dep_type!(Edge);
 
+  #[derive(Debug, Clone)]
+  struct BuildTimeEdge(Option<ModuleIdentifier>, String, DependencyId);
+
+  impl_noop_trait_dep_type!(BuildTimeEdge, true);
+
+  #[derive(Debug, Clone)]
+  struct AsyncEdge(String, DependencyId, ChunkGroupOptions);
+
+  impl Dependency for AsyncEdge {
+    fn id(&self) -> &DependencyId {
+      &self.1
+    }
+
+    fn dependency_type(&self) -> &DependencyType {
+      &DependencyType::DynamicImport
+    }
+  }
+
+  impl ModuleDependency for AsyncEdge {
+    fn request(&self) -> &str {
+      &self.0
+    }
+
+    fn user_request(&self) -> &str {
+      &self.0
+    }
+
+    fn span(&self) -> Option<&crate::ErrorSpan> {
+      None
+    }
+
+    fn set_request(&mut self, request: String) {
+      self.0 = request;
+    }
+
+    fn group_options(&self) -> Option<ChunkGroupOptionsKindRef> {
+      Some(ChunkGroupOptionsKindRef::Normal(&self.2))
+    }
+  }
+
+  impl crate::AsDependencyTemplate for AsyncEdge {}
+
+  #[derive(Debug, Clone)]
+  struct CustomEdge(String, DependencyId, DependencyType);
+
+  impl Dependency for CustomEdge {
+    fn id(&self) -> &DependencyId {
+      &self.1
+    }
+
+    fn dependency_type(&self) -> &DependencyType {
+      &self.2
+    }
+  }
+
+  impl ModuleDependency for CustomEdge {
+    fn request(&self) -> &str {
+      &self.0
+    }
+
+    fn user_request(&self) -> &str {
+      &self.0
+    }
+
+    fn span(&self) -> Option<&crate::ErrorSpan> {
+      None
+    }
+
+    fn set_request(&mut self, request: String) {
+      self.0 = request;
+    }
+  }
+
+  impl crate::AsDependencyTemplate for CustomEdge {}
+
   fn add_module_to_graph(mg: &mut ModuleGraph, m: Box<dyn Module>) {
     let other_exports_info = ExportInfo::new(None, UsageState::Unknown, None);
     let side_effects_only_info = ExportInfo::new(
@@ -789,4 +1015,116 @@ mod test {
     assert!(mgm_b.outgoing_connections.is_empty());
     assert!(mgm_c.incoming_connections.is_empty());
   }
+
+  #[test]
+  fn test_build_time_only_dependency() {
+    let mut mg = ModuleGraph::default();
+    let a = node!("a");
+    let b = node!("b");
+    let a_id = a.identifier();
+    let b_id = b.identifier();
+    add_module_to_graph(&mut mg, Box::new(a));
+    add_module_to_graph(&mut mg, Box::new(b));
+    let a_to_b = BuildTimeEdge(Some(a_id), b_id.to_string(), DependencyId::new());
+    let a_to_b_id = link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, Box::new(a_to_b));
+
+    // resolved into the graph, but never pulled into a chunk
+    assert_eq!(
+      mg.module_identifier_by_dependency_id(&a_to_b_id),
+      Some(&b_id)
+    );
+    let connection = mg
+      .connection_by_dependency(&a_to_b_id)
+      .expect("should have connection");
+    assert!(!connection.is_active(&mg, None));
+    assert!(mgm(&mg, &a_id).depended_modules(&mg).is_empty());
+  }
+
+  #[test]
+  fn test_async_blocks() {
+    let mut mg = ModuleGraph::default();
+    let [a, b, c, d] = [node!("a"), node!("b"), node!("c"), node!("d")];
+    let [a_id, b_id, c_id, d_id] = [
+      a.identifier(),
+      b.identifier(),
+      c.identifier(),
+      d.identifier(),
+    ];
+    for m in [a, b, c, d] {
+      add_module_to_graph(&mut mg, Box::new(m));
+    }
+    let lazy = ChunkGroupOptions::default().name("lazy");
+    let a_to_b = AsyncEdge("./b".into(), DependencyId::new(), lazy.clone());
+    let a_to_c = AsyncEdge(
+      "./c".into(),
+      DependencyId::new(),
+      ChunkGroupOptions::default(),
+    );
+    let a_to_d = AsyncEdge("./d".into(), DependencyId::new(), lazy);
+    link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, Box::new(a_to_b));
+    link_modules_with_dependency(&mut mg, Some(&a_id), &c_id, Box::new(a_to_c));
+    link_modules_with_dependency(&mut mg, Some(&a_id), &d_id, Box::new(a_to_d));
+    let a_to_b_sync = edge!(Some(a_id), b_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, Box::new(a_to_b_sync));
+
+    let blocks = mg.get_async_blocks(&a_id);
+    let blocks = blocks
+      .iter()
+      .map(|block| {
+        (
+          block.group_name,
+          block
+            .dependencies
+            .iter()
+            .map(|dependency| (dependency.request, dependency.module_identifier))
+            .collect::<Vec<_>>(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      blocks,
+      vec![
+        (Some("lazy"), vec![("./b", Some(b_id)), ("./d", Some(d_id))]),
+        (None, vec![("./c", Some(c_id))]),
+      ]
+    );
+    assert!(mg.get_async_blocks(&b_id).is_empty());
+  }
+
+  #[test]
+  fn test_unregistered_custom_dependency_types() {
+    let mut mg = ModuleGraph::default();
+    let [a, b, c] = [node!("a"), node!("b"), node!("c")];
+    let [a_id, b_id, c_id] = [a.identifier(), b.identifier(), c.identifier()];
+    for m in [a, b, c] {
+      add_module_to_graph(&mut mg, Box::new(m));
+    }
+    let custom = |request: &str, ty: &str| {
+      Box::new(CustomEdge(
+        request.into(),
+        DependencyId::new(),
+        DependencyType::Custom(ty.into()),
+      ))
+    };
+    link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, custom("./b", "registered"));
+    link_modules_with_dependency(&mut mg, Some(&a_id), &c_id, custom("./c", "typo"));
+    link_modules_with_dependency(&mut mg, Some(&a_id), &c_id, custom("./c?1", "typo"));
+    link_modules_with_dependency(&mut mg, Some(&b_id), &c_id, custom("./c", "typo"));
+    let a_to_c = edge!(Some(a_id), c_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&a_id), &c_id, Box::new(a_to_c));
+
+    let apply_context = ApplyContext::default();
+    apply_context.register_custom_dependency_type("registered");
+    apply_context.enable_strict_custom_dependency_types();
+    assert!(apply_context
+      .strict_custom_dependency_types
+      .load(std::sync::atomic::Ordering::Relaxed));
+    let registered = &apply_context.registered_custom_dependency_types;
+    // reported once per module
+    assert_eq!(
+      mg.unregistered_custom_dependency_types(|ty| registered.contains(ty)),
+      vec![("typo", a_id), ("typo", b_id)]
+    );
+    assert!(mg.unregistered_custom_dependency_types(|_| true).is_empty());
+  }
 }
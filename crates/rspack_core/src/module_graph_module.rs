@@ -132,7 +132,7 @@ impl ModuleGraphModule {
           .expect("should have id")
           .as_module_dependency()
         {
-          return !is_async_dependency(dep) && !dep.weak();
+          return !is_async_dependency(dep) && !dep.weak() && !dep.build_time_only();
         }
         false
       })
@@ -153,7 +153,7 @@ impl ModuleGraphModule {
           .expect("should have id")
           .as_module_dependency()
         {
-          if !is_async_dependency(dep) {
+          if !is_async_dependency(dep) || dep.build_time_only() {
             return None;
           }
           let module = module_graph
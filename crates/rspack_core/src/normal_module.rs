@@ -502,13 +502,27 @@ impl Module for NormalModule {
       if let Some(side_effect_free) = mgm.build_meta.as_ref().and_then(|m| m.side_effect_free) && side_effect_free {
         // use module chain instead of is_evaluating_side_effects to mut module graph
         if module_chain.contains(&self.identifier()) {
+          module_graph.mark_circular_side_effects_visit();
           return ConnectionState::CircularConnection;
         }
         module_chain.insert(self.identifier());
         let mut current = ConnectionState::Bool(false);
         for dependency_id in mgm.dependencies.iter() {
           if let Some(dependency) = module_graph.dependency_by_id(dependency_id).expect("should have dependency").as_module_dependency() {
-            let state = dependency.get_module_evaluation_side_effects_state(module_graph, module_chain);
+            let state = if let Some(value) = module_graph.cached_side_effects_state(dependency_id) {
+              ConnectionState::Bool(value)
+            } else {
+              let circular_visits = module_graph.circular_side_effects_visits();
+              let state =
+                dependency.get_module_evaluation_side_effects_state(module_graph, module_chain);
+              // a state reached through the cycle guard depends on `module_chain`, so it's not cached
+              if let ConnectionState::Bool(value) = state
+                && circular_visits == module_graph.circular_side_effects_visits()
+              {
+                module_graph.cache_side_effects_state(*dependency_id, value);
+              }
+              state
+            };
             if matches!(state, ConnectionState::Bool(true)) {
               // TODO add optimization bailout
               return ConnectionState::Bool(true);
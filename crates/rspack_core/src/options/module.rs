@@ -3,7 +3,7 @@ use std::fmt::{self, Debug};
 use async_recursion::async_recursion;
 use derivative::Derivative;
 use futures::future::BoxFuture;
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
 use rspack_regex::RspackRegex;
 use rspack_util::{try_all, try_any};
 use rustc_hash::FxHashMap as HashMap;
@@ -28,6 +28,7 @@ impl ParserOptionsByModuleType {
 #[derive(Debug, Clone)]
 pub enum ParserOptions {
   Asset(AssetParserOptions),
+  Javascript(JavascriptParserOptions),
   Unknown,
 }
 
@@ -39,6 +40,67 @@ impl ParserOptions {
     };
     maybe.filter(|_| matches!(module_type, ModuleType::Asset))
   }
+
+  pub fn get_javascript(&self, module_type: &ModuleType) -> Option<&JavascriptParserOptions> {
+    let maybe = match self {
+      ParserOptions::Javascript(i) => Some(i),
+      _ => None,
+    };
+    maybe.filter(|_| module_type.is_js_like())
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JavascriptParserOptions {
+  pub dynamic_import_mode: DynamicImportMode,
+  /// Worker syntaxes recognized in addition to the defaults, e.g. `"MyWorker from ./my-worker"`
+  pub worker: Vec<String>,
+  /// Warn about `import()` of a request without a static prefix, which makes a context of the
+  /// whole directory of the module. Unlike webpack, it is off by default
+  pub expr_context_critical: bool,
+}
+
+impl JavascriptParserOptions {
+  /// The default worker syntaxes followed by the configured ones, `"..."` stands for the defaults
+  /// in webpack, which are always included here
+  pub fn worker_syntax(&self) -> Vec<&str> {
+    crate::needs_refactor::DEFAULT_WORKER_SYNTAX
+      .iter()
+      .copied()
+      .chain(
+        self
+          .worker
+          .iter()
+          .map(String::as_str)
+          .filter(|syntax| *syntax != "..."),
+      )
+      .collect()
+  }
+}
+
+/// How `import()` loads the imported module, a `/* webpackMode: "eager" */` comment overrides it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DynamicImportMode {
+  /// The module is put into a separate chunk, which is loaded on `import()`
+  #[default]
+  Lazy,
+  /// The module is put into the chunk of the importer, `import()` resolves without loading a
+  /// chunk
+  Eager,
+}
+
+impl TryFrom<&str> for DynamicImportMode {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "lazy" => Ok(Self::Lazy),
+      "eager" => Ok(Self::Eager),
+      _ => Err(internal_error!(
+        "Unknown dynamic import mode \"{value}\", expected \"lazy\" or \"eager\""
+      )),
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -354,3 +416,21 @@ pub struct ModuleOptions {
   pub parser: Option<ParserOptionsByModuleType>,
   pub generator: Option<GeneratorOptionsByModuleType>,
 }
+
+#[cfg(test)]
+mod test {
+  use super::DynamicImportMode;
+
+  #[test]
+  fn dynamic_import_mode_from_str() {
+    assert_eq!(
+      DynamicImportMode::try_from("lazy").expect("should be valid"),
+      DynamicImportMode::Lazy
+    );
+    assert_eq!(
+      DynamicImportMode::try_from("eager").expect("should be valid"),
+      DynamicImportMode::Eager
+    );
+    assert!(DynamicImportMode::try_from("weak").is_err());
+  }
+}
@@ -99,4 +99,6 @@ pub struct Optimization {
   pub side_effects: SideEffectOption,
   pub provided_exports: bool,
   pub used_exports: UsedExportsOption,
+  /// Filename of an asset listing the used and unused exports of every module
+  pub exports_usage_report: Option<String>,
 }
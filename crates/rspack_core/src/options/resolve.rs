@@ -96,7 +96,10 @@ impl ByDependency {
   }
 
   pub fn get(&self, k: &DependencyCategory) -> Option<&Resolve> {
-    self.0.get(k)
+    self
+      .0
+      .get(k)
+      .or_else(|| k.parent().and_then(|parent| self.0.get(&parent)))
   }
 }
 
@@ -264,4 +267,25 @@ mod test {
       ])
     );
   }
+
+  #[test]
+  fn test_by_dependency_asset_falls_back_to_url() {
+    let url = Resolve {
+      prefer_relative: Some(true),
+      ..Default::default()
+    };
+    let asset = Resolve {
+      fully_specified: Some(true),
+      ..Default::default()
+    };
+    let by_dependency = ByDependency::from_iter([(DependencyCategory::Url, url.clone())]);
+    assert_eq!(by_dependency.get(&DependencyCategory::Asset), Some(&url));
+
+    let by_dependency = ByDependency::from_iter([
+      (DependencyCategory::Url, url),
+      (DependencyCategory::Asset, asset.clone()),
+    ]);
+    assert_eq!(by_dependency.get(&DependencyCategory::Asset), Some(&asset));
+    assert_eq!(by_dependency.get(&DependencyCategory::Esm), None);
+  }
 }
@@ -1,6 +1,10 @@
-use std::{fmt::Debug, path::Path};
+use std::{
+  fmt::Debug,
+  path::Path,
+  sync::atomic::{AtomicBool, Ordering},
+};
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
 use rspack_hash::RspackHashDigest;
 use rspack_loader_runner::{Content, ResourceData};
@@ -13,7 +17,7 @@ use crate::{
   ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   NormalModuleFactoryContext, OptimizeChunksArgs, ParserAndGenerator, PluginContext,
   ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
-  RenderStartupArgs, Resolver, SourceType, ThisCompilationArgs,
+  RenderRuntimeModuleContentArgs, RenderStartupArgs, Resolver, SourceType, ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -36,6 +40,7 @@ pub type PluginProcessAssetsOutput = Result<()>;
 pub type PluginOptimizeChunksOutput = Result<()>;
 pub type PluginAdditionalChunkRuntimeRequirementsOutput = Result<()>;
 pub type PluginRenderModuleContentOutput<'a> = Result<RenderModuleContentArgs<'a>>;
+pub type PluginRenderRuntimeModuleContentOutput<'a> = Result<RenderRuntimeModuleContentArgs<'a>>;
 pub type PluginRenderStartupHookOutput = Result<Option<BoxSource>>;
 pub type PluginRenderHookOutput = Result<Option<BoxSource>>;
 pub type PluginJsChunkHashHookOutput = Result<()>;
@@ -197,6 +202,15 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(args)
   }
 
+  // JavascriptModulesPlugin hook
+  fn render_runtime_module_content<'a>(
+    &'a self,
+    _ctx: PluginContext,
+    args: RenderRuntimeModuleContentArgs<'a>,
+  ) -> PluginRenderRuntimeModuleContentOutput<'a> {
+    Ok(args)
+  }
+
   // JavascriptModulesPlugin hook
   fn js_chunk_hash(
     &self,
@@ -526,6 +540,8 @@ pub type BoxedParserAndGeneratorBuilder =
 pub struct ApplyContext {
   pub(crate) registered_parser_and_generator_builder:
     DashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub(crate) registered_custom_dependency_types: DashSet<Box<str>>,
+  pub(crate) strict_custom_dependency_types: AtomicBool,
 }
 
 impl ApplyContext {
@@ -538,4 +554,19 @@ impl ApplyContext {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Declare a `DependencyType::Custom` created by the plugin, see
+  /// [ApplyContext::enable_strict_custom_dependency_types]
+  pub fn register_custom_dependency_type(&self, dependency_type: impl Into<Box<str>>) {
+    self
+      .registered_custom_dependency_types
+      .insert(dependency_type.into());
+  }
+
+  /// Warn about every `DependencyType::Custom` in the module graph that no plugin registered
+  pub fn enable_strict_custom_dependency_types(&self) {
+    self
+      .strict_custom_dependency_types
+      .store(true, Ordering::Relaxed);
+  }
 }
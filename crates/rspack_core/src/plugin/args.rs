@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use rspack_error::{internal_error, Result};
 use rspack_hash::RspackHash;
+use rspack_identifier::Identifier;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -238,9 +239,19 @@ pub struct RenderModuleContentArgs<'a> {
   pub module_source: BoxSource,
   pub chunk_init_fragments: ChunkInitFragments,
   pub compilation: &'a Compilation,
+  pub chunk: &'a ChunkUkey,
   pub module_graph_module: &'a ModuleGraphModule,
 }
 
+#[derive(Debug)]
+pub struct RenderRuntimeModuleContentArgs<'a> {
+  pub runtime_module_source: BoxSource,
+  /// Name of the runtime module, e.g. `webpack/runtime/jsonp_chunk_loading`
+  pub runtime_module_name: Identifier,
+  pub compilation: &'a Compilation,
+  pub chunk: &'a ChunkUkey,
+}
+
 #[derive(Debug)]
 pub struct RenderStartupArgs<'a> {
   // pub module_source: &'a BoxSource,
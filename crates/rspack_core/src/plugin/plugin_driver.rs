@@ -1,11 +1,11 @@
 use std::{
   path::Path,
-  sync::{Arc, Mutex},
+  sync::{atomic::Ordering, Arc, Mutex},
 };
 
 use rspack_error::{Diagnostic, Result};
 use rspack_loader_runner::ResourceData;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tracing::instrument;
 
 use crate::{
@@ -20,8 +20,9 @@ use crate::{
   PluginModuleHookOutput, PluginNormalModuleFactoryAfterResolveOutput,
   PluginNormalModuleFactoryBeforeResolveOutput, PluginProcessAssetsOutput,
   PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
-  PluginRenderModuleContentOutput, PluginRenderStartupHookOutput, PluginThisCompilationHookOutput,
-  ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
+  PluginRenderModuleContentOutput, PluginRenderRuntimeModuleContentOutput,
+  PluginRenderStartupHookOutput, PluginThisCompilationHookOutput, ProcessAssetsArgs, RenderArgs,
+  RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderRuntimeModuleContentArgs,
   RenderStartupArgs, Resolver, ResolverFactory, Stats, ThisCompilationArgs,
 };
 
@@ -31,6 +32,9 @@ pub struct PluginDriver {
   pub resolver_factory: Arc<ResolverFactory>,
   // pub registered_parser: HashMap<ModuleType, BoxedParser>,
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub registered_custom_dependency_types: HashSet<Box<str>>,
+  /// Whether unregistered `DependencyType::Custom` in the module graph are reported
+  pub strict_custom_dependency_types: bool,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
 }
@@ -42,6 +46,14 @@ impl std::fmt::Debug for PluginDriver {
       .field("plugins", &self.plugins)
       // field("registered_parser", &self.registered_parser)
       .field("registered_parser_and_generator_builder", &"{..}")
+      .field(
+        "registered_custom_dependency_types",
+        &self.registered_custom_dependency_types,
+      )
+      .field(
+        "strict_custom_dependency_types",
+        &self.strict_custom_dependency_types,
+      )
       .field("diagnostics", &self.diagnostics)
       .finish()
   }
@@ -53,7 +65,7 @@ impl PluginDriver {
     plugins: Vec<Box<dyn Plugin>>,
     resolver_factory: Arc<ResolverFactory>,
   ) -> (Arc<Self>, Arc<CompilerOptions>) {
-    let registered_parser_and_generator_builder = plugins
+    let apply_contexts = plugins
       .iter()
       .map(|plugin| {
         let mut apply_context = ApplyContext::default();
@@ -65,13 +77,19 @@ impl PluginDriver {
           .expect("TODO:");
         apply_context
       })
-      .flat_map(|apply_context| {
-        apply_context
-          .registered_parser_and_generator_builder
-          .into_iter()
-          .collect::<Vec<_>>()
-      })
-      .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
+      .collect::<Vec<_>>();
+    let strict_custom_dependency_types = apply_contexts.iter().any(|apply_context| {
+      apply_context
+        .strict_custom_dependency_types
+        .load(Ordering::Relaxed)
+    });
+    let mut registered_custom_dependency_types = HashSet::default();
+    let mut registered_parser_and_generator_builder = HashMap::default();
+    for apply_context in apply_contexts {
+      registered_custom_dependency_types.extend(apply_context.registered_custom_dependency_types);
+      registered_parser_and_generator_builder
+        .extend(apply_context.registered_parser_and_generator_builder);
+    }
 
     let options = Arc::new(options);
 
@@ -82,6 +100,8 @@ impl PluginDriver {
         resolver_factory,
         // registered_parser,
         registered_parser_and_generator_builder,
+        registered_custom_dependency_types,
+        strict_custom_dependency_types,
         diagnostics: Arc::new(Mutex::new(vec![])),
       }),
       options,
@@ -304,6 +324,16 @@ impl PluginDriver {
     Ok(args)
   }
 
+  pub fn render_runtime_module_content<'a>(
+    &'a self,
+    mut args: RenderRuntimeModuleContentArgs<'a>,
+  ) -> PluginRenderRuntimeModuleContentOutput<'a> {
+    for plugin in &self.plugins {
+      args = plugin.render_runtime_module_content(PluginContext::new(), args)?;
+    }
+    Ok(args)
+  }
+
   pub async fn factorize(
     &self,
     args: FactorizeArgs<'_>,
@@ -215,6 +215,26 @@ bitflags! {
     const NODE_MODULE_DECORATOR = 1 << 47;
 
     const HARMONY_MODULE_DECORATOR = 1 << 48;
+
+    /**
+     * prefetch a chunk, the chunk is fetched with a low priority but not evaluated
+     */
+    const PREFETCH_CHUNK = 1 << 49;
+
+    /**
+     * an object with handlers to prefetch a chunk
+     */
+    const PREFETCH_CHUNK_HANDLERS = 1 << 50;
+
+    /**
+     * preload a chunk, the chunk is fetched in parallel with its parent but not evaluated
+     */
+    const PRELOAD_CHUNK = 1 << 51;
+
+    /**
+     * an object with handlers to preload a chunk
+     */
+    const PRELOAD_CHUNK_HANDLERS = 1 << 52;
   }
 }
 
@@ -284,6 +304,10 @@ impl RuntimeGlobals {
       R::CREATE_FAKE_NAMESPACE_OBJECT => "__webpack_require__.t",
       R::HARMONY_MODULE_DECORATOR => "__webpack_require__.hmd",
       R::NODE_MODULE_DECORATOR => "__webpack_require__.nmd",
+      R::PREFETCH_CHUNK => "__webpack_require__.E",
+      R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
+      R::PRELOAD_CHUNK => "__webpack_require__.G",
+      R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
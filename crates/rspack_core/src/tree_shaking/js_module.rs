@@ -4,13 +4,16 @@ use super::{
   analyzer::OptimizeAnalyzer,
   visitor::{ModuleRefAnalyze, OptimizeAnalyzeResult, SyntaxContextInfo},
 };
-use crate::{ast::javascript::Ast, BoxDependency, CompilerOptions, ModuleIdentifier};
+use crate::{
+  ast::javascript::Ast, BoxDependency, CompilerOptions, JavascriptParserOptions, ModuleIdentifier,
+};
 
 pub struct JsModule<'b, 'a: 'b> {
   ast: &'a Ast,
   dependencies: &'b Vec<BoxDependency>,
   module_identifier: ModuleIdentifier,
   compiler_options: &'a CompilerOptions,
+  parser_options: &'a JavascriptParserOptions,
 }
 
 impl<'a, 'b> JsModule<'b, 'a> {
@@ -19,12 +22,14 @@ impl<'a, 'b> JsModule<'b, 'a> {
     dependencies: &'b Vec<BoxDependency>,
     module_identifier: ModuleIdentifier,
     compiler_options: &'a CompilerOptions,
+    parser_options: &'a JavascriptParserOptions,
   ) -> Self {
     Self {
       ast,
       dependencies,
       module_identifier,
       compiler_options,
+      parser_options,
     }
   }
 }
@@ -37,9 +42,9 @@ impl<'a, 'b> OptimizeAnalyzer for JsModule<'a, 'b> {
 
       let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
       let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
-      let mut worker_syntax_scanner = crate::needs_refactor::WorkerSyntaxScanner::new(
-        crate::needs_refactor::DEFAULT_WORKER_SYNTAX,
-      );
+      let worker_syntax = self.parser_options.worker_syntax();
+      let mut worker_syntax_scanner =
+        crate::needs_refactor::WorkerSyntaxScanner::new(&worker_syntax);
       program.visit_with(&mut worker_syntax_scanner);
       let worker_syntax_list = &worker_syntax_scanner.into();
 
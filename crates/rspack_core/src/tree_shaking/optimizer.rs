@@ -524,8 +524,12 @@ impl<'a> CodeSizeOptimizer<'a> {
             DependencyType::CommonJSRequireContext
               | DependencyType::RequireContext
               | DependencyType::DynamicImport
+              | DependencyType::DynamicImportEager
+              | DependencyType::SystemImport
               | DependencyType::CjsRequire
+              | DependencyType::RequireEnsureItem
               | DependencyType::ImportContext
+              | DependencyType::ImportMetaContext
           );
 
           if self.side_effects_free_modules.contains(module_identifier)
@@ -542,6 +546,7 @@ impl<'a> CodeSizeOptimizer<'a> {
             DependencyType::CommonJSRequireContext
               | DependencyType::RequireContext
               | DependencyType::ImportContext
+              | DependencyType::ImportMetaContext
           ) {
             let deps_module_id_of_context_module = self
               .compilation
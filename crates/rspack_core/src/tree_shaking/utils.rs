@@ -1,5 +1,7 @@
 use swc_core::common::SyntaxContext;
-use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit};
+use swc_core::ecma::ast::{
+  CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp,
+};
 use swc_core::ecma::atoms::{js_word, JsWord};
 
 use super::symbol::{IndirectTopLevelSymbol, StarSymbol, Symbol};
@@ -50,6 +52,26 @@ pub fn get_dynamic_import_string_literal(e: &CallExpr) -> Option<JsWord> {
   }
 }
 
+/// `System.import("./a")`, unless `System` is a binding of the module
+pub fn get_system_import_string_literal(
+  e: &CallExpr,
+  unresolved_ctxt: SyntaxContext,
+) -> Option<JsWord> {
+  let is_system_import = matches!(
+    &e.callee,
+    Callee::Expr(box Expr::Member(MemberExpr {
+      obj: box Expr::Ident(obj),
+      prop: MemberProp::Ident(prop),
+      ..
+    })) if &obj.sym == "System" && obj.span.ctxt == unresolved_ctxt && &prop.sym == "import"
+  );
+  if e.args.len() == 1 && is_system_import {
+    get_first_string_lit_arg(e)
+  } else {
+    None
+  }
+}
+
 /// # Panic
 /// when module_identifier is not a pattern of xxx|xxxxxxxxxxxxxxxxxx
 pub fn get_path_of_module_identifier<T: AsRef<str>>(path: T) -> String {
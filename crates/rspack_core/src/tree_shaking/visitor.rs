@@ -18,7 +18,9 @@ use super::symbol::{
 };
 use super::SideEffectType;
 use super::{
-  utils::{get_dynamic_import_string_literal, get_require_literal},
+  utils::{
+    get_dynamic_import_string_literal, get_require_literal, get_system_import_string_literal,
+  },
   BailoutFlag,
 };
 use crate::needs_refactor::WorkerSyntaxList;
@@ -764,7 +766,10 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
         match decl {
           ModuleDecl::Import(import) => {
             let src = &import.src.value;
-            let dep_id = match self.resolve_module_identifier(src, &DependencyType::EsmImport) {
+            let dep_id = match self
+              .resolve_module_identifier(src, &DependencyType::EsmImport)
+              .or_else(|| self.resolve_module_identifier(src, &DependencyType::JsonImport))
+            {
               Some(module_identifier) => module_identifier,
               None => {
                 // TODO: Ignore for now because swc helper interference.
@@ -1102,8 +1107,24 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
           );
         }
       };
-    } else if let Some(import_str) = get_dynamic_import_string_literal(node) {
-      match self.resolve_module_identifier(&import_str, &DependencyType::DynamicImport) {
+    } else if let Some((import_str, dependency_type)) = get_dynamic_import_string_literal(node)
+      .map(|import_str| (import_str, DependencyType::DynamicImport))
+      .or_else(|| {
+        get_system_import_string_literal(node, self.unresolved_ctxt)
+          .map(|import_str| (import_str, DependencyType::SystemImport))
+      })
+    {
+      let dep_id = self
+        .resolve_module_identifier(&import_str, &dependency_type)
+        .or_else(|| {
+          // `import(/* webpackMode: "eager" */ "./a")`
+          if matches!(dependency_type, DependencyType::DynamicImport) {
+            self.resolve_module_identifier(&import_str, &DependencyType::DynamicImportEager)
+          } else {
+            None
+          }
+        });
+      match dep_id {
         Some(dep_id) => match self
           .bail_out_module_identifiers
           .entry(ModuleIdOrDepId::DepId(dep_id))
@@ -0,0 +1,41 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static PATH_NAME_NORMALIZE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^a-zA-Z0-9_!§$()=\-^°]+").expect("should init regex"));
+static PATH_NAME_TRIM_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^-|-$").expect("should init regex"));
+
+/// Port of `Template.toPath` of webpack, make a request usable as part of a file name
+pub fn to_path(str: &str) -> String {
+  let normalized = PATH_NAME_NORMALIZE_REGEX.replace_all(str, "-");
+  PATH_NAME_TRIM_REGEX
+    .replace_all(&normalized, "")
+    .into_owned()
+}
+
+/// Render a chunk name template of an async chunk created for a context element or a worker
+/// e.g.
+/// ```
+/// use rspack_core::render_chunk_name_template;
+/// assert_eq!(
+///   "workers/foo-worker-js",
+///   render_chunk_name_template("workers/[request]", 0, "./foo.worker.js", None)
+/// );
+/// assert_eq!(
+///   "pages-1-home",
+///   render_chunk_name_template("pages-[index]-[name]", 1, "./home.js", Some("home"))
+/// );
+/// ```
+pub fn render_chunk_name_template(
+  template: &str,
+  index: usize,
+  request: &str,
+  name: Option<&str>,
+) -> String {
+  let request = to_path(request);
+  template
+    .replace("[index]", &index.to_string())
+    .replace("[request]", &request)
+    .replace("[name]", name.unwrap_or(&request))
+}
@@ -9,6 +9,9 @@ pub use identifier::*;
 mod comment;
 pub use comment::*;
 
+mod chunk_name;
+pub use chunk_name::*;
+
 mod source;
 pub use source::*;
 
@@ -100,9 +100,14 @@ pub async fn module_rule_matcher<'a>(
     return Ok(false);
   }
 
-  if let Some(dependency_rule) = &module_rule.dependency
-    && !dependency_rule.try_match(&dependency.to_string()).await? {
-    return Ok(false);
+  if let Some(dependency_rule) = &module_rule.dependency {
+    let mut matched = dependency_rule.try_match(&dependency.to_string()).await?;
+    if !matched && let Some(parent) = dependency.parent() {
+      matched = dependency_rule.try_match(&parent.to_string()).await?;
+    }
+    if !matched {
+      return Ok(false);
+    }
   }
 
   if let Some(description_data) = &module_rule.description_data {
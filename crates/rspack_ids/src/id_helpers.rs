@@ -49,7 +49,8 @@ pub fn get_used_module_ids_and_modules(
         used_ids.insert(module_id.clone());
       } else {
         if filter.as_ref().map_or(true, |f| (f)(module))
-          && chunk_graph.get_number_of_module_chunks(module.identifier()) != 0
+          && (chunk_graph.get_number_of_module_chunks(module.identifier()) != 0
+            || is_weakly_referenced(module, &compilation.module_graph))
         {
           modules.push(module.identifier());
         }
@@ -58,6 +59,17 @@ pub fn get_used_module_ids_and_modules(
   (used_ids, modules)
 }
 
+/// Modules only reachable through weak dependencies (e.g. `require.resolveWeak`) are
+/// never placed in a chunk, but the referencing code still needs their id.
+fn is_weakly_referenced(module: &BoxModule, module_graph: &ModuleGraph) -> bool {
+  module_graph
+    .get_incoming_connections(module)
+    .iter()
+    .filter_map(|connection| module_graph.dependency_by_id(&connection.dependency_id))
+    .filter_map(|dep| dep.as_module_dependency())
+    .any(|dep| dep.weak())
+}
+
 pub fn get_short_module_name(module: &BoxModule, context: &str) -> String {
   let lib_ident = module.lib_ident(rspack_core::LibIdentOptions { context });
   if let Some(lib_ident) = lib_ident {
@@ -10,3 +10,5 @@ mod stable_named_chunk_ids_plugin;
 pub use stable_named_chunk_ids_plugin::StableNamedChunkIdsPlugin;
 mod deterministic_chunk_ids_plugin;
 pub use deterministic_chunk_ids_plugin::DeterministicChunkIdsPlugin;
+mod url_worker_chunk_ids_plugin;
+pub use url_worker_chunk_ids_plugin::UrlWorkerChunkIdsPlugin;
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use rspack_core::{ChunkUkey, Compilation, DependencyType, ModuleIdentifier, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{assign_deterministic_ids, get_used_chunk_ids};
+
+/// Assigns ids to the entry chunks of `new Worker(new URL("./a.js", import.meta.url))`
+/// based on the url request, so they stay stable when unrelated modules change.
+///
+/// Must be applied before the general chunk ids plugin, which only fills in the
+/// chunks that are still without an id.
+#[derive(Debug, Default)]
+pub struct UrlWorkerChunkIdsPlugin;
+
+impl Plugin for UrlWorkerChunkIdsPlugin {
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    let mut used_ids = get_used_chunk_ids(compilation);
+    let used_ids_len = used_ids.len();
+
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut worker_chunks: HashMap<ChunkUkey, (&str, &ModuleIdentifier)> = HashMap::default();
+    for mgm in module_graph.module_graph_modules().values() {
+      for dependency_id in mgm.dependencies.iter() {
+        let Some(dependency) = module_graph
+          .dependency_by_id(dependency_id)
+          .filter(|dep| matches!(dep.dependency_type(), DependencyType::NewWorker))
+          .and_then(|dep| dep.as_module_dependency())
+        else {
+          continue;
+        };
+        let Some(module_identifier) =
+          module_graph.module_identifier_by_dependency_id(dependency_id)
+        else {
+          continue;
+        };
+        let Some(entrypoint) =
+          chunk_graph.get_block_chunk_group(module_identifier, &compilation.chunk_group_by_ukey)
+        else {
+          continue;
+        };
+        let chunk_ukey = entrypoint.get_entry_point_chunk();
+        if compilation
+          .chunk_by_ukey
+          .get(&chunk_ukey)
+          .map_or(true, |chunk| chunk.id.is_some())
+        {
+          continue;
+        }
+        worker_chunks
+          .entry(chunk_ukey)
+          .or_insert((dependency.request(), module_identifier));
+      }
+    }
+
+    let mut chunk_key_to_id = HashMap::with_capacity(worker_chunks.len());
+    assign_deterministic_ids(
+      worker_chunks.iter().collect::<Vec<_>>(),
+      |(_, (request, _))| request.to_string(),
+      |(_, a), (_, b)| a.cmp(b),
+      |(chunk_ukey, _), id| {
+        let size = used_ids.len();
+        used_ids.insert(id.clone());
+        if used_ids.len() == size {
+          return false;
+        }
+        chunk_key_to_id.insert(*chunk_ukey, id);
+        true
+      },
+      &[usize::pow(10, 3)],
+      10,
+      used_ids_len,
+      10,
+    );
+
+    chunk_key_to_id.into_iter().for_each(|(chunk_ukey, id)| {
+      let chunk = compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("Chunk should exists");
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id];
+    });
+
+    Ok(())
+  }
+}
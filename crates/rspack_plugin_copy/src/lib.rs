@@ -9,15 +9,17 @@ use std::{
 
 use async_trait::async_trait;
 use dashmap::DashSet;
+use futures::future::BoxFuture;
 use glob::{MatchOptions, Pattern as GlobPattern};
 use regex::Regex;
 use rspack_core::{
   rspack_sources::RawSource, AssetInfo, Compilation, CompilationAsset, CompilationLogger, Filename,
   Logger, PathData, Plugin,
 };
-use rspack_error::Diagnostic;
+use rspack_error::{Diagnostic, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use sugar_path::{AsPath, SugarPath};
+use walkdir::WalkDir;
 
 #[derive(Debug, Clone)]
 pub struct CopyRspackPluginOptions {
@@ -48,17 +50,61 @@ impl Display for ToType {
   }
 }
 
+pub type TransformerFn =
+  Arc<dyn for<'a> Fn(Vec<u8>, &'a str) -> BoxFuture<'a, Result<RawSource>> + Sync + Send>;
+
+#[derive(Clone)]
+pub enum Transformer {
+  Fn(TransformerFn),
+}
+
+impl std::fmt::Debug for Transformer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+pub struct ToFnCtx<'a> {
+  pub context: &'a Path,
+  pub absolute_filename: &'a Path,
+}
+
+pub type ToFn = Arc<dyn for<'a> Fn(ToFnCtx<'a>) -> BoxFuture<'a, Result<String>> + Sync + Send>;
+
+#[derive(Clone)]
+pub enum ToOption {
+  String(String),
+  Fn(ToFn),
+}
+
+impl std::fmt::Debug for ToOption {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::String(arg0) => f.debug_tuple("String").field(arg0).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CopyPattern {
+  /// A file, a directory or a glob. Globs support `{a,b}` alternatives and `!(a|b)` negations,
+  /// `\{`, `\}` and `\!` match the literal characters.
   pub from: String,
-  pub to: Option<String>,
+  pub to: Option<ToOption>,
   pub context: Option<PathBuf>,
   pub to_type: Option<ToType>,
   pub no_error_on_missing: bool,
   pub info: Option<AssetInfo>,
   pub force: bool,
+  /// Patterns with a higher priority are copied later, so with `force` they overwrite the
+  /// files of lower priority patterns copied to the same destination. Files of patterns with
+  /// an equal priority are copied in the lexicographic order of their source paths.
   pub priority: i32,
   pub glob_options: CopyGlobOptions,
+  pub transform: Option<Transformer>,
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +112,7 @@ pub struct CopyGlobOptions {
   pub case_sensitive_match: Option<bool>,
   pub dot: Option<bool>,
   pub ignore: Option<Vec<GlobPattern>>,
+  pub follow_symbolic_links: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +121,7 @@ pub struct RunPatternResult {
   pub absolute_filename: PathBuf,
   pub filename: String,
   pub source: RawSource,
+  pub content_hash: String,
   pub info: Option<AssetInfo>,
   pub force: bool,
   pub priority: i32,
@@ -144,15 +192,46 @@ impl CopyRspackPlugin {
       context.join(&from)
     };
 
-    let to = if let Some(to) = pattern.to.as_ref() {
-      to.clone()
-        .as_path()
-        .normalize()
-        .to_string_lossy()
-        .to_string()
-    } else {
-      "".into()
+    let to = match pattern.to.as_ref() {
+      Some(ToOption::String(to)) => to.clone(),
+      Some(ToOption::Fn(to_fn)) => {
+        let to = match to_fn(ToFnCtx {
+          context,
+          absolute_filename: &absolute_filename,
+        })
+        .await
+        {
+          Ok(to) => to,
+          Err(e) => {
+            let rspack_err: Vec<Diagnostic> = e.into();
+            for err in rspack_err {
+              diagnostics.insert(err);
+            }
+            return None;
+          }
+        };
+
+        // Don't let a dynamic destination escape the output directory, neither through `..` nor
+        // as an absolute path
+        if !is_inside_output_path(&to, output_path) {
+          diagnostics.insert(Diagnostic::error(
+            "CopyRspackPlugin Error".into(),
+            format!(
+              "the 'to' function returned '{to}' for '{}', which is outside of '{}'",
+              absolute_filename.display(),
+              output_path.display()
+            ),
+            0,
+            0,
+          ));
+          return None;
+        }
+
+        to
+      }
+      None => "".into(),
     };
+    let to = to.as_path().normalize().to_string_lossy().to_string();
 
     let to_type = if let Some(to_type) = pattern.to_type.as_ref() {
       to_type.clone()
@@ -210,7 +289,25 @@ impl CopyRspackPlugin {
       Ok(data) => {
         logger.debug(format!("read '{}'...", absolute_filename.display()));
 
-        RawSource::Buffer(data)
+        if let Some(Transformer::Fn(transformer)) = &pattern.transform {
+          logger.log(format!(
+            "transforming content for '{}'...",
+            absolute_filename.display()
+          ));
+
+          match transformer(data, &absolute_filename.to_string_lossy()).await {
+            Ok(source) => source,
+            Err(e) => {
+              let rspack_err: Vec<Diagnostic> = e.into();
+              for err in rspack_err {
+                diagnostics.insert(err);
+              }
+              return None;
+            }
+          }
+        } else {
+          RawSource::Buffer(data)
+        }
       }
       Err(e) => {
         let rspack_err: Vec<Diagnostic> = rspack_error::Error::from(e).into();
@@ -221,6 +318,13 @@ impl CopyRspackPlugin {
       }
     };
 
+    let content_hash = Self::get_content_hash(
+      &source,
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+
     let filename = if matches!(&to_type, ToType::Template) {
       logger.log(format!(
         "interpolating template '{}' for '${}'...`",
@@ -228,12 +332,6 @@ impl CopyRspackPlugin {
         source_filename.display()
       ));
 
-      let content_hash = Self::get_content_hash(
-        &source,
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
       let content_hash = content_hash.rendered(compilation.options.output.hash_digest_length);
       let template_str = compilation.get_asset_path(
         &Filename::from(filename.to_string_lossy().to_string()),
@@ -258,7 +356,8 @@ impl CopyRspackPlugin {
       absolute_filename,
       filename,
       source,
-      info: None,
+      content_hash: content_hash.encoded().to_string(),
+      info: pattern.info.clone(),
       force: pattern.force,
       priority: pattern.priority,
     })
@@ -365,22 +464,41 @@ impl CopyRspackPlugin {
       }
     };
 
+    // `{a,b}` and `!(a)` are not understood by the glob crate
+    let (glob_queries, negated_queries) = match from_type {
+      FromType::Glob => expand_extended_glob(&glob_query),
+      FromType::Dir | FromType::File => (vec![glob_query.clone()], vec![]),
+    };
+    let negated_patterns = negated_queries
+      .iter()
+      .filter_map(|query| GlobPattern::new(query).ok())
+      .collect::<Vec<_>>();
+
     logger.log(format!("begin globbing '{glob_query}'..."));
 
-    let glob_entries = glob::glob_with(
-      &glob_query,
-      MatchOptions {
-        case_sensitive: pattern.glob_options.case_sensitive_match.unwrap_or(true),
-        require_literal_separator: Default::default(),
-        require_literal_leading_dot: !dot_enable.unwrap_or(false),
-      },
-    );
+    let match_options = MatchOptions {
+      case_sensitive: pattern.glob_options.case_sensitive_match.unwrap_or(true),
+      require_literal_separator: Default::default(),
+      require_literal_leading_dot: !dot_enable.unwrap_or(false),
+    };
+    let follow_symbolic_links = pattern.glob_options.follow_symbolic_links.unwrap_or(true);
+    let glob_entries = glob_queries
+      .iter()
+      .map(|glob_query| walk_glob(glob_query, match_options, follow_symbolic_links))
+      .collect::<std::result::Result<Vec<_>, _>>();
 
     match glob_entries {
       Ok(entries) => {
-        let entries: Vec<_> = entries
+        let mut entries: Vec<_> = entries
+          .into_iter()
+          .flatten()
           .filter_map(|entry| {
-            let entry = entry.ok()?;
+            if negated_patterns
+              .iter()
+              .any(|negated| negated.matches_path_with(&entry, match_options))
+            {
+              return None;
+            }
 
             let filters = pattern.glob_options.ignore.as_ref();
 
@@ -395,6 +513,11 @@ impl CopyRspackPlugin {
             }
           })
           .collect();
+        // alternatives of a `{a,b}` may match the same files
+        if glob_queries.len() > 1 {
+          entries.sort_unstable();
+          entries.dedup();
+        }
 
         if need_add_context_to_dependency &&
         let Some(common_dir) = get_closest_common_parent_dir(
@@ -411,12 +534,28 @@ impl CopyRspackPlugin {
             return None;
           }
 
-          diagnostics.insert(Diagnostic::error(
-            "CopyRspackPlugin Error".into(),
-            format!("unable to locate '{glob_query}' glob"),
-            0,
-            0,
-          ));
+          // An existing directory with nothing to copy, like an empty `assets`, is only worth a
+          // warning, a missing one is most likely a mistake
+          let root_exists = match from_type {
+            FromType::Dir | FromType::File => true,
+            FromType::Glob => get_glob_root(&glob_query).exists(),
+          };
+          if root_exists {
+            diagnostics.insert(Diagnostic::warn(
+              "CopyRspackPlugin Warning".into(),
+              format!("'{glob_query}' glob matched no files"),
+              0,
+              0,
+            ));
+          } else {
+            diagnostics.insert(Diagnostic::error(
+              "CopyRspackPlugin Error".into(),
+              format!("unable to locate '{glob_query}' glob"),
+              0,
+              0,
+            ));
+          }
+          return None;
         }
 
         let output_path = &compilation.options.output.path;
@@ -541,20 +680,36 @@ impl Plugin for CopyRspackPlugin {
       .extend(context_dependencies);
     compilation.push_batch_diagnostic(diagnostics.into_iter().collect());
 
-    copied_result.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    // The glob walk order depends on the platform, the source path keeps the last writer of a
+    // destination stable
+    copied_result.sort_unstable_by(|(a_priority, a), (b_priority, b)| {
+      a_priority
+        .cmp(b_priority)
+        .then_with(|| a.absolute_filename.cmp(&b.absolute_filename))
+    });
+
+    // The content hash becomes the asset version, so the compiler does not write the files whose
+    // source is unchanged since the last build again
     copied_result.into_iter().for_each(|(_priority, result)| {
       if let Some(exist_asset) = args.compilation.assets_mut().get_mut(&result.filename) {
         if !result.force {
           return;
         }
         exist_asset.set_source(Some(Arc::new(result.source)));
+        if let Some(info) = result.info {
+          exist_asset.set_info(info);
+        }
+        exist_asset.info.version = result.content_hash;
         // TODO set info { copied: true, sourceFilename }
       } else {
         args.compilation.emit_asset(
           result.filename,
           CompilationAsset {
             source: Some(Arc::new(result.source)),
-            info: AssetInfo::default(),
+            info: result
+              .info
+              .unwrap_or_default()
+              .with_version(result.content_hash),
           },
         )
       }
@@ -585,6 +740,194 @@ fn get_closest_common_parent_dir(paths: &Vec<&Path>) -> Option<PathBuf> {
   Some(parent_dir)
 }
 
+/// The paths under the root of `glob_query` matching it. Symbolic links to directories are only
+/// walked into with `follow_symbolic_links`, symlinked files are copied either way, just like
+/// webpack's copy plugin does. A link to one of its own ancestors is reported as an error by the
+/// walker instead of being walked again and again, such entries are left out.
+///
+/// Like the glob crate, the walk never goes deeper than the glob without `**` reaches, and skips
+/// the directories its components before the first `**` don't match, so `*.md` doesn't walk
+/// `node_modules`.
+fn walk_glob(
+  glob_query: &str,
+  match_options: MatchOptions,
+  follow_symbolic_links: bool,
+) -> std::result::Result<Vec<PathBuf>, glob::PatternError> {
+  let pattern = GlobPattern::new(glob_query)?;
+  let (root, segments) = split_glob(glob_query);
+  let globstar = segments.iter().position(|segment| segment == "**");
+  let segment_patterns = segments[..globstar.unwrap_or(segments.len())]
+    .iter()
+    .map(|segment| GlobPattern::new(segment))
+    .collect::<std::result::Result<Vec<_>, _>>()?;
+  let mut walker = WalkDir::new(root)
+    .follow_links(follow_symbolic_links)
+    .sort_by_file_name();
+  if globstar.is_none() {
+    walker = walker.max_depth(segments.len());
+  }
+  Ok(
+    walker
+      .into_iter()
+      .filter_entry(|entry| {
+        // the components after the first `**` may match any directory
+        if entry.depth() == 0 || !entry.file_type().is_dir() {
+          return true;
+        }
+        segment_patterns
+          .get(entry.depth() - 1)
+          .map_or(true, |segment| {
+            segment.matches_with(&entry.file_name().to_string_lossy(), match_options)
+          })
+      })
+      .filter_map(|entry| entry.ok())
+      .map(|entry| entry.into_path())
+      .filter(|path| pattern.matches_path_with(path, match_options))
+      .collect(),
+  )
+}
+
+/// Splits a glob into the directory it walks, which is made of the leading components without
+/// any glob characters, and the components after it.
+fn split_glob(glob_query: &str) -> (PathBuf, Vec<String>) {
+  let mut components = Path::new(glob_query).components().peekable();
+  let mut root = PathBuf::new();
+  while let Some(component) = components.next_if(|component| {
+    let component = component.as_os_str().to_string_lossy();
+    !component.contains(['*', '?', '[', ']', '{']) && !component.contains("!(")
+  }) {
+    root.push(component);
+  }
+  let segments = components
+    .map(|component| component.as_os_str().to_string_lossy().to_string())
+    .collect();
+  (root, segments)
+}
+
+/// The directory walked by a glob.
+fn get_glob_root(glob_query: &str) -> PathBuf {
+  split_glob(glob_query).0
+}
+
+/// Expands the `{a,b}` alternatives and the `!(a|b)` negations of a glob, which the glob crate
+/// doesn't support, into the plain globs to walk and the plain globs of the files to leave out.
+/// `\{`, `\}` and `\!` stand for the literal characters.
+fn expand_extended_glob(glob: &str) -> (Vec<String>, Vec<String>) {
+  let mut queries = vec![];
+  let mut negated_queries = vec![];
+  for glob in expand_braces(glob) {
+    let negations = find_negations(&glob);
+    if negations.is_empty() {
+      queries.push(unescape_extended_glob(&glob));
+      continue;
+    }
+    // `a/!(*.map)` walks `a/*` and leaves out `a/*.map`
+    let replace_negations = |negated: Option<(usize, &str)>| {
+      let mut replaced = String::with_capacity(glob.len());
+      let mut last = 0;
+      for (index, &(start, end)) in negations.iter().enumerate() {
+        replaced.push_str(&glob[last..start]);
+        match negated {
+          Some((negated_index, alternative)) if negated_index == index => {
+            replaced.push_str(alternative)
+          }
+          _ => replaced.push('*'),
+        }
+        last = end;
+      }
+      replaced.push_str(&glob[last..]);
+      unescape_extended_glob(&replaced)
+    };
+    queries.push(replace_negations(None));
+    for (index, &(start, end)) in negations.iter().enumerate() {
+      // the content between `!(` and `)`
+      for alternative in glob[start + 2..end - 1].split('|') {
+        negated_queries.push(replace_negations(Some((index, alternative))));
+      }
+    }
+  }
+  (queries, negated_queries)
+}
+
+fn expand_braces(glob: &str) -> Vec<String> {
+  let bytes = glob.as_bytes();
+  let mut open = None;
+  let mut depth = 0;
+  let mut commas = vec![];
+  let mut index = 0;
+  while index < bytes.len() {
+    match bytes[index] {
+      b'\\' => index += 1,
+      b'{' => {
+        if depth == 0 {
+          open = Some(index);
+          commas.clear();
+        }
+        depth += 1;
+      }
+      b',' if depth == 1 => commas.push(index),
+      b'}' if depth > 0 => {
+        depth -= 1;
+        if depth == 0
+          && let Some(open) = open
+          && !commas.is_empty()
+        {
+          let (prefix, suffix) = (&glob[..open], &glob[index + 1..]);
+          let mut bounds = vec![open];
+          bounds.extend(commas.iter().copied());
+          bounds.push(index);
+          return bounds
+            .windows(2)
+            .flat_map(|window| {
+              expand_braces(&format!(
+                "{prefix}{}{suffix}",
+                &glob[window[0] + 1..window[1]]
+              ))
+            })
+            .collect();
+        }
+      }
+      _ => {}
+    }
+    index += 1;
+  }
+  vec![glob.to_string()]
+}
+
+/// The byte ranges of the unescaped `!(...)` in a glob
+fn find_negations(glob: &str) -> Vec<(usize, usize)> {
+  let bytes = glob.as_bytes();
+  let mut negations = vec![];
+  let mut index = 0;
+  while index < bytes.len() {
+    match bytes[index] {
+      b'\\' => index += 1,
+      b'!' if bytes.get(index + 1) == Some(&b'(') => {
+        if let Some(close) = glob[index..].find(')') {
+          negations.push((index, index + close + 1));
+          index += close;
+        }
+      }
+      _ => {}
+    }
+    index += 1;
+  }
+  negations
+}
+
+fn unescape_extended_glob(glob: &str) -> String {
+  glob
+    .replace("\\!", "!")
+    .replace("\\{", "{")
+    .replace("\\}", "}")
+}
+
+/// Whether `to` resolved against `output_path` stays inside of it
+fn is_inside_output_path(to: &str, output_path: &Path) -> bool {
+  let output_path = output_path.normalize();
+  output_path.join(to).normalize().starts_with(&output_path)
+}
+
 fn escape_glob_chars(s: &str) -> String {
   let mut escaped = String::with_capacity(s.len());
   for c in s.chars() {
@@ -597,8 +940,163 @@ fn escape_glob_chars(s: &str) -> String {
   escaped
 }
 
+#[test]
+fn test_is_inside_output_path() {
+  let output_path = Path::new("/project/dist");
+  assert!(is_inside_output_path("a/b.txt", output_path));
+  assert!(is_inside_output_path("a/../b.txt", output_path));
+  assert!(is_inside_output_path("/project/dist/b.txt", output_path));
+  assert!(!is_inside_output_path("../b.txt", output_path));
+  assert!(!is_inside_output_path("/project/b.txt", output_path));
+  assert!(!is_inside_output_path("/project/dist-other/b.txt", output_path));
+}
+
 #[test]
 fn test_escape() {
   assert_eq!(escape_glob_chars("a/b/**/*.js"), r#"a/b/\*\*/\*.js"#);
   assert_eq!(escape_glob_chars("a/b/c"), r#"a/b/c"#);
 }
+
+#[test]
+fn test_expand_extended_glob() {
+  assert_eq!(
+    expand_extended_glob("src/**/*.{png,jpg}"),
+    (
+      vec!["src/**/*.png".to_string(), "src/**/*.jpg".to_string()],
+      vec![]
+    )
+  );
+  assert_eq!(
+    expand_extended_glob("assets/**/!(*.map|*.d.ts)"),
+    (
+      vec!["assets/**/*".to_string()],
+      vec![
+        "assets/**/*.map".to_string(),
+        "assets/**/*.d.ts".to_string()
+      ]
+    )
+  );
+  assert_eq!(
+    expand_extended_glob("{a,b/{c,d}}/*.js"),
+    (
+      vec![
+        "a/*.js".to_string(),
+        "b/c/*.js".to_string(),
+        "b/d/*.js".to_string()
+      ],
+      vec![]
+    )
+  );
+  assert_eq!(
+    expand_extended_glob(r"a/\!(b).js"),
+    (vec!["a/!(b).js".to_string()], vec![])
+  );
+  assert_eq!(
+    expand_extended_glob(r"a/\{b,c\}.js"),
+    (vec!["a/{b,c}.js".to_string()], vec![])
+  );
+}
+
+#[test]
+fn test_glob_root() {
+  assert_eq!(get_glob_root("/a/b/**/*.js"), PathBuf::from("/a/b"));
+  assert_eq!(get_glob_root("/a/b/c[0-9]/d.js"), PathBuf::from("/a/b"));
+  assert_eq!(get_glob_root("/a/assets"), PathBuf::from("/a/assets"));
+  assert_eq!(get_glob_root("/a/{b,c}/d.js"), PathBuf::from("/a"));
+}
+
+#[test]
+fn test_split_glob() {
+  assert_eq!(
+    split_glob("/a/b/**/*.js"),
+    (
+      PathBuf::from("/a/b"),
+      vec!["**".to_string(), "*.js".to_string()]
+    )
+  );
+  assert_eq!(
+    split_glob("/a/assets"),
+    (PathBuf::from("/a/assets"), vec![])
+  );
+}
+
+#[test]
+fn test_walk_glob_prunes_directories() {
+  let root = std::env::temp_dir().join(format!("rspack-copy-walk-prune-{}", std::process::id()));
+  let _ = fs::remove_dir_all(&root);
+  for dir in ["node_modules/pkg", "docs/guide", "src"] {
+    fs::create_dir_all(root.join(dir)).expect("should create dir");
+  }
+  for file in [
+    "README.md",
+    "node_modules/pkg/README.md",
+    "docs/index.md",
+    "docs/guide/intro.md",
+    "src/index.md",
+  ] {
+    fs::write(root.join(file), "").expect("should write file");
+  }
+
+  let files = |glob: &str| {
+    walk_glob(
+      &format!("{}/{glob}", root.display()),
+      MatchOptions::new(),
+      true,
+    )
+    .expect("should be a valid glob")
+    .into_iter()
+    .map(|path| {
+      path
+        .strip_prefix(&root)
+        .expect("should be under root")
+        .to_path_buf()
+    })
+    .collect::<Vec<_>>()
+  };
+  // not deeper than the glob reaches
+  assert_eq!(files("*.md"), vec![PathBuf::from("README.md")]);
+  // only into the directories matching the components before `**`
+  assert_eq!(
+    files("d*/**/*.md"),
+    vec![
+      PathBuf::from("docs/guide/intro.md"),
+      PathBuf::from("docs/index.md")
+    ]
+  );
+  fs::remove_dir_all(&root).expect("should remove dir");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_walk_glob_symlinks() {
+  let root = std::env::temp_dir().join(format!("rspack-copy-walk-glob-{}", std::process::id()));
+  let _ = fs::remove_dir_all(&root);
+  fs::create_dir_all(root.join("dir")).expect("should create dir");
+  fs::write(root.join("dir/file.txt"), "").expect("should write file");
+  std::os::unix::fs::symlink(root.join("dir"), root.join("dir-ln")).expect("should link dir");
+  // a link to its own parent directory, which would be walked forever
+  std::os::unix::fs::symlink(root.join("dir"), root.join("dir/cycle")).expect("should link dir");
+  let query = format!("{}/**/*.txt", root.display());
+
+  let files = |follow_symbolic_links| {
+    walk_glob(&query, MatchOptions::new(), follow_symbolic_links)
+      .expect("should be a valid glob")
+      .into_iter()
+      .map(|path| {
+        path
+          .strip_prefix(&root)
+          .expect("should be under root")
+          .to_path_buf()
+      })
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(
+    files(true),
+    vec![
+      PathBuf::from("dir/file.txt"),
+      PathBuf::from("dir-ln/file.txt")
+    ]
+  );
+  assert_eq!(files(false), vec![PathBuf::from("dir/file.txt")]);
+  fs::remove_dir_all(&root).expect("should remove dir");
+}
@@ -0,0 +1,51 @@
+use rspack_core::{
+  AsDependencyTemplate, AsModuleDependency, Dependency, DependencyId, DependencyType,
+  ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// Provides the locals of a css module, including the values of its `:export` blocks, as named
+/// exports. Names that are not valid identifiers, e.g. `primary-color`, are still string keys of
+/// the exported object.
+#[derive(Debug, Clone)]
+pub struct CssModuleExportDependency {
+  id: DependencyId,
+  exports: Vec<JsWord>,
+}
+
+impl CssModuleExportDependency {
+  pub fn new(exports: Vec<JsWord>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      exports,
+    }
+  }
+}
+
+impl Dependency for CssModuleExportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssModuleExport
+  }
+
+  fn get_exports(&self) -> Option<ExportsSpec> {
+    Some(ExportsSpec {
+      exports: ExportsOfExportsSpec::Array(
+        self
+          .exports
+          .iter()
+          .cloned()
+          .map(ExportNameOrSpec::String)
+          .collect(),
+      ),
+      ..Default::default()
+    })
+  }
+}
+
+impl AsModuleDependency for CssModuleExportDependency {}
+
+impl AsDependencyTemplate for CssModuleExportDependency {}
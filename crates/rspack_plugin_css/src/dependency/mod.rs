@@ -1,7 +1,9 @@
 mod compose;
+mod export;
 mod import;
 mod url;
 
 pub use compose::*;
+pub use export::*;
 pub use import::*;
 pub use url::*;
@@ -62,7 +62,7 @@ impl Dependency for CssUrlDependency {
   }
 
   fn category(&self) -> &DependencyCategory {
-    &DependencyCategory::Url
+    &DependencyCategory::Asset
   }
 
   fn dependency_type(&self) -> &DependencyType {
@@ -21,12 +21,12 @@ use sugar_path::SugarPath;
 use swc_core::{css::parser::parser::ParserConfig, ecma::atoms::JsWord};
 
 use crate::{
-  dependency::CssComposeDependency,
+  dependency::{CssComposeDependency, CssModuleExportDependency},
   swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig},
 };
 use crate::{
   plugin::CssConfig,
-  utils::{css_modules_exports_to_string, ModulesTransformConfig},
+  utils::{css_modules_exports_to_string, take_icss_exports, ModulesTransformConfig},
 };
 use crate::{
   utils::{stringify_css_modules_exports_elements, stringify_css_modules_exports_key},
@@ -128,6 +128,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
       );
       let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
       exports.sort_keys();
+      let icss_exports = take_icss_exports(&mut stylesheet)?;
 
       self.exports = Some(IndexMap::from_iter(
         exports
@@ -138,6 +139,15 @@ impl ParserAndGenerator for CssParserAndGenerator {
               stringify_css_modules_exports_elements(elements),
             )
           })
+          .chain(icss_exports.iter().map(|(key, value)| {
+            (
+              stringify_css_modules_exports_key(key, &self.config.modules.locals_convention),
+              vec![(
+                serde_json::to_string(value).expect("should stringify a string"),
+                None,
+              )],
+            )
+          }))
           .collect::<Vec<_>>(),
       ));
 
@@ -183,6 +193,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
         None
       });
       dependencies.extend(compose_deps);
+      let export_names = locals
+        .keys()
+        .flatten()
+        .filter_map(|key| serde_json::from_str::<String>(key).ok())
+        .map(JsWord::from)
+        .collect();
+      dependencies.push(Box::new(CssModuleExportDependency::new(export_names)));
       dependencies
     } else {
       dependencies
@@ -244,12 +261,14 @@ impl ParserAndGenerator for CssParserAndGenerator {
           .expect("should have module graph module");
 
         mgm.dependencies.iter().for_each(|id| {
-          if let Some(dependency) = compilation
+          let dependency = compilation
             .module_graph
             .dependency_by_id(id)
-            .expect("should have dependency")
-            .as_dependency_template()
-          {
+            .expect("should have dependency");
+          if dependency.build_time_only() {
+            return;
+          }
+          if let Some(dependency) = dependency.as_dependency_template() {
             dependency.apply(&mut source, &mut context)
           }
         });
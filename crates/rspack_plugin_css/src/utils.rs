@@ -4,9 +4,17 @@ use heck::{ToKebabCase, ToLowerCamelCase};
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_core::{Compilation, OutputOptions, PathData, RuntimeGlobals};
+use rspack_core::{Compilation, OutputOptions, PathData, RuntimeGlobals, UsageState, UsedName};
 use rspack_error::{internal_error, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash};
+use swc_core::css::ast::{
+  ComplexSelectorChildren, ComponentValue, DeclarationName, QualifiedRule, QualifiedRulePrelude,
+  Rule, Stylesheet, SubclassSelector,
+};
+use swc_core::css::codegen::{
+  writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+  CodeGenerator, CodegenConfig, Emit,
+};
 use swc_core::css::modules::CssClassName;
 use swc_core::ecma::atoms::JsWord;
 
@@ -111,13 +119,97 @@ pub fn stringify_css_modules_exports_elements(
     .collect::<Vec<_>>()
 }
 
+/// Whether the rule is a `:export { name: value }` block of css modules.
+fn is_icss_export_rule(rule: &QualifiedRule) -> bool {
+  let QualifiedRulePrelude::SelectorList(selector_list) = &rule.prelude else {
+    return false;
+  };
+  let [complex_selector] = selector_list.children.as_slice() else {
+    return false;
+  };
+  let [ComplexSelectorChildren::CompoundSelector(compound_selector)] =
+    complex_selector.children.as_slice()
+  else {
+    return false;
+  };
+  compound_selector.nesting_selector.is_none()
+    && compound_selector.type_selector.is_none()
+    && matches!(
+      compound_selector.subclass_selectors.as_slice(),
+      [SubclassSelector::PseudoClass(pseudo_class)]
+        if &*pseudo_class.name.value == "export" && pseudo_class.children.is_none()
+    )
+}
+
+/// Takes the `:export { name: value }` blocks out of the stylesheet, they only provide values
+/// to js and are not part of the css.
+pub fn take_icss_exports(stylesheet: &mut Stylesheet) -> Result<Vec<(JsWord, String)>> {
+  let mut exports = vec![];
+  let mut error = None;
+  stylesheet.rules.retain(|rule| {
+    let Rule::QualifiedRule(rule) = rule else {
+      return true;
+    };
+    if !is_icss_export_rule(rule) {
+      return true;
+    }
+    for value in &rule.block.value {
+      let ComponentValue::Declaration(declaration) = value else {
+        continue;
+      };
+      let name: JsWord = match &declaration.name {
+        DeclarationName::Ident(ident) => ident.value.clone(),
+        DeclarationName::DashedIdent(ident) => format!("--{}", ident.value).into(),
+      };
+      // `name: value` is printed as is, the value is everything after the colon
+      let mut code = String::new();
+      let emitted = CodeGenerator::new(
+        BasicCssWriter::new(&mut code, None, BasicCssWriterConfig::default()),
+        CodegenConfig { minify: false },
+      )
+      .emit(&**declaration);
+      if let Err(e) = emitted {
+        error.get_or_insert(e);
+        continue;
+      }
+      let value = code
+        .split_once(':')
+        .map(|(_, value)| value.trim().to_string())
+        .unwrap_or_default();
+      exports.push((name, value));
+    }
+    false
+  });
+  if let Some(e) = error {
+    return Err(internal_error!(e.to_string()));
+  }
+  Ok(exports)
+}
+
 pub fn css_modules_exports_to_string(
   exports: &IndexMap<Vec<String>, Vec<(String, Option<String>)>>,
   module: &dyn rspack_core::Module,
   compilation: &Compilation,
 ) -> Result<String> {
+  // the exports that are never referenced are dropped
+  let exports_info = compilation.options.is_new_tree_shaking().then(|| {
+    compilation
+      .module_graph
+      .get_exports_info(&module.identifier())
+  });
+  let is_unused = |item: &str| {
+    exports_info.is_some_and(|exports_info| {
+      serde_json::from_str::<String>(item).is_ok_and(|name| {
+        exports_info.get_used(UsedName::Str(name.into()), None, &compilation.module_graph)
+          == UsageState::Unused
+      })
+    })
+  };
   let mut code = String::from("module.exports = {\n");
   for (key, elements) in exports {
+    if key.iter().all(|item| is_unused(item)) {
+      continue;
+    }
     let content = elements
       .iter()
       .map(|(name, from)| {
@@ -153,7 +245,7 @@ pub fn css_modules_exports_to_string(
       })
       .collect::<Vec<_>>()
       .join(" + \" \" + ");
-    for item in key {
+    for item in key.iter().filter(|item| !is_unused(item)) {
       writeln!(code, "  {}: {},", item, content).map_err(|e| internal_error!(e.to_string()))?;
     }
   }
@@ -33,10 +33,15 @@ impl Plugin for EntryPlugin {
     if let Some(state) = compilation.options.get_incremental_rebuild_make_state() && !state.is_first() {
       return Ok(());
     }
-    let dependency: BoxDependency = Box::new(EntryDependency::new(
+    let mut dependency = EntryDependency::new(
       self.entry_request.clone(),
       self.context.clone(),
-    ));
+      &self.options,
+    );
+    if let Some(library) = &compilation.options.output.library {
+      dependency = dependency.with_library_export(library.export.clone().unwrap_or_default());
+    }
+    let dependency: BoxDependency = Box::new(dependency);
     let dependency_id = dependency.id();
     compilation.add_entry(*dependency_id, self.options.clone());
     param.add_force_build_dependency(*dependency_id, None);
@@ -36,6 +36,7 @@ pub fn stringify(
         emit_columns: !devtool.cheap(),
         names: Default::default(),
       },
+      None,
       false,
       if let Some(true) = keep_comments {
         program.comments.as_ref().map(|c| c as &dyn Comments)
@@ -47,11 +48,15 @@ pub fn stringify(
   })
 }
 
+/// The source map is composed with `input_source_map` when given, so it points at the sources
+/// the printed program was generated from.
+#[allow(clippy::too_many_arguments)]
 pub fn print(
   node: &SwcProgram,
   source_map: Arc<SourceMap>,
   target: EsVersion,
   source_map_config: SourceMapConfig,
+  input_source_map: Option<&sourcemap::SourceMap>,
   minify: bool,
   comments: Option<&dyn Comments>,
   ascii_only: bool,
@@ -96,7 +101,7 @@ pub fn print(
     let mut buf = vec![];
 
     source_map
-      .build_source_map_with_config(&src_map_buf, None, source_map_config)
+      .build_source_map_with_config(&src_map_buf, input_source_map, source_map_config)
       .to_writer(&mut buf)
       .map_err(|e| internal_error!(e.to_string()))?;
     // SAFETY: This buffer is already sanitized
@@ -136,3 +141,70 @@ impl SourceMapGenConfig for SourceMapConfig {
     self.names.get(&pos).map(|v| &**v)
   }
 }
+
+#[cfg(test)]
+mod test {
+  use swc_core::{common::GLOBALS, ecma::parser::Syntax};
+
+  use super::*;
+  use crate::{ast::parse_js, IsModule};
+
+  fn print_mappings(emit_columns: bool) -> String {
+    let source_map: Arc<SourceMap> = Default::default();
+    let fm = source_map.new_source_file(
+      FileName::Custom("main.js".to_string()),
+      "const first = 1; const second = 2;\nconsole.log(first, second);\n".to_string(),
+    );
+    GLOBALS.set(&Default::default(), || {
+      let program = parse_js(
+        fm,
+        EsVersion::Es2022,
+        Syntax::Es(Default::default()),
+        IsModule::Bool(true),
+        None,
+      )
+      .expect("should parse");
+      let output = print(
+        &program,
+        source_map.clone(),
+        EsVersion::Es2022,
+        SourceMapConfig {
+          enable: true,
+          inline_sources_content: false,
+          emit_columns,
+          names: Default::default(),
+        },
+        None,
+        false,
+        None,
+        false,
+      )
+      .expect("should print");
+      let map: serde_json::Value =
+        serde_json::from_str(&output.map.expect("should have map")).expect("valid map");
+      map["mappings"]
+        .as_str()
+        .expect("should have mappings")
+        .to_string()
+    })
+  }
+
+  #[test]
+  fn line_only_source_map_keeps_the_mapped_lines() {
+    let with_columns = print_mappings(true);
+    let line_only = print_mappings(false);
+    assert!(line_only.len() < with_columns.len());
+
+    let mapped_lines = |mappings: &str| {
+      mappings
+        .split(';')
+        .map(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(mapped_lines(&line_only), mapped_lines(&with_columns));
+    // one segment per line, starting at generated column 0
+    assert!(line_only
+      .split(';')
+      .all(|line| line.is_empty() || (!line.contains(',') && line.starts_with('A'))));
+  }
+}
@@ -4,3 +4,7 @@ mod require_resolve_dependency;
 pub use require_resolve_dependency::RequireResolveDependency;
 mod module_decorator_dependency;
 pub use module_decorator_dependency::ModuleDecoratorDependency;
+mod require_ensure_dependency;
+pub use require_ensure_dependency::RequireEnsureDependency;
+mod require_ensure_item_dependency;
+pub use require_ensure_item_dependency::RequireEnsureItemDependency;
@@ -1,19 +1,40 @@
 use rspack_core::{
-  DependencyTemplate, InitFragmentStage, NormalInitFragment, RuntimeGlobals, TemplateContext,
+  AsModuleDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, InitFragmentStage, NormalInitFragment, RuntimeGlobals, TemplateContext,
   TemplateReplaceSource,
 };
 
 #[derive(Debug, Clone)]
 pub struct ModuleDecoratorDependency {
+  id: DependencyId,
   decorator: RuntimeGlobals,
 }
 
 impl ModuleDecoratorDependency {
   pub fn new(decorator: RuntimeGlobals) -> Self {
-    Self { decorator }
+    Self {
+      id: DependencyId::new(),
+      decorator,
+    }
   }
 }
 
+impl Dependency for ModuleDecoratorDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ModuleDecorator
+  }
+}
+
+impl AsModuleDependency for ModuleDecoratorDependency {}
+
 impl DependencyTemplate for ModuleDecoratorDependency {
   fn apply(
     &self,
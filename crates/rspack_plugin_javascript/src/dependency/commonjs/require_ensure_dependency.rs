@@ -0,0 +1,104 @@
+use rspack_core::{
+  block_promise, module_id, AsModuleDependency, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// Rewrites `require.ensure(["./a"], function(require) {}, function(err) {}, "name")` to
+/// load the chunks of its [crate::dependency::RequireEnsureItemDependency] and call the
+/// callback with `__webpack_require__` afterwards.
+#[derive(Debug, Clone)]
+pub struct RequireEnsureDependency {
+  id: DependencyId,
+  start: u32,
+  end: u32,
+  callback_range: (u32, u32),
+  error_callback_range: Option<(u32, u32)>,
+  items: Vec<(DependencyId, JsWord)>,
+}
+
+impl RequireEnsureDependency {
+  pub fn new(
+    start: u32,
+    end: u32,
+    callback_range: (u32, u32),
+    error_callback_range: Option<(u32, u32)>,
+    items: Vec<(DependencyId, JsWord)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      start,
+      end,
+      callback_range,
+      error_callback_range,
+      items,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsure
+  }
+}
+
+impl AsModuleDependency for RequireEnsureDependency {}
+
+impl DependencyTemplate for RequireEnsureDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      compilation,
+      ..
+    } = code_generatable_context;
+
+    let mut promises = self
+      .items
+      .iter()
+      .map(|(id, request)| {
+        block_promise(
+          &module_id(compilation, id, request, false),
+          runtime_requirements,
+        )
+      })
+      .collect::<Vec<_>>();
+    let promise = match promises.len() {
+      0 => "Promise.resolve()".to_string(),
+      1 => promises.remove(0),
+      _ => format!("Promise.all([{}])", promises.join(", ")),
+    };
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+
+    let (callback_start, callback_end) = self.callback_range;
+    source.replace(
+      self.start,
+      callback_start,
+      format!("{promise}.then((").as_str(),
+      None,
+    );
+    let bind = format!(").bind(null, {}))", RuntimeGlobals::REQUIRE);
+    if let Some((error_callback_start, error_callback_end)) = self.error_callback_range {
+      source.replace(
+        callback_end,
+        error_callback_start,
+        format!("{bind}['catch'](").as_str(),
+        None,
+      );
+      source.replace(error_callback_end, self.end, ")", None);
+    } else {
+      source.replace(callback_end, self.end, bind.as_str(), None);
+    }
+  }
+}
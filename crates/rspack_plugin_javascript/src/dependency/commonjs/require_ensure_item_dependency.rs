@@ -0,0 +1,96 @@
+use rspack_core::{
+  module_id, ChunkGroupOptions, ChunkGroupOptionsKindRef, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ModuleDependency, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// A module loaded by `require.ensure`, either listed in its dependencies array or
+/// required through the `require` parameter of its callback
+#[derive(Debug, Clone)]
+pub struct RequireEnsureItemDependency {
+  id: DependencyId,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  /// The range of the request in `require("./a")` inside the callback, items of the
+  /// dependencies array are not rendered
+  request_range: Option<(u32, u32)>,
+  group_options: ChunkGroupOptions,
+}
+
+impl RequireEnsureItemDependency {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    request_range: Option<(u32, u32)>,
+    group_options: ChunkGroupOptions,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+      request_range,
+      group_options,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsureItem
+  }
+}
+
+impl ModuleDependency for RequireEnsureItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn group_options(&self) -> Option<ChunkGroupOptionsKindRef> {
+    Some(ChunkGroupOptionsKindRef::Normal(&self.group_options))
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+impl DependencyTemplate for RequireEnsureItemDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let Some((start, end)) = self.request_range else {
+      return;
+    };
+    source.replace(
+      start,
+      end,
+      module_id(
+        code_generatable_context.compilation,
+        &self.id,
+        &self.request,
+        false,
+      )
+      .as_str(),
+      None,
+    );
+  }
+}
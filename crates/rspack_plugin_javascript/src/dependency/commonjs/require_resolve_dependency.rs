@@ -46,7 +46,11 @@ impl Dependency for RequireResolveDependency {
   }
 
   fn dependency_type(&self) -> &DependencyType {
-    &DependencyType::RequireResolve
+    if self.weak {
+      &DependencyType::RequireResolveWeak
+    } else {
+      &DependencyType::RequireResolve
+    }
   }
 }
 
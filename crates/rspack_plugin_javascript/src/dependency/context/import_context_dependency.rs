@@ -1,7 +1,8 @@
 use rspack_core::{
-  create_resource_identifier_for_context_dependency, module_id_expr, normalize_context,
-  ContextOptions, Dependency, DependencyCategory, DependencyId, DependencyTemplate, DependencyType,
-  ErrorSpan, ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+  context_dependency_modules_count, create_resource_identifier_for_context_dependency,
+  module_id_expr, normalize_context, ContextOptions, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, ErrorSpan, ModuleDependency, ModuleGraph, RuntimeGlobals,
+  TemplateContext, TemplateReplaceSource,
 };
 
 #[derive(Debug, Clone)]
@@ -48,6 +49,10 @@ impl Dependency for ImportContextDependency {
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::ImportContext
   }
+
+  fn modules_count(&self, module_graph: &ModuleGraph) -> usize {
+    context_dependency_modules_count(&self.id, module_graph)
+  }
 }
 
 impl ModuleDependency for ImportContextDependency {
@@ -4,3 +4,5 @@ mod common_js_require_context_dependency;
 pub use common_js_require_context_dependency::CommonJsRequireContextDependency;
 mod require_context_dependency;
 pub use require_context_dependency::RequireContextDependency;
+mod import_meta_context_dependency;
+pub use import_meta_context_dependency::ImportMetaContextDependency;
@@ -1,7 +1,8 @@
 use rspack_core::{
-  create_resource_identifier_for_context_dependency, module_id_expr, ContextOptions, Dependency,
-  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ErrorSpan,
-  ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+  context_dependency_modules_count, create_resource_identifier_for_context_dependency,
+  module_id_expr, ContextOptions, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ModuleDependency, ModuleGraph, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
 };
 
 #[derive(Debug, Clone)]
@@ -40,6 +41,10 @@ impl Dependency for RequireContextDependency {
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::RequireContext
   }
+
+  fn modules_count(&self, module_graph: &ModuleGraph) -> usize {
+    context_dependency_modules_count(&self.id, module_graph)
+  }
 }
 
 impl ModuleDependency for RequireContextDependency {
@@ -1,3 +1,6 @@
+use std::hash::BuildHasherDefault;
+
+use indexmap::IndexSet;
 use rspack_core::{
   create_exports_object_referenced, create_no_exports_referenced, export_from_import,
   get_exports_type, process_export_info, ConnectionState, Dependency, DependencyCategory,
@@ -6,7 +9,7 @@ use rspack_core::{
   ModuleDependency, ModuleGraph, ModuleIdentifier, RuntimeSpec, TemplateContext,
   TemplateReplaceSource, UsageState,
 };
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::atoms::JsWord;
 
 use super::create_resource_identifier_for_esm_dependency;
@@ -21,6 +24,7 @@ pub struct HarmonyExportImportedSpecifierDependency {
   pub request: JsWord,
   pub ids: Vec<(JsWord, Option<JsWord>)>,
   name: Option<JsWord>,
+  span: Option<ErrorSpan>,
   resource_identifier: String,
   // Because it is shared by multiply HarmonyExportImportedSpecifierDependency, so put it to `BuildInfo`
   // pub active_exports: HashSet<JsWord>,
@@ -29,19 +33,28 @@ pub struct HarmonyExportImportedSpecifierDependency {
 }
 
 impl HarmonyExportImportedSpecifierDependency {
-  pub fn new(request: JsWord, ids: Vec<(JsWord, Option<JsWord>)>, name: Option<JsWord>) -> Self {
+  pub fn new(
+    request: JsWord,
+    ids: Vec<(JsWord, Option<JsWord>)>,
+    name: Option<JsWord>,
+    span: Option<ErrorSpan>,
+  ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
       id: DependencyId::new(),
       name,
       request,
       ids,
+      span,
       resource_identifier,
       other_star_exports: None,
     }
   }
 
-  pub fn active_exports<'a>(&self, module_graph: &'a ModuleGraph) -> &'a HashSet<JsWord> {
+  pub fn active_exports<'a>(
+    &self,
+    module_graph: &'a ModuleGraph,
+  ) -> &'a IndexSet<JsWord, BuildHasherDefault<FxHasher>> {
     let build_info = module_graph
       .module_graph_module_by_dependency_id(&self.id)
       .expect("should have mgm")
@@ -51,6 +64,16 @@ impl HarmonyExportImportedSpecifierDependency {
     &build_info.harmony_named_exports
   }
 
+  /// Names read from the source module, e.g. `default` for `export { default as Foo } from "./m"`.
+  /// `export * as ns` has none since the whole namespace object is re-exported.
+  pub fn imported_ids(&self) -> Vec<JsWord> {
+    self
+      .ids
+      .iter()
+      .filter_map(|(_, imported)| imported.clone())
+      .collect()
+  }
+
   pub fn all_star_exports<'a>(&self, module_graph: &'a ModuleGraph) -> &'a Vec<DependencyId> {
     let build_info = module_graph
       .module_graph_module_by_dependency_id(&self.id)
@@ -225,7 +248,7 @@ impl HarmonyExportImportedSpecifierDependency {
     let no_extra_exports = matches!(other_export_info.provided, Some(ExportInfoProvided::False));
     let no_extra_imports = matches!(other_export_info.get_used(runtime), UsageState::Unused);
     let ignored_exports: HashSet<JsWord> = {
-      let mut e = self.active_exports(module_graph).clone();
+      let mut e: HashSet<JsWord> = self.active_exports(module_graph).iter().cloned().collect();
       e.insert("default".into());
       e
     };
@@ -276,9 +299,10 @@ impl HarmonyExportImportedSpecifierDependency {
         ) {
           continue;
         }
-        if let Some(hidden) = hidden.as_mut() && hidden_exports.as_ref()
-          .map(|hidden_exports| hidden_exports.contains(&export_name))
-          .is_some()
+        if let Some(hidden) = hidden.as_mut()
+          && hidden_exports
+            .as_ref()
+            .is_some_and(|hidden_exports| hidden_exports.contains(&export_name))
         {
           hidden.insert(export_name.clone());
           continue;
@@ -310,9 +334,10 @@ impl HarmonyExportImportedSpecifierDependency {
         if matches!(export_info.get_used(runtime), UsageState::Unused) {
           continue;
         }
-        if let Some(hidden) = hidden.as_mut() && hidden_exports.as_ref()
-          .map(|hidden_exports| hidden_exports.contains(&import_export_info_name))
-          .is_some()
+        if let Some(hidden) = hidden.as_mut()
+          && hidden_exports
+            .as_ref()
+            .is_some_and(|hidden_exports| hidden_exports.contains(&import_export_info_name))
         {
           hidden.insert(import_export_info_name.clone());
           continue;
@@ -432,7 +457,7 @@ impl ModuleDependency for HarmonyExportImportedSpecifierDependency {
   }
 
   fn span(&self) -> Option<&ErrorSpan> {
-    None
+    self.span.as_ref()
   }
 
   fn set_request(&mut self, request: String) {
@@ -455,11 +480,7 @@ impl ModuleDependency for HarmonyExportImportedSpecifierDependency {
           .expect("should be HarmonyExportImportedSpecifierDependency");
         let mode = down_casted_dep.get_mode(
           down_casted_dep.name.clone(),
-          &down_casted_dep
-            .ids
-            .iter()
-            .map(|id| id.0.clone())
-            .collect::<Vec<_>>(),
+          &down_casted_dep.imported_ids(),
           module_graph,
           &down_casted_dep.id,
           runtime,
@@ -472,12 +493,22 @@ impl ModuleDependency for HarmonyExportImportedSpecifierDependency {
     )))
   }
 
+  // Whether the re-exported names are used is decided by `get_condition`, so a re-export that is
+  // also imported elsewhere keeps its connection. Here we only need to know if evaluating the
+  // source module has side effects, a side-effect-free source can be pruned when unused.
   fn get_module_evaluation_side_effects_state(
     &self,
-    _module_graph: &ModuleGraph,
-    _module_chain: &mut HashSet<ModuleIdentifier>,
+    module_graph: &ModuleGraph,
+    module_chain: &mut HashSet<ModuleIdentifier>,
   ) -> ConnectionState {
-    ConnectionState::Bool(false)
+    if let Some(module) = module_graph
+      .module_identifier_by_dependency_id(&self.id)
+      .and_then(|module_identifier| module_graph.module_by_identifier(module_identifier))
+    {
+      module.get_side_effects_connection_state(module_graph, module_chain)
+    } else {
+      ConnectionState::Bool(false)
+    }
   }
 
   fn get_referenced_exports(
@@ -487,7 +518,7 @@ impl ModuleDependency for HarmonyExportImportedSpecifierDependency {
   ) -> Vec<ExtendedReferencedExport> {
     let mode = self.get_mode(
       self.name.clone(),
-      &self.ids.iter().map(|id| id.0.clone()).collect::<Vec<_>>(),
+      &self.imported_ids(),
       module_graph,
       &self.id,
       runtime,
@@ -1,18 +1,22 @@
+use std::hash::BuildHasherDefault;
+
+use indexmap::IndexSet;
 use rspack_core::tree_shaking::symbol::{self, IndirectTopLevelSymbol};
 use rspack_core::tree_shaking::visitor::SymbolRef;
 use rspack_core::{
   import_statement, ConnectionState, Dependency, DependencyCategory, DependencyCondition,
-  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport,
-  InitFragmentStage, ModuleDependency, ModuleIdentifier, NormalInitFragment, RuntimeGlobals,
-  TemplateContext, TemplateReplaceSource,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExportInfoProvided,
+  ExtendedReferencedExport, HarmonyExportInitFragment, ImportAttributes, InitFragmentStage,
+  ModuleDependency, ModuleIdentifier, NormalInitFragment, ResolvedExportInfoTargetWithCircular,
+  RuntimeGlobals, TemplateContext, TemplateReplaceSource,
 };
 use rspack_core::{ModuleGraph, RuntimeSpec};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::atoms::JsWord;
 
 use super::create_resource_identifier_for_esm_dependency;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Specifier {
   Namespace(JsWord),
   Default(JsWord),
@@ -28,6 +32,8 @@ pub struct HarmonyImportDependency {
   pub specifiers: Vec<Specifier>,
   pub dependency_type: DependencyType,
   pub export_all: bool,
+  // Boxed to keep the dependency small, most imports have no attributes
+  attributes: Option<Box<ImportAttributes>>,
   resource_identifier: String,
 }
 
@@ -38,6 +44,7 @@ impl HarmonyImportDependency {
     specifiers: Vec<Specifier>,
     dependency_type: DependencyType,
     export_all: bool,
+    attributes: Option<ImportAttributes>,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
@@ -47,6 +54,7 @@ impl HarmonyImportDependency {
       specifiers,
       dependency_type,
       export_all,
+      attributes: attributes.map(Box::new),
       resource_identifier,
     }
   }
@@ -77,7 +85,10 @@ impl DependencyTemplate for HarmonyImportDependency {
         .specifiers
         .iter()
         .filter(|specifier| {
-          let is_import = matches!(self.dependency_type, DependencyType::EsmImport);
+          let is_import = matches!(
+            self.dependency_type,
+            DependencyType::EsmImport | DependencyType::JsonImport
+          );
           if is_import && !ref_mgm.module_type.is_js_like() {
             return true;
           }
@@ -99,7 +110,7 @@ impl DependencyTemplate for HarmonyImportDependency {
               }
             }
             Specifier::Named(local, imported) => {
-              let symbol = if matches!(self.dependency_type, DependencyType::EsmImport) {
+              let symbol = if is_import {
                 SymbolRef::Indirect(IndirectTopLevelSymbol {
                   src: ref_mgm.module_identifier,
                   ty: symbol::IndirectType::Import(local.clone(), imported.clone()),
@@ -174,6 +185,29 @@ impl DependencyTemplate for HarmonyImportDependency {
       )));
     }
     if self.export_all {
+      let options = &compilation.options;
+      let build_info = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&module.identifier())
+        .and_then(|mgm| mgm.build_info.as_ref())
+        .expect("should have build info");
+      // `__webpack_require__.es` skips names that are already defined, so shadowing the
+      // ambiguous names once, before any star export runs, hides them from all of them
+      if options.is_new_tree_shaking()
+        && options.optimization.provided_exports
+        && build_info.all_star_exports.first() == Some(&self.id)
+      {
+        for name in get_ambiguous_star_exports(
+          &compilation.module_graph,
+          &build_info.all_star_exports,
+          &build_info.harmony_named_exports,
+        ) {
+          init_fragments.push(Box::new(HarmonyExportInitFragment::new((
+            name,
+            "undefined".into(),
+          ))));
+        }
+      }
       runtime_requirements.insert(RuntimeGlobals::EXPORT_STAR);
       let exports_argument = compilation
         .module_graph
@@ -197,6 +231,50 @@ impl DependencyTemplate for HarmonyImportDependency {
   }
 }
 
+/// Names provided by more than one `export *` that resolve to different bindings, the spec
+/// excludes these from the namespace object. Explicit exports of the module itself win over star
+/// exports, so they are never ambiguous.
+fn get_ambiguous_star_exports(
+  module_graph: &ModuleGraph,
+  star_exports: &[DependencyId],
+  named_exports: &IndexSet<JsWord, BuildHasherDefault<FxHasher>>,
+) -> Vec<JsWord> {
+  let mut bindings: HashMap<JsWord, HashSet<(ModuleIdentifier, Option<Vec<JsWord>>)>> =
+    HashMap::default();
+  for dependency_id in star_exports {
+    let Some(module_identifier) = module_graph.module_identifier_by_dependency_id(dependency_id)
+    else {
+      continue;
+    };
+    let exports_info = module_graph.get_exports_info(module_identifier);
+    for export_info_id in exports_info.exports.values() {
+      let export_info = module_graph.get_export_info_by_id(export_info_id);
+      let Some(name) = export_info.name.as_ref() else {
+        continue;
+      };
+      if name == "default"
+        || !matches!(export_info.provided, Some(ExportInfoProvided::True))
+        || named_exports.contains(name)
+      {
+        continue;
+      }
+      let binding = match export_info.get_terminal_binding(module_graph) {
+        Some(ResolvedExportInfoTargetWithCircular::Target(target)) => {
+          (target.module, target.exports)
+        }
+        _ => (*module_identifier, Some(vec![name.clone()])),
+      };
+      bindings.entry(name.clone()).or_default().insert(binding);
+    }
+  }
+  let mut ambiguous = bindings
+    .into_iter()
+    .filter_map(|(name, bindings)| (bindings.len() > 1).then_some(name))
+    .collect::<Vec<_>>();
+  ambiguous.sort();
+  ambiguous
+}
+
 impl Dependency for HarmonyImportDependency {
   fn id(&self) -> &DependencyId {
     &self.id
@@ -228,6 +306,10 @@ impl ModuleDependency for HarmonyImportDependency {
     self.request = request.into();
   }
 
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_deref()
+  }
+
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
@@ -3,8 +3,8 @@ use rspack_core::{
   get_dependency_used_by_exports_condition, get_exports_type,
   tree_shaking::symbol::DEFAULT_JS_WORD, Compilation, ConnectionState, Dependency,
   DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate, DependencyType,
-  ErrorSpan, ExportsType, ExtendedReferencedExport, ModuleDependency, ModuleGraph,
-  ModuleGraphModule, ModuleIdentifier, ReferencedExport, RuntimeSpec, TemplateContext,
+  ErrorSpan, ExportsReferencedType, ExportsType, ExtendedReferencedExport, ModuleDependency,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport, RuntimeSpec, TemplateContext,
   TemplateReplaceSource, UsedByExports,
 };
 use rustc_hash::FxHashSet as HashSet;
@@ -12,20 +12,25 @@ use swc_core::ecma::atoms::JsWord;
 
 use super::{create_resource_identifier_for_esm_dependency, Specifier};
 
+/// The request, specifier, ids, shorthand, call and direct import of a reference, references
+/// with the same key generate the same code
+pub type HarmonyImportReferenceKey = (JsWord, Specifier, Vec<JsWord>, bool, bool, bool);
+
 #[derive(Debug, Clone)]
 pub struct HarmonyImportSpecifierDependency {
   id: DependencyId,
   request: JsWord,
   shorthand: bool,
-  start: u32,
-  end: u32,
+  /// every occurrence of the reference, identical references in a module share one dependency
+  spans: Vec<(u32, u32)>,
+  span: ErrorSpan,
   ids: Vec<JsWord>,
   call: bool,
   direct_import: bool,
   specifier: Specifier,
   used_by_exports: UsedByExports,
   namespace_object_as_context: bool,
-  referenced_properties_in_destructuring: Option<HashSet<JsWord>>,
+  referenced_properties_in_destructuring: Option<HashSet<Vec<JsWord>>>,
   resource_identifier: String,
 }
 
@@ -40,15 +45,15 @@ impl HarmonyImportSpecifierDependency {
     call: bool,
     direct_import: bool,
     specifier: Specifier,
-    referenced_properties_in_destructuring: Option<HashSet<JsWord>>,
+    referenced_properties_in_destructuring: Option<HashSet<Vec<JsWord>>>,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
       id: DependencyId::new(),
       request,
       shorthand,
-      start,
-      end,
+      spans: vec![(start, end)],
+      span: ErrorSpan::new(start, end),
       ids,
       call,
       direct_import,
@@ -60,6 +65,35 @@ impl HarmonyImportSpecifierDependency {
     }
   }
 
+  pub fn set_id(&mut self, id: DependencyId) {
+    self.id = id;
+  }
+
+  pub fn spans(&self) -> &[(u32, u32)] {
+    &self.spans
+  }
+
+  /// References collecting properties in destructuring have their own referenced exports and
+  /// are never merged
+  pub fn reference_key(&self) -> Option<HarmonyImportReferenceKey> {
+    if self.referenced_properties_in_destructuring.is_some() {
+      return None;
+    }
+    Some((
+      self.request.clone(),
+      self.specifier.clone(),
+      self.ids.clone(),
+      self.shorthand,
+      self.call,
+      self.direct_import,
+    ))
+  }
+
+  /// Record another occurrence of the same reference, which is rewritten by this dependency too
+  pub fn add_span(&mut self, start: u32, end: u32) {
+    self.spans.push((start, end));
+  }
+
   // TODO move export_info
   pub fn check_used(&self, reference_mgm: &ModuleGraphModule, compilation: &Compilation) -> bool {
     if compilation.options.builtins.tree_shaking.is_false() {
@@ -98,13 +132,13 @@ impl HarmonyImportSpecifierDependency {
     if let Some(referenced_properties) = &self.referenced_properties_in_destructuring {
       referenced_properties
         .iter()
-        .map(|prop| {
+        .map(|path| {
           if let Some(v) = ids {
             let mut value = v.clone();
-            value.push(prop.clone());
+            value.extend(path.iter().cloned());
             ReferencedExport::new(value, false)
           } else {
-            ReferencedExport::new(vec![prop.clone()], false)
+            ReferencedExport::new(path.clone(), false)
           }
         })
         .map(ExtendedReferencedExport::Export)
@@ -115,6 +149,26 @@ impl HarmonyImportSpecifierDependency {
       create_exports_object_referenced()
     }
   }
+
+  /// `import * as ns` is only read through the properties collected in destructuring,
+  /// any other usage (including computed access) references the whole namespace object.
+  fn get_namespace_exports_referenced_type(&self) -> ExportsReferencedType {
+    if let Some(referenced_properties) = &self.referenced_properties_in_destructuring {
+      ExportsReferencedType::Partial(Box::new(referenced_properties.iter().cloned().collect()))
+    } else {
+      ExportsReferencedType::Object
+    }
+  }
+
+  fn replace_spans(&self, source: &mut TemplateReplaceSource, value: &str) {
+    for &(start, end) in &self.spans {
+      if self.shorthand {
+        source.insert(end, &format!(": {value}"), None);
+      } else {
+        source.replace(start, end, value, None)
+      }
+    }
+  }
 }
 
 impl DependencyTemplate for HarmonyImportSpecifierDependency {
@@ -135,11 +189,7 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
     if !used {
       // TODO do this by PureExpressionDependency.
       let value = format!("/* \"{}\" unused */null", self.request);
-      if self.shorthand {
-        source.insert(self.end, &format!(": {value}"), None);
-      } else {
-        source.replace(self.start, self.end, &value, None)
-      }
+      self.replace_spans(source, &value);
       return;
     }
 
@@ -157,11 +207,7 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
       self.call,
       !self.direct_import,
     );
-    if self.shorthand {
-      source.insert(self.end, format!(": {export_expr}").as_str(), None);
-    } else {
-      source.replace(self.start, self.end, export_expr.as_str(), None)
-    }
+    self.replace_spans(source, &export_expr);
   }
 }
 
@@ -189,7 +235,7 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
   }
 
   fn span(&self) -> Option<&ErrorSpan> {
-    None
+    Some(&self.span)
   }
 
   fn set_request(&mut self, request: String) {
@@ -219,10 +265,10 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
   ) -> Vec<ExtendedReferencedExport> {
     // namespace import
     if self.ids.is_empty() {
-      return self.get_referenced_exports_in_destructuring(None);
+      return self.get_namespace_exports_referenced_type().into();
     }
 
-    let mut ids = vec![];
+    let mut ids = self.ids.clone();
     let mut namespace_object_as_context = self.namespace_object_as_context;
     if let Some(id) = self.ids.get(0) && id == "default" {
       let parent_module = module_graph.parent_module_by_dependency_id(&self.id).expect("should have parent module");
@@ -232,7 +278,7 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
           if self.ids.len() == 1 {
             return self.get_referenced_exports_in_destructuring(None);
           }
-          ids = self.ids.iter().skip(1).collect::<Vec<_>>();
+          ids = self.ids[1..].to_vec();
           namespace_object_as_context = true;
         }
         ExportsType::Dynamic => {
@@ -247,9 +293,9 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
         return create_exports_object_referenced();
       }
       // remove last one
-      ids.shrink_to(ids.len() - 1);
+      ids.pop();
     }
 
-    self.get_referenced_exports_in_destructuring(Some(&self.ids))
+    self.get_referenced_exports_in_destructuring(Some(&ids))
   }
 }
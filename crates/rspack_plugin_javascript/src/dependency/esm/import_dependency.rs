@@ -13,7 +13,12 @@ pub struct ImportDependency {
   id: DependencyId,
   request: JsWord,
   span: Option<ErrorSpan>,
+  /// The exports given by `webpackExports` together with those read in `.then()`, the whole
+  /// namespace object is referenced if `None`
   referenced_exports: Option<Vec<JsWord>>,
+  /// `DynamicImport` for `import()`, `DynamicImportEager` for `import()` in the eager mode,
+  /// `SystemImport` for `System.import()`
+  dependency_type: DependencyType,
   /// This is used to implement `webpackChunkName`, `webpackPrefetch` etc.
   /// for example: `import(/* webpackChunkName: "my-chunk-name", webpackPrefetch: true */ './module')`
   pub group_options: ChunkGroupOptions,
@@ -27,6 +32,7 @@ impl ImportDependency {
     span: Option<ErrorSpan>,
     group_options: ChunkGroupOptions,
     referenced_exports: Option<Vec<JsWord>>,
+    dependency_type: DependencyType,
   ) -> Self {
     Self {
       start,
@@ -35,6 +41,7 @@ impl ImportDependency {
       span,
       id: DependencyId::new(),
       referenced_exports,
+      dependency_type,
       group_options,
     }
   }
@@ -50,7 +57,7 @@ impl Dependency for ImportDependency {
   }
 
   fn dependency_type(&self) -> &DependencyType {
-    &DependencyType::DynamicImport
+    &self.dependency_type
   }
 }
 
@@ -81,7 +88,10 @@ impl ModuleDependency for ImportDependency {
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
     if let Some(referenced_exports) = &self.referenced_exports {
-      vec![ReferencedExport::new(referenced_exports.clone(), false).into()]
+      referenced_exports
+        .iter()
+        .map(|name| ReferencedExport::new(vec![name.clone()], false).into())
+        .collect()
     } else {
       vec![ExtendedReferencedExport::Array(vec![])]
     }
@@ -97,7 +107,14 @@ impl DependencyTemplate for ImportDependency {
     source.replace(
       self.start,
       self.end,
-      module_namespace_promise(code_generatable_context, &self.id, &self.request, false).as_str(),
+      module_namespace_promise(
+        code_generatable_context,
+        &self.id,
+        &self.request,
+        false,
+        matches!(self.dependency_type, DependencyType::DynamicImportEager),
+      )
+      .as_str(),
       None,
     );
   }
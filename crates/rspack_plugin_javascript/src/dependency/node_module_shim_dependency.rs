@@ -0,0 +1,55 @@
+use rspack_core::{
+  DependencyTemplate, InitFragmentStage, NormalInitFragment, TemplateContext, TemplateReplaceSource,
+};
+
+/// Declares `__dirname` and `__filename` for modules rendered into esm output.
+///
+/// Like webpack's `NodeStuffPlugin`, they point to the source module rather than the emitted
+/// chunk: `request` is the module resource relative to `output.path`, resolved against
+/// `import.meta.url` at runtime. This is only accurate for chunks emitted directly into
+/// `output.path`, chunks with a directory in their filename will resolve it from that directory.
+///
+/// The `url` and `path` imports they rely on are added to the chunk by `APIPlugin`.
+#[derive(Debug, Clone)]
+pub struct NodeModuleShimDependency {
+  request: String,
+  dirname: bool,
+  filename: bool,
+}
+
+impl NodeModuleShimDependency {
+  pub fn new(request: String, dirname: bool, filename: bool) -> Self {
+    Self {
+      request,
+      dirname,
+      filename,
+    }
+  }
+}
+
+impl DependencyTemplate for NodeModuleShimDependency {
+  fn apply(
+    &self,
+    _source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let request = serde_json::to_string(&self.request).expect("invalid request");
+    let filename = format!("__WEBPACK_EXTERNAL_fileURLToPath(new URL({request}, import.meta.url))");
+    let mut content = String::new();
+    if self.filename {
+      content.push_str(&format!("const __filename = {filename};\n"));
+    }
+    if self.dirname {
+      content.push_str(&format!(
+        "const __dirname = __WEBPACK_EXTERNAL_dirname({filename});\n"
+      ));
+    }
+    code_generatable_context
+      .init_fragments
+      .push(Box::new(NormalInitFragment::new(
+        content,
+        InitFragmentStage::StageHarmonyImports,
+        None,
+      )));
+  }
+}
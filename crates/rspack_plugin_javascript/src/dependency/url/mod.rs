@@ -1,10 +1,32 @@
 use rspack_core::{
   get_dependency_used_by_exports_condition, module_id, Dependency, DependencyCategory,
   DependencyCondition, DependencyId, DependencyTemplate, DependencyType, ErrorSpan,
-  ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource, UsedByExports,
+  ModuleDependency, OutputOptions, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+  UsedByExports,
 };
 use swc_core::ecma::atoms::JsWord;
 
+/// What the url of the asset in `new URL("./a.png", import.meta.url)` is resolved against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum URLResolutionMode {
+  /// `__webpack_require__.b`, the asset url is already prefixed with the public path
+  #[default]
+  BaseUri,
+  /// The native `import.meta.url` of the chunk, so the url stays relative to where the chunk is
+  /// loaded from at runtime
+  ImportMetaUrl,
+}
+
+impl URLResolutionMode {
+  pub fn from_output_options(output: &OutputOptions) -> Self {
+    if output.module {
+      Self::ImportMetaUrl
+    } else {
+      Self::BaseUri
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct URLDependency {
   start: u32,
@@ -13,10 +35,17 @@ pub struct URLDependency {
   request: JsWord,
   span: Option<ErrorSpan>,
   used_by_exports: UsedByExports,
+  mode: URLResolutionMode,
 }
 
 impl URLDependency {
-  pub fn new(start: u32, end: u32, request: JsWord, span: Option<ErrorSpan>) -> Self {
+  pub fn new(
+    start: u32,
+    end: u32,
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    mode: URLResolutionMode,
+  ) -> Self {
     Self {
       start,
       end,
@@ -24,6 +53,7 @@ impl URLDependency {
       request,
       span,
       used_by_exports: UsedByExports::default(),
+      mode,
     }
   }
 }
@@ -76,8 +106,14 @@ impl DependencyTemplate for URLDependency {
       ..
     } = code_generatable_context;
 
-    runtime_requirements.insert(RuntimeGlobals::BASE_URI);
     runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    let base = match self.mode {
+      URLResolutionMode::BaseUri => {
+        runtime_requirements.insert(RuntimeGlobals::BASE_URI);
+        RuntimeGlobals::BASE_URI.to_string()
+      }
+      URLResolutionMode::ImportMetaUrl => "import.meta.url".to_string(),
+    };
 
     source.replace(
       self.start,
@@ -86,7 +122,7 @@ impl DependencyTemplate for URLDependency {
         "/* asset import */{}({}), {}",
         RuntimeGlobals::REQUIRE,
         module_id(compilation, &self.id, &self.request, false),
-        RuntimeGlobals::BASE_URI
+        base
       )
       .as_str(),
       None,
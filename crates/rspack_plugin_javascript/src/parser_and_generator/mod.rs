@@ -1,3 +1,6 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
 use rspack_core::rspack_sources::{
   BoxSource, RawSource, ReplaceSource, Source, SourceExt, SourceMap, SourceMapSource,
   WithoutOriginalOptions,
@@ -6,20 +9,27 @@ use rspack_core::tree_shaking::analyzer::OptimizeAnalyzer;
 use rspack_core::tree_shaking::js_module::JsModule;
 use rspack_core::tree_shaking::visitor::OptimizeAnalyzeResult;
 use rspack_core::{
-  render_box_init_fragments, GenerateContext, Module, ParseContext, ParseResult,
+  render_box_init_fragments, GenerateContext, Module, ModuleType, ParseContext, ParseResult,
   ParserAndGenerator, SourceType, TemplateContext,
 };
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
 
 use crate::utils::syntax_by_module_type;
-use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
+use crate::visitors::{
+  run_before_pass, scan_dependencies, swc_visitor::resolver, HarmonyImportScanCache,
+};
 #[derive(Debug)]
-pub struct JavaScriptParserAndGenerator;
+pub struct JavaScriptParserAndGenerator {
+  harmony_import_scan_cache: Arc<HarmonyImportScanCache>,
+}
 
 #[allow(unused)]
 impl JavaScriptParserAndGenerator {
-  pub(crate) fn new() -> Self {
-    Self {}
+  pub(crate) fn new(harmony_import_scan_cache: Arc<HarmonyImportScanCache>) -> Self {
+    Self {
+      harmony_import_scan_cache,
+    }
   }
 }
 
@@ -43,6 +53,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       build_info,
       build_meta,
       module_identifier,
+      module_parser_options,
       ..
     } = parse_context;
 
@@ -114,21 +125,48 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       ));
     });
 
-    let (dependencies, presentational_dependencies) = ast.visit(|program, context| {
-      scan_dependencies(
-        program,
-        context.unresolved_mark,
-        resource_data,
-        compiler_options,
-        module_type,
-        build_info,
-        build_meta,
-        module_identifier,
-      )
-    });
+    let mut code_hasher = RspackHash::from(&compiler_options.output);
+    output.code.hash(&mut code_hasher);
+    let code_hash = code_hasher.digest(&compiler_options.output.hash_digest);
+
+    // the options of the module rule take precedence over `module.parser.javascript`
+    let parser_options = module_parser_options
+      .and_then(|options| options.get_javascript(module_type))
+      .or_else(|| {
+        let parser = compiler_options.module.parser.as_ref()?;
+        parser
+          .get(module_type)
+          .and_then(|options| options.get_javascript(module_type))
+          .or_else(|| parser.get(&ModuleType::Js)?.get_javascript(module_type))
+      })
+      .cloned()
+      .unwrap_or_default();
+
+    let (dependencies, presentational_dependencies, warning_diagnostics) =
+      ast.visit(|program, context| {
+        scan_dependencies(
+          program,
+          context.unresolved_mark,
+          resource_data,
+          compiler_options,
+          module_type,
+          build_info,
+          build_meta,
+          module_identifier,
+          &parser_options,
+          Some((&self.harmony_import_scan_cache, code_hash)),
+        )
+      });
 
     let analyze_result = if compiler_options.builtins.tree_shaking.enable() {
-      JsModule::new(&ast, &dependencies, module_identifier, compiler_options).analyze()
+      JsModule::new(
+        &ast,
+        &dependencies,
+        module_identifier,
+        compiler_options,
+        &parser_options,
+      )
+      .analyze()
     } else {
       OptimizeAnalyzeResult::default()
     };
@@ -151,7 +189,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         presentational_dependencies,
         analyze_result,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(warning_diagnostics),
     )
   }
 
@@ -182,12 +220,14 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .expect("should have module graph module");
 
       mgm.dependencies.iter().for_each(|id| {
-        if let Some(dependency) = compilation
+        let dependency = compilation
           .module_graph
           .dependency_by_id(id)
-          .expect("should have dependency")
-          .as_dependency_template()
-        {
+          .expect("should have dependency");
+        if dependency.build_time_only() {
+          return;
+        }
+        if let Some(dependency) = dependency.as_dependency_template() {
           dependency.apply(&mut source, &mut context)
         }
       });
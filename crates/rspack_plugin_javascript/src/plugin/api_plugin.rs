@@ -1,8 +1,25 @@
 use rspack_core::{
-  InitFragmentStage, NormalInitFragment, Plugin, PluginContext, PluginRenderModuleContentOutput,
-  RenderModuleContentArgs,
+  get_runtime_key, InitFragmentStage, NormalInitFragment, Plugin, PluginContext,
+  PluginRenderModuleContentOutput, RenderModuleContentArgs, RuntimeSpec,
 };
 
+/// Shared with the fragment emitted by `node-commonjs` externals, so that a chunk
+/// only imports `createRequire` once.
+const CREATE_REQUIRE_FRAGMENT_KEY: &str = "external module node-commonjs";
+
+/// The fragment of a chunk shared by several runtimes is keyed by them, single runtime chunks
+/// keep the plain key so their output stays the same.
+fn create_require_fragment_key(runtime: &RuntimeSpec) -> String {
+  if runtime.len() > 1 {
+    format!(
+      "{CREATE_REQUIRE_FRAGMENT_KEY}|{}",
+      get_runtime_key(runtime.clone())
+    )
+  } else {
+    CREATE_REQUIRE_FRAGMENT_KEY.to_string()
+  }
+}
+
 #[derive(Debug)]
 pub struct APIPlugin;
 
@@ -12,9 +29,19 @@ impl Plugin for APIPlugin {
     _ctx: PluginContext,
     mut args: RenderModuleContentArgs<'a>,
   ) -> PluginRenderModuleContentOutput<'a> {
-    if let Some(build_info) = &args.module_graph_module.build_info && build_info.need_create_require {
-      args.chunk_init_fragments
-        .entry("external module node-commonjs".to_string())
+    let mgm = args.module_graph_module;
+    // only a module rendered into the chunk needs the import in the chunk's runtimes
+    if let Some(build_info) = &mgm.build_info
+      && build_info.need_create_require
+      && args
+        .compilation
+        .chunk_graph
+        .is_module_in_chunk(&mgm.module_identifier, *args.chunk)
+    {
+      let chunk = args.compilation.chunk_by_ukey.expect_get(args.chunk);
+      args
+        .chunk_init_fragments
+        .entry(create_require_fragment_key(&chunk.runtime))
         .or_insert(NormalInitFragment::new(
           "import { createRequire as __WEBPACK_EXTERNAL_createRequire } from 'module';\n"
             .to_string(),
@@ -22,6 +49,51 @@ impl Plugin for APIPlugin {
           None,
         ));
     }
+
+    // `__dirname` and `__filename` are declared per module by `NodeModuleShimDependency`,
+    // only the imports they need are hoisted to the chunk
+    if let Some(build_info) = &mgm.build_info
+      && (build_info.need_dirname || build_info.need_filename)
+    {
+      args
+        .chunk_init_fragments
+        .entry("external module node-module url".to_string())
+        .or_insert(NormalInitFragment::new(
+          "import { fileURLToPath as __WEBPACK_EXTERNAL_fileURLToPath } from 'url';\n".to_string(),
+          InitFragmentStage::StageHarmonyImports,
+          None,
+        ));
+      if build_info.need_dirname {
+        args
+          .chunk_init_fragments
+          .entry("external module node-module path".to_string())
+          .or_insert(NormalInitFragment::new(
+            "import { dirname as __WEBPACK_EXTERNAL_dirname } from 'path';\n".to_string(),
+            InitFragmentStage::StageHarmonyImports,
+            None,
+          ));
+      }
+    }
     Ok(args)
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn create_require_fragment_is_keyed_by_shared_runtimes() {
+    let single = RuntimeSpec::from_iter(["main".into()]);
+    assert_eq!(
+      create_require_fragment_key(&single),
+      CREATE_REQUIRE_FRAGMENT_KEY
+    );
+
+    let shared = RuntimeSpec::from_iter(["main".into(), "worker".into()]);
+    assert_eq!(
+      create_require_fragment_key(&shared),
+      format!("{CREATE_REQUIRE_FRAGMENT_KEY}|main\nworker")
+    );
+  }
+}
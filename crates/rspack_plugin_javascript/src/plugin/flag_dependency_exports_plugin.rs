@@ -234,6 +234,13 @@ impl<'a> FlagDependencyExportsProxy<'a> {
         self.changed = true;
       }
 
+      // A hidden export is not part of the namespace, but its name is still taken, so it must not
+      // be mangled or reused as the mangled name of a visible export
+      if hidden && export_info.can_mangle_provide != Some(false) {
+        export_info.can_mangle_provide = Some(false);
+        self.changed = true;
+      }
+
       if let Some(exports) = exports {
         let nested_exports_info = export_info.create_nested_exports_info(self.mg);
         self.merge_exports(
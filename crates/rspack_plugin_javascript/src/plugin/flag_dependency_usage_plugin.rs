@@ -1,12 +1,14 @@
 use std::collections::hash_map::Entry;
 use std::collections::VecDeque;
 
+use rspack_core::rspack_sources::{RawSource, SourceExt};
 use rspack_core::{
-  is_exports_object_referenced, is_no_exports_referenced, BuildMetaExportsType, Compilation,
-  ConnectionState, DependencyId, ExportsInfoId, ExtendedReferencedExport, ModuleIdentifier, Plugin,
-  ReferencedExport, RuntimeSpec, UsageState,
+  get_exports_usage_report, is_exports_object_referenced, is_no_exports_referenced, AssetInfo,
+  BuildMetaExportsType, Compilation, CompilationAsset, ConnectionState, DependencyId,
+  ExportsInfoId, ExtendedReferencedExport, ModuleIdentifier, Plugin, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs, ReferencedExport, RuntimeSpec, UsageState,
 };
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
 use rspack_identifier::IdentifierMap;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -223,7 +225,21 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
       .module_graph
       .module_graph_module_by_dependency_id(&dep)
     {
-      self.process_referenced_module(module.module_identifier, vec![], None, true, queue);
+      // the exports exposed by `output.library` are used
+      let referenced_exports = self
+        .compilation
+        .module_graph
+        .dependency_by_id(&dep)
+        .and_then(|dep| dep.as_module_dependency())
+        .map(|dep| dep.get_referenced_exports(&self.compilation.module_graph, None))
+        .unwrap_or_default();
+      self.process_referenced_module(
+        module.module_identifier,
+        referenced_exports,
+        None,
+        true,
+        queue,
+      );
     }
   }
 
@@ -351,7 +367,18 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
 }
 
 #[derive(Debug, Default)]
-pub struct FlagDependencyUsagePlugin;
+pub struct FlagDependencyUsagePlugin {
+  /// Emit the used and unused exports of every module as a json asset with this filename
+  pub exports_usage_report: Option<String>,
+}
+
+impl FlagDependencyUsagePlugin {
+  pub fn new(exports_usage_report: Option<String>) -> Self {
+    Self {
+      exports_usage_report,
+    }
+  }
+}
 
 #[async_trait::async_trait]
 impl Plugin for FlagDependencyUsagePlugin {
@@ -361,4 +388,22 @@ impl Plugin for FlagDependencyUsagePlugin {
     proxy.apply();
     Ok(None)
   }
+
+  async fn process_assets_stage_report(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let Some(filename) = &self.exports_usage_report else {
+      return Ok(());
+    };
+    let compilation = args.compilation;
+    let report = get_exports_usage_report(&compilation.module_graph);
+    let json = serde_json::to_string_pretty(&report).map_err(|e| internal_error!(e.to_string()))?;
+    compilation.emit_asset(
+      filename.clone(),
+      CompilationAsset::new(Some(RawSource::from(json).boxed()), AssetInfo::default()),
+    );
+    Ok(())
+  }
 }
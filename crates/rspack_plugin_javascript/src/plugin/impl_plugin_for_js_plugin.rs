@@ -24,42 +24,47 @@ impl Plugin for JsPlugin {
     ctx: PluginContext<&mut rspack_core::ApplyContext>,
     options: &mut CompilerOptions,
   ) -> Result<()> {
-    let create_parser_and_generator =
-      move || Box::new(JavaScriptParserAndGenerator::new()) as Box<dyn ParserAndGenerator>;
+    let harmony_import_scan_cache = self.harmony_import_scan_cache.clone();
+    let create_parser_and_generator = move || {
+      Box::new(JavaScriptParserAndGenerator::new(
+        harmony_import_scan_cache.clone(),
+      )) as Box<dyn ParserAndGenerator>
+    };
 
     if options.should_transform_by_default() {
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Ts,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Tsx,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Jsx,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::JsxEsm,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::JsxDynamic,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
     }
 
-    ctx
-      .context
-      .register_parser_and_generator_builder(ModuleType::Js, Box::new(create_parser_and_generator));
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::Js,
+      Box::new(create_parser_and_generator.clone()),
+    );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsEsm,
-      Box::new(create_parser_and_generator),
+      Box::new(create_parser_and_generator.clone()),
     );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsDynamic,
-      Box::new(create_parser_and_generator),
+      Box::new(create_parser_and_generator.clone()),
     );
 
     Ok(())
@@ -44,8 +44,10 @@ impl Plugin for InferAsyncModulesPlugin {
           .incoming_connections_unordered(module_graph)?
           .filter(|con| {
             if let Some(dep) = module_graph.dependency_by_id(&con.dependency_id) {
-              *dep.dependency_type() == DependencyType::EsmImport
-                || *dep.dependency_type() == DependencyType::EsmExport
+              matches!(
+                dep.dependency_type(),
+                DependencyType::EsmImport | DependencyType::JsonImport | DependencyType::EsmExport
+              )
             } else {
               false
             }
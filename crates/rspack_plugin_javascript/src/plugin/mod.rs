@@ -6,6 +6,7 @@ pub mod infer_async_modules_plugin;
 mod side_effects_flag_plugin;
 
 use std::hash::Hash;
+use std::sync::Arc;
 
 pub use flag_dependency_exports_plugin::*;
 pub use flag_dependency_usage_plugin::*;
@@ -22,13 +23,19 @@ use crate::runtime::{
   render_chunk_init_fragments, render_chunk_modules, render_iife, render_runtime_modules,
   stringify_array,
 };
+use crate::visitors::HarmonyImportScanCache;
 
 #[derive(Debug)]
-pub struct JsPlugin;
+pub struct JsPlugin {
+  /// Shared by the parsers of all modules and kept across rebuilds
+  harmony_import_scan_cache: Arc<HarmonyImportScanCache>,
+}
 
 impl JsPlugin {
   pub fn new() -> Self {
-    Self {}
+    Self {
+      harmony_import_scan_cache: Default::default(),
+    }
   }
 
   pub fn render_require(&self, chunk_ukey: &ChunkUkey, compilation: &Compilation) -> BoxSource {
@@ -2,7 +2,7 @@ use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   render_init_fragments, ChunkInitFragments, ChunkUkey, Compilation, ModuleGraphModule,
-  RenderModuleContentArgs, RuntimeGlobals, SourceType,
+  RenderModuleContentArgs, RenderRuntimeModuleContentArgs, RuntimeGlobals, SourceType,
 };
 use rspack_error::{internal_error, Result};
 use rustc_hash::FxHashSet as HashSet;
@@ -38,6 +38,7 @@ pub fn render_chunk_modules(
         let render_module_result = plugin_driver
           .render_module_content(RenderModuleContentArgs {
             compilation,
+            chunk: chunk_ukey,
             module_graph_module: mgm,
             module_source: origin_source.clone(),
             chunk_init_fragments: ChunkInitFragments::default(),
@@ -172,20 +173,30 @@ pub fn render_runtime_modules(
     })
     .collect::<Vec<_>>();
   runtime_modules.sort_unstable_by_key(|(_, m)| m.stage());
-  runtime_modules.iter().for_each(|((_, source), module)| {
+  for ((_, source), module) in runtime_modules {
     sources.add(RawSource::from(format!("// {}\n", module.identifier())));
     if !module.should_isolate() {
       sources.add(RawSource::from("(function() {\n"));
     }
-    if module.cacheable() {
-      sources.add(source.clone());
+    let runtime_module_source = if module.cacheable() {
+      source.clone()
     } else {
-      sources.add(module.generate(compilation));
-    }
+      module.generate(compilation)
+    };
+    let render_runtime_module_result =
+      compilation
+        .plugin_driver
+        .render_runtime_module_content(RenderRuntimeModuleContentArgs {
+          runtime_module_source,
+          runtime_module_name: module.name(),
+          compilation,
+          chunk: chunk_ukey,
+        })?;
+    sources.add(render_runtime_module_result.runtime_module_source);
     if !module.should_isolate() {
       sources.add(RawSource::from("\n})();\n"));
     }
-  });
+  }
   Ok(sources.boxed())
 }
 
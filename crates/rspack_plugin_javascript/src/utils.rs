@@ -1,8 +1,8 @@
 use std::path::Path;
 
-use rspack_core::{ErrorSpan, ModuleType};
+use rspack_core::ModuleType;
 use rspack_error::{DiagnosticKind, Error};
-use swc_core::common::{SourceFile, Span, Spanned, SyntaxContext, DUMMY_SP};
+use swc_core::common::{BytePos, SourceFile, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit, Str};
 use swc_core::ecma::atoms::{js_word, JsWord};
 use swc_core::ecma::parser::Syntax;
@@ -35,6 +35,7 @@ fn syntax_by_ext(
       decorators: should_transform_by_default && enable_decorators,
       fn_bind: true,
       allow_super_outside_method: true,
+      import_attributes: true,
       ..Default::default()
     }),
   }
@@ -56,6 +57,8 @@ pub fn syntax_by_module_type(
     decorators: should_transform_by_default && enable_decorators,
     fn_bind: true,
     allow_super_outside_method: true,
+    // Both `assert { type: "json" }` and `with { type: "json" }`
+    import_attributes: true,
     ..Default::default()
   });
 
@@ -159,11 +162,16 @@ pub fn ecma_parse_error_to_rspack_error(
     _ => unreachable!(),
   };
   let message = error.kind().msg().to_string();
-  let span: ErrorSpan = error.span().into();
+  // the span is absolute in the source map, the code frame needs offsets into `fm`. An error at
+  // the end of the file may point right after the source.
+  let span = error.span();
+  let offset = |pos: BytePos| (pos.0.saturating_sub(fm.start_pos.0) as usize).min(fm.src.len());
+  let start = offset(span.lo);
+  let end = offset(span.hi).max(start);
   let traceable_error = rspack_error::TraceableError::from_source_file(
     fm,
-    span.start as usize,
-    span.end as usize,
+    start,
+    end,
     format!("{file_type} parsing error"),
     message,
   )
@@ -20,6 +20,8 @@ pub const WEBPACK_RESOURCE_QUERY: &str = "__resourceQuery";
 pub const WEBPACK_CHUNK_LOAD: &str = "__webpack_chunk_load__";
 pub const WEBPACK_BASE_URI: &str = "__webpack_base_uri__";
 pub const NON_WEBPACK_REQUIRE: &str = "__non_webpack_require__";
+pub const DIR_NAME: &str = "__dirname";
+pub const FILE_NAME: &str = "__filename";
 
 pub struct ApiScanner<'a> {
   pub unresolved_ctxt: &'a SyntaxContext,
@@ -86,21 +88,21 @@ impl Visit for ApiScanner<'_> {
       WEBPACK_HASH => {
         self
           .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+          .push(Box::new(ConstDependency::with_runtime(
             ident.span.real_lo(),
             ident.span.real_hi(),
             format!("{}()", RuntimeGlobals::GET_FULL_HASH).into(),
-            Some(RuntimeGlobals::GET_FULL_HASH),
+            RuntimeGlobals::GET_FULL_HASH,
           )));
       }
       WEBPACK_PUBLIC_PATH => {
         self
           .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+          .push(Box::new(ConstDependency::with_runtime(
             ident.span.real_lo(),
             ident.span.real_hi(),
             RuntimeGlobals::PUBLIC_PATH.name().into(),
-            Some(RuntimeGlobals::PUBLIC_PATH),
+            RuntimeGlobals::PUBLIC_PATH,
           )));
       }
       WEBPACK_MODULES => {
@@ -109,11 +111,11 @@ impl Visit for ApiScanner<'_> {
         }
         self
           .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+          .push(Box::new(ConstDependency::with_runtime(
             ident.span.real_lo(),
             ident.span.real_hi(),
             RuntimeGlobals::MODULE_FACTORIES.name().into(),
-            Some(RuntimeGlobals::MODULE_FACTORIES),
+            RuntimeGlobals::MODULE_FACTORIES,
           )));
       }
       WEBPACK_RESOURCE_QUERY => {
@@ -133,11 +135,11 @@ impl Visit for ApiScanner<'_> {
       WEBPACK_CHUNK_LOAD => {
         self
           .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+          .push(Box::new(ConstDependency::with_runtime(
             ident.span.real_lo(),
             ident.span.real_hi(),
             RuntimeGlobals::ENSURE_CHUNK.name().into(),
-            Some(RuntimeGlobals::ENSURE_CHUNK),
+            RuntimeGlobals::ENSURE_CHUNK,
           )));
       }
       WEBPACK_MODULE => {
@@ -152,11 +154,11 @@ impl Visit for ApiScanner<'_> {
       WEBPACK_BASE_URI => {
         self
           .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+          .push(Box::new(ConstDependency::with_runtime(
             ident.span.real_lo(),
             ident.span.real_hi(),
             RuntimeGlobals::BASE_URI.name().into(),
-            Some(RuntimeGlobals::BASE_URI),
+            RuntimeGlobals::BASE_URI,
           )));
       }
       NON_WEBPACK_REQUIRE => {
@@ -174,6 +176,12 @@ impl Visit for ApiScanner<'_> {
             None,
           )));
       }
+      DIR_NAME if self.module => {
+        self.build_info.need_dirname = true;
+      }
+      FILE_NAME if self.module => {
+        self.build_info.need_filename = true;
+      }
       _ => {}
     }
   }
@@ -182,11 +190,11 @@ impl Visit for ApiScanner<'_> {
     if expr_matcher::is_require_cache(expr) {
       self
         .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
+        .push(Box::new(ConstDependency::with_runtime(
           expr.span().real_lo(),
           expr.span().real_hi(),
           RuntimeGlobals::MODULE_CACHE.name().into(),
-          Some(RuntimeGlobals::MODULE_CACHE),
+          RuntimeGlobals::MODULE_CACHE,
         )));
     } else if expr_matcher::is_webpack_module_id(expr) {
       self
@@ -24,6 +24,7 @@ pub struct CommonJsExportDependencyScanner<'a> {
   is_harmony: bool,
   parser_exports_state: &'a mut Option<bool>,
   enter_call: u32,
+  has_module_decorator: bool,
 }
 
 impl<'a> CommonJsExportDependencyScanner<'a> {
@@ -42,6 +43,7 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
       is_harmony: false,
       parser_exports_state,
       enter_call: 0,
+      has_module_decorator: false,
     }
   }
 }
@@ -64,14 +66,19 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
       } else {
         RuntimeGlobals::NODE_MODULE_DECORATOR
       };
-      self
-        .presentational_dependencies
-        .push(Box::new(ModuleDecoratorDependency::new(decorator)));
+      self.add_module_decorator(decorator);
       self.bailout();
     }
   }
 
   fn visit_expr(&mut self, expr: &Expr) {
+    // `module.id` and `module.loaded` read the node module object in commonjs
+    if !self.is_harmony
+      && (expr_matcher::is_module_id(expr) || expr_matcher::is_module_loaded(expr))
+    {
+      self.add_module_decorator(RuntimeGlobals::NODE_MODULE_DECORATOR);
+      return;
+    }
     if expr_matcher::is_module_id(expr)
       || expr_matcher::is_module_loaded(expr)
       || expr_matcher::is_module_hot(expr)
@@ -180,6 +187,16 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
   }
 
   // can't scan `__esModule` value
+  fn add_module_decorator(&mut self, decorator: RuntimeGlobals) {
+    if self.has_module_decorator {
+      return;
+    }
+    self.has_module_decorator = true;
+    self
+      .presentational_dependencies
+      .push(Box::new(ModuleDecoratorDependency::new(decorator)));
+  }
+
   fn bailout(&mut self) {
     if matches!(self.parser_exports_state, Some(true)) {
       self.build_meta.exports_type = BuildMetaExportsType::Unset;
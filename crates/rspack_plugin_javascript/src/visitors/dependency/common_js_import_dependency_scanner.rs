@@ -132,7 +132,8 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
                       exclude: None,
                       category: DependencyCategory::CommonJS,
                       request: context,
-                      namespace_object: ContextNameSpaceObject::Unset
+                      namespace_object: ContextNameSpaceObject::Unset,
+                      chunk_name_template: None,
                     },
                     Some(call_expr.span.into()),
                   )));
@@ -20,6 +20,12 @@ pub fn scanner_context_module(expr: &Expr) -> Option<(String, String)> {
   }
 }
 
+/// Whether the context of `scanner_context_module` is matched without a static prefix, e.g.
+/// `${a}.js`, so it spans the whole directory of the module
+pub fn is_fully_dynamic_context(reg: &str) -> bool {
+  reg.starts_with("^.*")
+}
+
 static META_REG: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"[-\[\]\\/{}()*+?.^$|]").expect("Failed to initialize `MATCH_RESOURCE_REGEX`")
 });
@@ -48,14 +54,14 @@ fn scan_context_module_tpl(tpl: &Tpl) -> (String, String) {
     String::new()
   };
   let (context, prefix) = split_context_from_prefix(prefix_raw);
+  // the quasis between the first and the last expression, e.g. `-` of `./${a}-${b}.js`
   let inner_reg = tpl
     .quasis
     .iter()
-    .skip(tpl.quasis.len())
     .skip(1)
-    .map(|s| s.raw.to_string() + ".*")
-    .collect::<Vec<String>>()
-    .join("");
+    .take(tpl.quasis.len().saturating_sub(2))
+    .map(|s| quote_meta(s.raw.to_string()) + ".*")
+    .collect::<String>();
   let reg = format!(
     "^{prefix}.*{inner_reg}{postfix_raw}$",
     prefix = quote_meta(prefix),
@@ -194,3 +200,44 @@ fn find_concat_expr_postfix_string(expr: &CallExpr) -> Option<String> {
     None
   })
 }
+
+#[cfg(test)]
+mod test {
+  use swc_core::ecma::{
+    ast::{ModuleItem, Stmt},
+    parser::{EsConfig, Syntax},
+  };
+
+  use super::*;
+  use crate::visitors::parse_and_visit;
+
+  fn scan(code: &str) -> Option<(String, String)> {
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      let module = program
+        .get_inner_program()
+        .as_module()
+        .expect("should be a module");
+      match module.body.first() {
+        Some(ModuleItem::Stmt(Stmt::Expr(stmt))) => scanner_context_module(&stmt.expr),
+        _ => None,
+      }
+    })
+  }
+
+  #[test]
+  fn template_literal_context() {
+    assert_eq!(
+      scan("`./locales/${lang}.json`"),
+      Some(("./locales".to_string(), r"^\.\/.*\.json$".to_string()))
+    );
+    // the quasis between the expressions are matched in order
+    assert_eq!(
+      scan("`./locales/${lang}-${region}.json`"),
+      Some(("./locales".to_string(), r"^\.\/.*\-.*\.json$".to_string()))
+    );
+    assert_eq!(
+      scan("`./${a}/${b}/index.js`"),
+      Some((".".to_string(), r"^\.\/.*\/.*\/index\.js$".to_string()))
+    );
+  }
+}
@@ -1,28 +1,36 @@
+use std::path::PathBuf;
+
+use dashmap::DashMap;
 use indexmap::IndexMap;
+use once_cell::sync::Lazy;
 use rspack_core::{
   tree_shaking::symbol::DEFAULT_JS_WORD, BoxDependency, BoxDependencyTemplate, BuildInfo,
-  ConstDependency, DependencyType, SpanExt,
+  ConstDependency, DependencyId, DependencyType, ImportAttributes, ModuleIdentifier, SpanExt,
 };
+use rspack_error::{Diagnostic, DiagnosticKind};
+use rspack_hash::RspackHashDigest;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde_json::json;
 use swc_core::{
-  common::Span,
+  common::{comments::Comments, Span},
   ecma::{
     ast::{
       AssignExpr, AssignOp, Callee, ExportAll, ExportSpecifier, Expr, Id, Ident, ImportDecl,
-      ImportSpecifier, Lit, MemberExpr, MemberProp, ModuleExportName, NamedExport, Pat, PatOrExpr,
-      Program, Prop,
+      ImportSpecifier, Lit, MemberExpr, MemberProp, ModuleExportName, NamedExport, ObjectLit,
+      ObjectPat, Pat, PatOrExpr, Program, Prop, PropName, PropOrSpread, TaggedTpl, VarDeclarator,
     },
     atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::collect_destructuring_assignment_properties;
+use super::{collect_destructuring_assignment_properties, is_webpack_ignored};
 use crate::dependency::{
-  HarmonyExportImportedSpecifierDependency, HarmonyImportDependency,
+  HarmonyExportImportedSpecifierDependency, HarmonyImportDependency, HarmonyImportReferenceKey,
   HarmonyImportSpecifierDependency, Specifier,
 };
 
+#[derive(Debug, Clone)]
 pub struct ImporterReferenceInfo {
   pub request: JsWord,
   pub specifier: Specifier,
@@ -41,10 +49,68 @@ impl ImporterReferenceInfo {
 
 pub type ImportMap = HashMap<Id, ImporterReferenceInfo>;
 
+/// Set `DEBUG_IMPORT_MAP` to a directory to dump the import map of every esm module into it,
+/// one json file per module
+static DEBUG_IMPORT_MAP_DIR: Lazy<Option<PathBuf>> =
+  Lazy::new(|| std::env::var("DEBUG_IMPORT_MAP").ok().map(PathBuf::from));
+
+/// Each local binding of the import map with the request it is imported from, the kind of its
+/// specifier and the imported name, sorted by the binding.
+pub fn import_map_to_debug_json(import_map: &ImportMap) -> serde_json::Value {
+  let mut bindings = import_map.iter().collect::<Vec<_>>();
+  bindings
+    .sort_unstable_by(|(a, _), (b, _)| a.0.cmp(&b.0).then_with(|| a.1.as_u32().cmp(&b.1.as_u32())));
+  bindings
+    .into_iter()
+    .map(|((local, ctxt), info)| {
+      json!({
+        "local": format!("{local}{ctxt:?}"),
+        "request": info.request.as_ref(),
+        "specifier": match info.specifier {
+          Specifier::Namespace(_) => "namespace",
+          Specifier::Default(_) => "default",
+          Specifier::Named(..) => "named",
+        },
+        "imported": info.names.as_deref(),
+      })
+    })
+    .collect()
+}
+
+/// Writes the import map of a module when `DEBUG_IMPORT_MAP` is set, to find out why an
+/// identifier isn't linked to its import.
+pub fn debug_import_map(module_identifier: &ModuleIdentifier, import_map: &ImportMap) {
+  let Some(dir) = &*DEBUG_IMPORT_MAP_DIR else {
+    return;
+  };
+  let filename = module_identifier
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || c == '.' {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect::<String>();
+  // keep the end of long identifiers, it's the resource path
+  let filename = &filename[filename.len().saturating_sub(200)..];
+  let content = json!({
+    "module": module_identifier.as_str(),
+    "importMap": import_map_to_debug_json(import_map),
+  });
+  let result = std::fs::create_dir_all(dir)
+    .and_then(|_| std::fs::write(dir.join(format!("{filename}.json")), format!("{content:#}")));
+  if let Err(e) = result {
+    tracing::warn!("failed to write the import map of {module_identifier}: {e}");
+  }
+}
+
 pub struct ImporterInfo {
   pub span: Span,
   pub specifiers: Vec<Specifier>,
   pub exports_all: bool,
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl ImporterInfo {
@@ -53,6 +119,53 @@ impl ImporterInfo {
       span,
       specifiers,
       exports_all,
+      attributes: None,
+    }
+  }
+
+  fn with_attributes(mut self, attributes: Option<ImportAttributes>) -> Self {
+    self.attributes = attributes;
+    self
+  }
+}
+
+/// Collect `assert { type: "json" }` or `with { type: "json" }`, `None` if there is none.
+fn get_import_attributes(with: Option<&ObjectLit>) -> Option<ImportAttributes> {
+  let attributes = with?
+    .props
+    .iter()
+    .filter_map(|prop| match prop {
+      PropOrSpread::Prop(prop) => match &**prop {
+        Prop::KeyValue(kv) => {
+          let key = match &kv.key {
+            PropName::Ident(ident) => ident.sym.clone(),
+            PropName::Str(str) => str.value.clone(),
+            _ => return None,
+          };
+          match &*kv.value {
+            Expr::Lit(Lit::Str(str)) => Some((key, str.value.clone())),
+            _ => None,
+          }
+        }
+        _ => None,
+      },
+      PropOrSpread::Spread(_) => None,
+    })
+    .collect::<ImportAttributes>();
+  (!attributes.is_empty()).then_some(attributes)
+}
+
+fn merge_importer_info(imports: &mut Imports, key: (JsWord, DependencyType), info: ImporterInfo) {
+  match imports.get_mut(&key) {
+    Some(importer_info) => {
+      importer_info.specifiers.extend(info.specifiers);
+      importer_info.exports_all |= info.exports_all;
+      if importer_info.attributes.is_none() {
+        importer_info.attributes = info.attributes;
+      }
+    }
+    None => {
+      imports.insert(key, info);
     }
   }
 }
@@ -65,6 +178,9 @@ pub struct HarmonyImportDependencyScanner<'a> {
   pub import_map: &'a mut ImportMap,
   pub imports: Imports,
   pub build_info: &'a mut BuildInfo,
+  pub comments: Option<&'a dyn Comments>,
+  pub warning_diagnostics: &'a mut Vec<Diagnostic>,
+  pub module_identifier: ModuleIdentifier,
 }
 
 impl<'a> HarmonyImportDependencyScanner<'a> {
@@ -73,6 +189,9 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
     presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
     import_map: &'a mut ImportMap,
     build_info: &'a mut BuildInfo,
+    comments: Option<&'a dyn Comments>,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
+    module_identifier: ModuleIdentifier,
   ) -> Self {
     Self {
       dependencies,
@@ -80,25 +199,163 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
       import_map,
       imports: Default::default(),
       build_info,
+      comments,
+      warning_diagnostics,
+      module_identifier,
     }
   }
+
+  /// Skip a specifier the scanner doesn't understand instead of failing the whole build
+  fn warn_unsupported_specifier(&mut self, span: Span, message: &str) {
+    self.warning_diagnostics.push(
+      Diagnostic::warn(
+        "Unsupported export specifier".to_string(),
+        message.to_string(),
+        span.real_lo() as usize,
+        span.real_hi() as usize,
+      )
+      .with_kind(DiagnosticKind::JavaScript),
+    );
+  }
+}
+
+/// Everything [HarmonyImportDependencyScanner] adds for one version of a module
+#[derive(Debug, Clone)]
+struct HarmonyImportScanResult {
+  dependencies: Vec<BoxDependency>,
+  presentational_dependencies: Vec<BoxDependencyTemplate>,
+  import_map: ImportMap,
+  harmony_named_exports: Vec<JsWord>,
+  all_star_exports: Vec<DependencyId>,
+  warning_diagnostics: Vec<Diagnostic>,
+}
+
+impl HarmonyImportScanResult {
+  /// A copy of the result whose dependencies have ids of their own, as the module graph can't
+  /// hold the dependencies of the previous build and the reused ones at the same time. `None` if
+  /// it holds a dependency it can't give a new id.
+  fn with_fresh_ids(&self) -> Option<Self> {
+    let mut result = self.clone();
+    let mut fresh_ids = HashMap::default();
+    for dependency in result.dependencies.iter_mut() {
+      let id = DependencyId::new();
+      fresh_ids.insert(*dependency.id(), id);
+      if let Some(dependency) = dependency.downcast_mut::<HarmonyImportDependency>() {
+        dependency.id = id;
+      } else if let Some(dependency) =
+        dependency.downcast_mut::<HarmonyExportImportedSpecifierDependency>()
+      {
+        dependency.id = id;
+      } else if let Some(dependency) = dependency.downcast_mut::<HarmonyImportSpecifierDependency>()
+      {
+        dependency.set_id(id);
+      } else {
+        return None;
+      }
+    }
+    for id in result.all_star_exports.iter_mut() {
+      *id = *fresh_ids.get(id)?;
+    }
+    Some(result)
+  }
+}
+
+/// Harmony import scan results of the previous build keyed by module, a module whose code
+/// hash is unchanged on a watch rebuild reuses them instead of visiting its AST again.
+///
+/// The code is parsed with a fresh source map and fresh marks, so the same code always
+/// ends up with the same spans and syntax contexts.
+#[derive(Debug, Default)]
+pub struct HarmonyImportScanCache {
+  results: DashMap<ModuleIdentifier, (RspackHashDigest, HarmonyImportScanResult)>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn scan_harmony_imports(
+  program: &Program,
+  dependencies: &mut Vec<BoxDependency>,
+  presentational_dependencies: &mut Vec<BoxDependencyTemplate>,
+  import_map: &mut ImportMap,
+  build_info: &mut BuildInfo,
+  comments: Option<&dyn Comments>,
+  warning_diagnostics: &mut Vec<Diagnostic>,
+  module_identifier: ModuleIdentifier,
+  cache: Option<(&HarmonyImportScanCache, RspackHashDigest)>,
+) {
+  if let Some((cache, code_hash)) = &cache
+    && let Some(result) = cache
+      .results
+      .get(&module_identifier)
+      .filter(|cached| &cached.0 == code_hash)
+      .and_then(|cached| cached.1.with_fresh_ids())
+  {
+    dependencies.extend(result.dependencies);
+    presentational_dependencies.extend(result.presentational_dependencies);
+    import_map.extend(result.import_map);
+    build_info
+      .harmony_named_exports
+      .extend(result.harmony_named_exports);
+    build_info.all_star_exports.extend(result.all_star_exports);
+    warning_diagnostics.extend(result.warning_diagnostics);
+    return;
+  }
+
+  let dependencies_len = dependencies.len();
+  let presentational_dependencies_len = presentational_dependencies.len();
+  let harmony_named_exports_len = build_info.harmony_named_exports.len();
+  let all_star_exports_len = build_info.all_star_exports.len();
+  let warning_diagnostics_len = warning_diagnostics.len();
+  program.visit_with(&mut HarmonyImportDependencyScanner::new(
+    dependencies,
+    presentational_dependencies,
+    import_map,
+    build_info,
+    comments,
+    warning_diagnostics,
+    module_identifier,
+  ));
+
+  if let Some((cache, code_hash)) = cache {
+    let result = HarmonyImportScanResult {
+      dependencies: dependencies[dependencies_len..].to_vec(),
+      presentational_dependencies: presentational_dependencies[presentational_dependencies_len..]
+        .to_vec(),
+      import_map: import_map.clone(),
+      harmony_named_exports: build_info
+        .harmony_named_exports
+        .iter()
+        .skip(harmony_named_exports_len)
+        .cloned()
+        .collect(),
+      all_star_exports: build_info.all_star_exports[all_star_exports_len..].to_vec(),
+      warning_diagnostics: warning_diagnostics[warning_diagnostics_len..].to_vec(),
+    };
+    // replaces the result of the previous version of the module
+    cache.results.insert(module_identifier, (code_hash, result));
+  }
 }
 
 impl Visit for HarmonyImportDependencyScanner<'_> {
   noop_visit_type!();
 
+  #[tracing::instrument(
+    name = "harmony_import_scanner:visit_program",
+    level = "trace",
+    skip_all,
+    fields(module = %self.module_identifier, dependencies = tracing::field::Empty)
+  )]
   fn visit_program(&mut self, program: &Program) {
+    let dependencies_len = self.dependencies.len();
     // collect import map info
     program.visit_children_with(self);
     for ((request, dependency_type), importer_info) in std::mem::take(&mut self.imports).into_iter()
     {
+      let _span = tracing::trace_span!("harmony_import", request = %request).entered();
       if matches!(dependency_type, DependencyType::EsmExport)
         && !importer_info.specifiers.is_empty()
       {
-        importer_info
-          .specifiers
-          .iter()
-          .for_each(|specifier| match specifier {
+        for specifier in importer_info.specifiers.iter() {
+          match specifier {
             Specifier::Namespace(n) => {
               self
                 .dependencies
@@ -106,11 +363,17 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
                   request.clone(),
                   vec![(n.clone(), None)],
                   Some(n.clone()),
+                  Some(importer_info.span.into()),
                 )));
               self.build_info.harmony_named_exports.insert(n.clone());
             }
-            Specifier::Default(_) => {
-              unreachable!()
+            Specifier::Default(n) => {
+              self.warn_unsupported_specifier(
+                importer_info.span,
+                &format!(
+                  "Re-exporting `{n}` as a default specifier is not supported, it is skipped"
+                ),
+              );
             }
             Specifier::Named(orig, exported) => {
               let name = exported.clone().unwrap_or(orig.clone());
@@ -120,10 +383,12 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
                   request.clone(),
                   vec![(name.clone(), Some(orig.clone()))],
                   Some(name.clone()),
+                  Some(importer_info.span.into()),
                 )));
               self.build_info.harmony_named_exports.insert(name);
             }
-          });
+          }
+        }
       }
       let dependency = HarmonyImportDependency::new(
         request.clone(),
@@ -131,21 +396,38 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
         importer_info.specifiers,
         dependency_type,
         importer_info.exports_all,
+        importer_info.attributes,
       );
       if importer_info.exports_all {
         self.build_info.all_star_exports.push(dependency.id);
       }
       self.dependencies.push(Box::new(dependency));
     }
+    tracing::Span::current().record("dependencies", self.dependencies.len() - dependencies_len);
 
-    // collect import reference info
+    // collect import reference info, nothing can be referenced by a module without imports
+    if self.import_map.is_empty() {
+      return;
+    }
+    let span = tracing::trace_span!(
+      "harmony_import_ref_scanner",
+      module = %self.module_identifier,
+      dependencies = tracing::field::Empty
+    )
+    .entered();
+    let dependencies_len = self.dependencies.len();
     program.visit_children_with(&mut HarmonyImportRefDependencyScanner::new(
       self.import_map,
       self.dependencies,
     ));
+    span.record("dependencies", self.dependencies.len() - dependencies_len);
   }
 
   fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+    // `/* webpackIgnore: true */ import "x"` is kept as is
+    if is_webpack_ignored(self.comments, import_decl.span.lo) {
+      return;
+    }
     let mut specifiers = vec![];
     import_decl.specifiers.iter().for_each(|s| match s {
       ImportSpecifier::Named(n) => {
@@ -192,14 +474,21 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
       }
     });
 
-    let key = (import_decl.src.value.clone(), DependencyType::EsmImport);
-    if let Some(importer_info) = self.imports.get_mut(&key) {
-      importer_info.specifiers.extend(specifiers);
+    let attributes = get_import_attributes(import_decl.with.as_deref());
+    let is_json = attributes
+      .as_ref()
+      .and_then(|attributes| attributes.get(&JsWord::from("type")))
+      .is_some_and(|ty| ty == "json");
+    let dependency_type = if is_json {
+      DependencyType::JsonImport
     } else {
-      self
-        .imports
-        .insert(key, ImporterInfo::new(import_decl.span, specifiers, false));
-    }
+      DependencyType::EsmImport
+    };
+    merge_importer_info(
+      &mut self.imports,
+      (import_decl.src.value.clone(), dependency_type),
+      ImporterInfo::new(import_decl.span, specifiers, false).with_attributes(attributes),
+    );
     self
       .presentational_dependencies
       .push(Box::new(ConstDependency::new(
@@ -213,39 +502,50 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
   fn visit_named_export(&mut self, named_export: &NamedExport) {
     if let Some(src) = &named_export.src {
       let mut specifiers = vec![];
-      named_export
-        .specifiers
-        .iter()
-        .for_each(|specifier| match specifier {
+      for specifier in named_export.specifiers.iter() {
+        match specifier {
           ExportSpecifier::Namespace(n) => {
             if let ModuleExportName::Ident(export) = &n.name {
               specifiers.push(Specifier::Namespace(export.sym.clone()));
             }
           }
-          ExportSpecifier::Default(_) => {
-            unreachable!()
+          ExportSpecifier::Default(d) => {
+            // `export v from "mod"` is only a proposal
+            self.warn_unsupported_specifier(
+              d.exported.span,
+              &format!(
+                "`export {} from \"{}\"` is not supported, it is skipped",
+                d.exported.sym, src.value
+              ),
+            );
           }
           ExportSpecifier::Named(named) => {
             if let ModuleExportName::Ident(orig) = &named.orig {
-              specifiers.push(Specifier::Named(
-                orig.sym.clone(),
-                match &named.exported {
-                  Some(ModuleExportName::Ident(export)) => Some(export.sym.clone()),
-                  None => None,
-                  _ => unreachable!(),
-                },
-              ));
+              let exported = match &named.exported {
+                Some(ModuleExportName::Ident(export)) => Some(export.sym.clone()),
+                None => None,
+                Some(ModuleExportName::Str(export)) => {
+                  self.warn_unsupported_specifier(
+                    named.span,
+                    &format!(
+                      "Re-exporting `{}` as the string name \"{}\" is not supported, it is skipped",
+                      orig.sym, export.value
+                    ),
+                  );
+                  continue;
+                }
+              };
+              specifiers.push(Specifier::Named(orig.sym.clone(), exported));
             }
           }
-        });
-      let key = (src.value.clone(), DependencyType::EsmExport);
-      if let Some(importer_info) = self.imports.get_mut(&key) {
-        importer_info.specifiers.extend(specifiers);
-      } else {
-        self
-          .imports
-          .insert(key, ImporterInfo::new(named_export.span, specifiers, false));
+        }
       }
+      merge_importer_info(
+        &mut self.imports,
+        (src.value.clone(), DependencyType::EsmExport),
+        ImporterInfo::new(named_export.span, specifiers, false)
+          .with_attributes(get_import_attributes(named_export.with.as_deref())),
+      );
       self
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
@@ -267,13 +567,12 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
     //     vec![],
     //     None,
     //   )));
-    if let Some(importer_info) = self.imports.get_mut(&key) {
-      importer_info.exports_all = true;
-    } else {
-      self
-        .imports
-        .insert(key, ImporterInfo::new(export_all.span, vec![], true));
-    }
+    merge_importer_info(
+      &mut self.imports,
+      key,
+      ImporterInfo::new(export_all.span, vec![], true)
+        .with_attributes(get_import_attributes(export_all.with.as_deref())),
+    );
 
     self
       .presentational_dependencies
@@ -290,7 +589,9 @@ pub struct HarmonyImportRefDependencyScanner<'a> {
   pub enter_callee: bool,
   pub import_map: &'a ImportMap,
   pub dependencies: &'a mut Vec<BoxDependency>,
-  pub properties_in_destructuring: HashMap<JsWord, HashSet<JsWord>>,
+  pub properties_in_destructuring: HashMap<JsWord, HashSet<Vec<JsWord>>>,
+  /// index of the dependency in `dependencies` shared by the references with the same key
+  references: HashMap<HarmonyImportReferenceKey, usize>,
 }
 
 impl<'a> HarmonyImportRefDependencyScanner<'a> {
@@ -300,6 +601,48 @@ impl<'a> HarmonyImportRefDependencyScanner<'a> {
       dependencies,
       enter_callee: false,
       properties_in_destructuring: HashMap::default(),
+      references: HashMap::default(),
+    }
+  }
+}
+
+impl HarmonyImportRefDependencyScanner<'_> {
+  fn add_reference(&mut self, dependency: HarmonyImportSpecifierDependency) {
+    let Some(key) = dependency.reference_key() else {
+      self.dependencies.push(Box::new(dependency));
+      return;
+    };
+    if let Some(index) = self.references.get(&key)
+      && let Some(reference) =
+        self.dependencies[*index].downcast_mut::<HarmonyImportSpecifierDependency>()
+    {
+      let (start, end) = dependency.spans()[0];
+      reference.add_span(start, end);
+      return;
+    }
+    self.references.insert(key, self.dependencies.len());
+    self.dependencies.push(Box::new(dependency));
+  }
+
+  fn collect_namespace_destructuring(&mut self, object_pat: &ObjectPat, right: &Expr) {
+    let Expr::Ident(ident) = right else {
+      return;
+    };
+    if !self
+      .import_map
+      .get(&ident.to_id())
+      .is_some_and(|reference| matches!(reference.specifier, Specifier::Namespace(_)))
+    {
+      return;
+    }
+    // a rest element or a computed key reads the whole namespace, which is what the namespace
+    // reference does without collected properties
+    if let Some(value) = collect_destructuring_assignment_properties(object_pat) {
+      self
+        .properties_in_destructuring
+        .entry(ident.sym.clone())
+        .and_modify(|v| v.extend(value.clone()))
+        .or_insert(value);
     }
   }
 }
@@ -309,33 +652,41 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
 
   // collect referenced properties in destructuring
   // import * as a from 'a';
-  // const { value } = a;
+  // ({ value } = a);
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
-    if let PatOrExpr::Pat(box Pat::Object(object_pat)) = &assign_expr.left && assign_expr.op == AssignOp::Assign && let box Expr::Ident(ident) = &assign_expr.right && let Some(reference) = self.import_map.get(&ident.to_id()) && matches!(reference.specifier, Specifier::Namespace(_))  {
-      if let Some(value) = collect_destructuring_assignment_properties(object_pat) {
-        self.properties_in_destructuring.entry(ident.sym.clone()).and_modify(|v| v.extend(value.clone())).or_insert(value);
-      }
+    if let PatOrExpr::Pat(box Pat::Object(object_pat)) = &assign_expr.left
+      && assign_expr.op == AssignOp::Assign
+    {
+      self.collect_namespace_destructuring(object_pat, &assign_expr.right);
     }
     assign_expr.visit_children_with(self);
   }
 
+  // const { value, other: renamed, withDefault = 1 } = a;
+  fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+    if let Pat::Object(object_pat) = &var_declarator.name
+      && let Some(init) = &var_declarator.init
+    {
+      self.collect_namespace_destructuring(object_pat, init);
+    }
+    var_declarator.visit_children_with(self);
+  }
+
   fn visit_prop(&mut self, n: &Prop) {
     match n {
       Prop::Shorthand(shorthand) => {
         if let Some(reference) = self.import_map.get(&shorthand.to_id()) {
-          self
-            .dependencies
-            .push(Box::new(HarmonyImportSpecifierDependency::new(
-              reference.request.clone(),
-              true,
-              shorthand.span.real_lo(),
-              shorthand.span.real_hi(),
-              reference.names.clone().map(|f| vec![f]).unwrap_or_default(),
-              false,
-              false,
-              reference.specifier.clone(),
-              None,
-            )));
+          self.add_reference(HarmonyImportSpecifierDependency::new(
+            reference.request.clone(),
+            true,
+            shorthand.span.real_lo(),
+            shorthand.span.real_hi(),
+            reference.names.clone().map(|f| vec![f]).unwrap_or_default(),
+            false,
+            false,
+            reference.specifier.clone(),
+            None,
+          ));
         }
       }
       _ => n.visit_children_with(self),
@@ -344,56 +695,88 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
 
   fn visit_ident(&mut self, ident: &Ident) {
     if let Some(reference) = self.import_map.get(&ident.to_id()) {
-      self
-        .dependencies
-        .push(Box::new(HarmonyImportSpecifierDependency::new(
-          reference.request.clone(),
-          false,
-          ident.span.real_lo(),
-          ident.span.real_hi(),
-          reference.names.clone().map(|f| vec![f]).unwrap_or_default(),
-          self.enter_callee,
-          true, // x()
-          reference.specifier.clone(),
-          self.properties_in_destructuring.remove(&ident.sym),
-        )));
+      self.add_reference(HarmonyImportSpecifierDependency::new(
+        reference.request.clone(),
+        false,
+        ident.span.real_lo(),
+        ident.span.real_hi(),
+        reference.names.clone().map(|f| vec![f]).unwrap_or_default(),
+        self.enter_callee,
+        true, // x()
+        reference.specifier.clone(),
+        self.properties_in_destructuring.remove(&ident.sym),
+      ));
     }
   }
 
   fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
-    if let Expr::Ident(ident) = &*member_expr.obj {
-      if let Some(reference) = self.import_map.get(&ident.to_id()) {
-        let prop = match &member_expr.prop {
-          MemberProp::Ident(ident) => Some(ident.sym.clone()),
-          MemberProp::Computed(c) => {
-            if let Expr::Lit(Lit::Str(str)) = &*c.expr {
-              Some(str.value.clone())
-            } else {
-              None
-            }
+    // collect the property chain of `a.b["c"].d` from the outermost member expression
+    let mut props = vec![];
+    let mut is_static = true;
+    let mut current = member_expr;
+    loop {
+      match &current.prop {
+        MemberProp::Ident(ident) => props.push(ident.sym.clone()),
+        MemberProp::Computed(c) => {
+          if let Expr::Lit(Lit::Str(str)) = &*c.expr {
+            props.push(str.value.clone());
+          } else {
+            is_static = false;
           }
-          _ => None,
-        };
-        if let Some(prop) = prop {
-          let mut ids = reference.names.clone().map(|f| vec![f]).unwrap_or_default();
-          ids.push(prop);
-          self
-            .dependencies
-            .push(Box::new(HarmonyImportSpecifierDependency::new(
-              reference.request.clone(),
-              false,
-              member_expr.span.real_lo(),
-              member_expr.span.real_hi(),
-              ids,
-              self.enter_callee,
-              !self.enter_callee, // x.xx()
-              reference.specifier.clone(),
-              None,
-            )));
-          return;
         }
+        _ => is_static = false,
+      }
+      if let Expr::Member(obj) = &*current.obj {
+        current = obj;
+      } else {
+        break;
       }
     }
+
+    if let Expr::Ident(ident) = &*current.obj
+      && let Some(reference) = self.import_map.get(&ident.to_id())
+    {
+      let mut ids = reference.names.clone().map(|f| vec![f]).unwrap_or_default();
+      if is_static {
+        ids.extend(props.into_iter().rev());
+        self.add_reference(HarmonyImportSpecifierDependency::new(
+          reference.request.clone(),
+          false,
+          member_expr.span.real_lo(),
+          member_expr.span.real_hi(),
+          ids,
+          self.enter_callee,
+          !self.enter_callee, // x.xx()
+          reference.specifier.clone(),
+          None,
+        ));
+        return;
+      }
+      // `ns[key].a` may read any export, so reference the whole import
+      self.add_reference(HarmonyImportSpecifierDependency::new(
+        reference.request.clone(),
+        false,
+        ident.span.real_lo(),
+        ident.span.real_hi(),
+        ids,
+        self.enter_callee,
+        true,
+        reference.specifier.clone(),
+        None,
+      ));
+      let mut current = member_expr;
+      loop {
+        if let MemberProp::Computed(c) = &current.prop {
+          c.visit_with(self);
+        }
+        if let Expr::Member(obj) = &*current.obj {
+          current = obj;
+        } else {
+          break;
+        }
+      }
+      return;
+    }
     member_expr.visit_children_with(self);
   }
 
@@ -403,7 +786,513 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
     self.enter_callee = false;
   }
 
+  // gql`...` and styled.div`...` call the tag, only the expressions of the template are not
+  // part of the callee
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl) {
+    self.enter_callee = true;
+    tagged_tpl.tag.visit_with(self);
+    self.enter_callee = false;
+    tagged_tpl.tpl.visit_with(self);
+  }
+
   fn visit_import_decl(&mut self, _decl: &ImportDecl) {}
 
   fn visit_named_export(&mut self, _named_export: &NamedExport) {}
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_core::{BuildInfo, ExtendedReferencedExport, ModuleDependency, ModuleGraph};
+  use rspack_hash::{HashDigest, RspackHashDigest};
+  use swc_core::ecma::parser::{EsConfig, Syntax};
+
+  use super::*;
+  use crate::visitors::parse_and_visit;
+
+  fn scan(cache: &HarmonyImportScanCache, code: &str) -> (Vec<DependencyId>, BuildInfo) {
+    let mut dependencies = vec![];
+    let mut presentational_dependencies = vec![];
+    let mut import_map = Default::default();
+    let mut build_info = BuildInfo::default();
+    let code_hash = RspackHashDigest::new(code.as_bytes().to_vec(), &HashDigest::Hex);
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut presentational_dependencies,
+        &mut import_map,
+        &mut build_info,
+        None,
+        &mut vec![],
+        "main.js".into(),
+        Some((cache, code_hash)),
+      )
+    });
+    let ids = dependencies.iter().map(|dep| *dep.id()).collect();
+    (ids, build_info)
+  }
+
+  #[test]
+  fn unchanged_code_reuses_the_previous_scan() {
+    let cache = HarmonyImportScanCache::default();
+    let code = "import { a } from './a'; export * from './b'; export { c } from './c'; a;";
+    let (first, first_build_info) = scan(&cache, code);
+    assert!(!first.is_empty());
+
+    // the cached dependencies are handed out again with ids of their own
+    let (second, second_build_info) = scan(&cache, code);
+    assert_eq!(first.len(), second.len());
+    assert!(second.iter().all(|id| !first.contains(id)));
+    assert_eq!(
+      first_build_info.all_star_exports.len(),
+      second_build_info.all_star_exports.len()
+    );
+    assert!(second_build_info
+      .all_star_exports
+      .iter()
+      .all(|id| second.contains(id)));
+    assert_eq!(
+      first_build_info.harmony_named_exports,
+      second_build_info.harmony_named_exports
+    );
+
+    let (changed, _) = scan(&cache, &format!("{code} a;"));
+    assert!(changed.iter().all(|id| !first.contains(id)));
+  }
+
+  #[test]
+  fn unsupported_export_specifiers_are_skipped_with_a_warning() {
+    let code = "export v from './v'; export { a as 'a b', c } from './a';";
+    let mut dependencies = vec![];
+    let mut warning_diagnostics = vec![];
+    let mut build_info = BuildInfo::default();
+    parse_and_visit(
+      code,
+      Syntax::Es(EsConfig {
+        export_default_from: true,
+        ..Default::default()
+      }),
+      |program, _| {
+        scan_harmony_imports(
+          program.get_inner_program(),
+          &mut dependencies,
+          &mut vec![],
+          &mut Default::default(),
+          &mut build_info,
+          None,
+          &mut warning_diagnostics,
+          "main.js".into(),
+          None,
+        )
+      },
+    );
+    assert_eq!(warning_diagnostics.len(), 2);
+    assert_eq!(
+      &code[warning_diagnostics[0].start..warning_diagnostics[0].end],
+      "v"
+    );
+    // the supported specifier next to the skipped one is still re-exported
+    assert_eq!(build_info.harmony_named_exports.len(), 1);
+    assert!(build_info.harmony_named_exports.contains(&JsWord::from("c")));
+  }
+
+  #[test]
+  fn specifier_dependencies_point_at_the_reference() {
+    let code = "import { x } from 'missing';\nx();\nexport { x as y } from 'missing';";
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let mut spans = dependencies
+      .iter()
+      .filter_map(|dep| {
+        dep
+          .downcast_ref::<HarmonyImportSpecifierDependency>()
+          .map(|dep| dep as &dyn ModuleDependency)
+          .or_else(|| {
+            dep
+              .downcast_ref::<HarmonyExportImportedSpecifierDependency>()
+              .map(|dep| dep as &dyn ModuleDependency)
+          })
+      })
+      .map(|dep| {
+        let span = dep.span().expect("should have a span");
+        &code[span.start as usize..span.end as usize]
+      })
+      .collect::<Vec<_>>();
+    spans.sort();
+    assert_eq!(spans, vec!["export { x as y } from 'missing';", "x"]);
+  }
+
+  #[test]
+  fn modules_without_import_bindings_have_no_import_references() {
+    let code = "import './polyfill';\nexport * from './all';\nexport { a } from './a';\na(); b;";
+    let mut dependencies = vec![];
+    let mut import_map = ImportMap::default();
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut import_map,
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    // neither side effect imports nor re-exports bind a local name
+    assert!(import_map.is_empty());
+    let count =
+      |matches: fn(&BoxDependency) -> bool| dependencies.iter().filter(|dep| matches(dep)).count();
+    assert_eq!(
+      count(|dep| dep.downcast_ref::<HarmonyImportDependency>().is_some()),
+      3
+    );
+    assert_eq!(
+      count(|dep| dep
+        .downcast_ref::<HarmonyExportImportedSpecifierDependency>()
+        .is_some()),
+      1
+    );
+    assert_eq!(dependencies.len(), 4);
+  }
+
+  #[test]
+  fn re_exported_names_keep_the_declaration_order() {
+    let code = "export { z } from './z'; export { a as y, b } from './a'; \
+      export { default as m } from './m'; export { c as x } from './c';";
+    let mut build_info = BuildInfo::default();
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut vec![],
+        &mut vec![],
+        &mut Default::default(),
+        &mut build_info,
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let exports = build_info
+      .harmony_named_exports
+      .iter()
+      .map(|export| export.to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+    insta::assert_snapshot!(exports, @"z, y, b, m, x");
+  }
+
+  #[test]
+  fn import_map_debug_json_lists_every_binding() {
+    let code = "import a, { b as c } from './a'; import * as ns from './b'; c; ns;";
+    let mut import_map = Default::default();
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut vec![],
+        &mut vec![],
+        &mut import_map,
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let bindings = import_map_to_debug_json(&import_map);
+    let bindings = bindings.as_array().expect("should be an array");
+    assert_eq!(bindings.len(), 3);
+    assert!(bindings[0]["local"]
+      .as_str()
+      .is_some_and(|local| local.starts_with('a')));
+    assert_eq!(bindings[0]["specifier"], "default");
+    assert_eq!(bindings[0]["imported"], "default");
+    assert_eq!(bindings[1]["specifier"], "named");
+    assert_eq!(bindings[1]["imported"], "b");
+    assert_eq!(bindings[2]["request"], "./b");
+    assert_eq!(bindings[2]["specifier"], "namespace");
+    assert!(bindings[2]["imported"].is_null());
+  }
+
+  fn namespace_destructuring_referenced_exports(code: &str) -> Vec<Vec<JsWord>> {
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let dependency = dependencies
+      .iter()
+      .find_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+      .expect("should have a namespace reference");
+    let mut referenced = dependency
+      .get_referenced_exports_in_destructuring(None)
+      .into_iter()
+      .map(|export| match export {
+        ExtendedReferencedExport::Array(name) => name,
+        ExtendedReferencedExport::Export(export) => export.name,
+      })
+      .collect::<Vec<_>>();
+    referenced.sort();
+    referenced
+  }
+
+  #[test]
+  fn namespace_destructuring_references_the_original_keys() {
+    let referenced = namespace_destructuring_referenced_exports(
+      "import * as ns from './a'; const { a, b: renamed, c = 1, 'd': e, f: g = 2 } = ns;",
+    );
+    assert_eq!(
+      referenced,
+      ["a", "b", "c", "d", "f"]
+        .map(|name| vec![JsWord::from(name)])
+        .to_vec()
+    );
+
+    let referenced =
+      namespace_destructuring_referenced_exports("import * as ns from './a'; ({ a: b } = ns);");
+    assert_eq!(referenced, vec![vec![JsWord::from("a")]]);
+  }
+
+  #[test]
+  fn nested_namespace_destructuring_references_full_paths() {
+    let path = |path: &[&str]| {
+      path
+        .iter()
+        .map(|name| JsWord::from(*name))
+        .collect::<Vec<_>>()
+    };
+    let referenced = namespace_destructuring_referenced_exports(
+      "import * as ns from './a'; const { a: { b, c: { d } }, e: { f } = {}, g: {} } = ns;",
+    );
+    assert_eq!(
+      referenced,
+      vec![
+        path(&["a", "b"]),
+        path(&["a", "c", "d"]),
+        path(&["e", "f"]),
+        path(&["g"]),
+      ]
+    );
+
+    // a nested computed key or rest element reads the whole nested object
+    let referenced = namespace_destructuring_referenced_exports(
+      "import * as ns from './a'; const { a: { [key]: b }, c: { d, ...rest } } = ns;",
+    );
+    assert_eq!(
+      referenced,
+      vec![path(&["a"]), path(&["c"]), path(&["c", "d"])]
+    );
+  }
+
+  #[test]
+  fn namespace_destructuring_with_rest_references_the_whole_namespace() {
+    for code in [
+      "import * as ns from './a'; const { a, ...rest } = ns;",
+      "import * as ns from './a'; const { [key]: value } = ns;",
+    ] {
+      assert_eq!(
+        namespace_destructuring_referenced_exports(code),
+        vec![Vec::<JsWord>::new()]
+      );
+    }
+  }
+
+  #[test]
+  fn namespace_references_are_partial_unless_computed() {
+    let code = "import * as ns from './a'; const { a, b: { c } } = ns; ns[key];";
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let path = |path: &[&str]| {
+      path
+        .iter()
+        .map(|name| JsWord::from(*name))
+        .collect::<Vec<_>>()
+    };
+    let referenced = dependencies
+      .iter()
+      .filter_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+      .map(|dep| {
+        let mut referenced = dep
+          .get_referenced_exports(&ModuleGraph::default(), None)
+          .into_iter()
+          .map(|export| match export {
+            ExtendedReferencedExport::Array(name) => (name, None),
+            ExtendedReferencedExport::Export(export) => (export.name, Some(export.can_mangle)),
+          })
+          .collect::<Vec<_>>();
+        referenced.sort();
+        referenced
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      referenced,
+      vec![
+        // only the destructured paths are referenced, the rest of the namespace may be dropped
+        vec![
+          (path(&["a"]), Some(false)),
+          (path(&["b", "c"]), Some(false))
+        ],
+        // a computed access falls back to the whole namespace object
+        vec![(path(&[]), None)],
+      ]
+    );
+  }
+
+  #[test]
+  fn member_chains_reference_the_full_static_path() {
+    let code = "import * as lib from './lib'; import { obj } from './obj'; \
+      lib.a.b.c; lib['a']['b']; lib.a[key].c; obj.x.y;";
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let references = dependencies
+      .iter()
+      .filter_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+      .map(|dep| {
+        let (_, _, ids, ..) = dep.reference_key().expect("should have a reference key");
+        let (start, end) = dep.spans()[0];
+        (
+          ids.iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+          &code[start as usize..end as usize],
+        )
+      })
+      .collect::<Vec<_>>();
+    let path = |path: &[&str]| path.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+      references,
+      vec![
+        (path(&["a", "b", "c"]), "lib.a.b.c"),
+        (path(&["a", "b"]), "lib['a']['b']"),
+        // a non-literal computed access anywhere in the chain references the whole namespace
+        (path(&[]), "lib"),
+        (path(&["obj", "x", "y"]), "obj.x.y"),
+      ]
+    );
+  }
+
+  #[test]
+  fn identical_references_share_one_dependency() {
+    let code = "import { a } from './a'; a; a(); a; ({ a }); a();";
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let references = dependencies
+      .iter()
+      .filter_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+      .map(|dep| {
+        dep
+          .spans()
+          .iter()
+          .map(|&(start, end)| &code[start as usize..end as usize])
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    // reads, calls and shorthand properties generate different code
+    assert_eq!(references, vec![vec!["a", "a"], vec!["a", "a"], vec!["a"]]);
+  }
+
+  #[test]
+  fn tagged_template_tags_are_calls() {
+    let code =
+      "import { gql } from './gql'; import styled from './s'; gql`a ${gql`b`}`; styled.div`c`;";
+    let mut dependencies = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut vec![],
+        &mut Default::default(),
+        &mut BuildInfo::default(),
+        None,
+        &mut vec![],
+        "main.js".into(),
+        None,
+      )
+    });
+    let references = dependencies
+      .iter()
+      .filter_map(|dep| dep.downcast_ref::<HarmonyImportSpecifierDependency>())
+      .map(|dep| {
+        let (request, _, ids, _, call, direct_import) =
+          dep.reference_key().expect("should have a reference key");
+        (request, ids, call, direct_import, dep.spans().len())
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      references,
+      vec![
+        (
+          JsWord::from("./gql"),
+          vec![JsWord::from("gql")],
+          true,
+          true,
+          2
+        ),
+        (
+          JsWord::from("./s"),
+          vec![JsWord::from("default"), JsWord::from("div")],
+          true,
+          false,
+          1
+        ),
+      ]
+    );
+  }
+}
@@ -0,0 +1,194 @@
+use rspack_core::{
+  BoxDependency, ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory,
+  DynamicImportMode, SpanExt,
+};
+use rspack_error::{Diagnostic, DiagnosticKind};
+use rspack_regex::RspackRegex;
+use swc_core::{
+  common::Span,
+  ecma::{
+    ast::{CallExpr, Expr, Lit, ObjectLit, Prop, PropName, PropOrSpread},
+    visit::{noop_visit_type, Visit, VisitWith},
+  },
+};
+
+use super::is_import_meta_context_call;
+use crate::dependency::ImportMetaContextDependency;
+
+// import.meta.webpackContext("./dir", { recursive, regExp, include, exclude, mode })
+pub struct ImportMetaContextScanner<'a> {
+  pub dependencies: &'a mut Vec<BoxDependency>,
+  pub warning_diagnostics: &'a mut Vec<Diagnostic>,
+  /// `eager` turns the `lazy` mode into `eager`
+  pub dynamic_import_mode: DynamicImportMode,
+}
+
+impl<'a> ImportMetaContextScanner<'a> {
+  pub fn new(
+    dependencies: &'a mut Vec<BoxDependency>,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
+    dynamic_import_mode: DynamicImportMode,
+  ) -> Self {
+    Self {
+      dependencies,
+      warning_diagnostics,
+      dynamic_import_mode,
+    }
+  }
+
+  fn warn(&mut self, span: Span, message: String) {
+    self.warning_diagnostics.push(
+      Diagnostic::warn(
+        "Unsupported import.meta.webpackContext".to_string(),
+        message,
+        span.real_lo() as usize,
+        span.real_hi() as usize,
+      )
+      .with_kind(DiagnosticKind::JavaScript),
+    );
+  }
+}
+
+fn get_option<'a>(options: Option<&'a ObjectLit>, name: &str) -> Option<&'a Expr> {
+  options?.props.iter().find_map(|prop| match prop {
+    PropOrSpread::Prop(box Prop::KeyValue(kv))
+      if matches!(&kv.key, PropName::Ident(ident) if &ident.sym == name)
+        || matches!(&kv.key, PropName::Str(str) if &str.value == name) =>
+    {
+      Some(kv.value.as_ref())
+    }
+    _ => None,
+  })
+}
+
+impl Visit for ImportMetaContextScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, node: &CallExpr) {
+    if is_import_meta_context_call(node) && !node.args.is_empty() {
+      if let Some(Lit::Str(str)) = node.args.get(0).and_then(|x| x.expr.as_lit()) {
+        let options = node.args.get(1).and_then(|x| x.expr.as_object());
+
+        let recursive = if let Some(Expr::Lit(Lit::Bool(bool))) = get_option(options, "recursive") {
+          bool.value
+        } else {
+          true
+        };
+
+        let (reg_exp, reg_str) =
+          if let Some(Expr::Lit(Lit::Regex(regex))) = get_option(options, "regExp") {
+            let Ok(reg_exp) = RspackRegex::try_from(regex) else {
+              self.warn(
+                regex.span,
+                format!(
+                  "The regExp /{}/{} is not supported, the call is left as is",
+                  regex.exp, regex.flags
+                ),
+              );
+              return;
+            };
+            (reg_exp, format!("{}|{}", regex.exp, regex.flags))
+          } else {
+            (
+              RspackRegex::new(r"^\.\/.*$").expect("reg failed"),
+              r"^\.\/.*$".to_string(),
+            )
+          };
+
+        let include = if let Some(Expr::Lit(Lit::Regex(regex))) = get_option(options, "include") {
+          Some(regex.exp.to_string())
+        } else {
+          None
+        };
+        let exclude = if let Some(Expr::Lit(Lit::Regex(regex))) = get_option(options, "exclude") {
+          Some(regex.exp.to_string())
+        } else {
+          None
+        };
+
+        let mode = if let Some(Expr::Lit(Lit::Str(mode))) = get_option(options, "mode") {
+          match mode.value.as_ref() {
+            "sync" => ContextMode::Sync,
+            "eager" => ContextMode::Eager,
+            "weak" => ContextMode::Weak,
+            "lazy" if self.dynamic_import_mode == DynamicImportMode::Eager => ContextMode::Eager,
+            "lazy" => ContextMode::Lazy,
+            "lazy-once" => ContextMode::LazyOnce,
+            _ => {
+              self.warn(
+                mode.span,
+                format!("Unknown mode \"{}\", the call is left as is", mode.value),
+              );
+              return;
+            }
+          }
+        } else {
+          ContextMode::Sync
+        };
+
+        let chunk_name_template =
+          if let Some(Expr::Lit(Lit::Str(str))) = get_option(options, "chunkName") {
+            Some(str.value.to_string())
+          } else {
+            None
+          };
+        self
+          .dependencies
+          .push(Box::new(ImportMetaContextDependency::new(
+            node.span.real_lo(),
+            node.span.real_hi(),
+            ContextOptions {
+              mode,
+              recursive,
+              reg_exp,
+              reg_str,
+              include,
+              exclude,
+              category: DependencyCategory::Esm,
+              request: str.value.to_string(),
+              namespace_object: ContextNameSpaceObject::Unset,
+              chunk_name_template,
+            },
+            Some(node.span.into()),
+          )));
+      }
+    } else {
+      node.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use swc_core::ecma::parser::{EsConfig, Syntax};
+
+  use super::*;
+  use crate::visitors::parse_and_visit;
+
+  #[test]
+  fn unsupported_options_are_warned() {
+    let code = r#"
+      import.meta.webpackContext("./dir", { mode: "lazy-twice" });
+      import.meta.webpackContext("./dir", { regExp: /[b-a]/ });
+    "#;
+    let mut dependencies = vec![];
+    let mut warning_diagnostics = vec![];
+    parse_and_visit(code, Syntax::Es(EsConfig::default()), |program, _| {
+      program
+        .get_inner_program()
+        .visit_with(&mut ImportMetaContextScanner::new(
+          &mut dependencies,
+          &mut warning_diagnostics,
+          DynamicImportMode::Lazy,
+        ))
+    });
+    assert!(dependencies.is_empty());
+    assert_eq!(
+      warning_diagnostics
+        .iter()
+        .map(|diagnostic| &code[diagnostic.start..diagnostic.end])
+        .collect::<Vec<_>>(),
+      vec![r#""lazy-twice""#, "/[b-a]/"]
+    );
+  }
+}
@@ -1,7 +1,6 @@
 use rspack_core::{CompilerOptions, ConstDependency, DependencyTemplate, ResourceData, SpanExt};
 use swc_core::common::Spanned;
-use swc_core::ecma::ast::{Expr, Ident, NewExpr, UnaryExpr, UnaryOp};
-use swc_core::ecma::atoms::js_word;
+use swc_core::ecma::ast::{Expr, NewExpr, UnaryExpr, UnaryOp};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use url::Url;
 
@@ -84,6 +83,10 @@ impl Visit for ImportMetaScanner<'_> {
     if is_member_expr_starts_with_import_meta_webpack_hot(expr) {
       return;
     }
+    // exclude import.meta.webpackContext, it's handled by `ImportMetaContextScanner`
+    if expr_matcher::is_import_meta_webpack_context(expr) {
+      return;
+    }
 
     // import.meta
     if expr_matcher::is_import_meta(expr) {
@@ -122,12 +125,9 @@ impl Visit for ImportMetaScanner<'_> {
   }
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr) {
-    // exclude new URL("", import.meta.url)
-    if let Expr::Ident(Ident {
-      sym: js_word!("URL"),
-      ..
-    }) = &*new_expr.callee
-    {
+    // exclude new URL("", import.meta.url), it's handled by `UrlScanner`, any other
+    // `import.meta.url` inside `new URL()` is a standalone reference and still needs rewriting
+    if rspack_core::needs_refactor::match_new_url(new_expr).is_some() {
       return;
     }
     new_expr.visit_children_with(self);
@@ -1,143 +1,527 @@
-use once_cell::sync::Lazy;
 use rspack_core::{
   BoxDependency, BuildMeta, ChunkGroupOptions, ContextMode, ContextNameSpaceObject, ContextOptions,
-  DependencyCategory, SpanExt,
+  DependencyCategory, DependencyType, DynamicImportMode, JavascriptParserOptions, ResourceData,
+  SpanExt,
 };
+use rspack_error::{Diagnostic, DiagnosticKind};
 use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
 use swc_core::{
-  common::{comments::Comments, Span},
+  common::{comments::Comments, BytePos, Span, Spanned, SyntaxContext},
   ecma::{
-    ast::{CallExpr, Callee, Expr, Lit},
+    ast::{CallExpr, Callee, Expr, ExprOrSpread, Id, Ident, Lit, MemberExpr, MemberProp, Pat},
     atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::context_helper::scanner_context_module;
+use super::{
+  collect_destructuring_assignment_properties,
+  context_helper::{is_fully_dynamic_context, scanner_context_module},
+  extract_webpack_chunk_name, extract_webpack_exports, extract_webpack_mode,
+  extract_webpack_prefetch_order, extract_webpack_preload_order, is_system_import_call,
+  is_webpack_ignored,
+};
 use crate::dependency::{ImportContextDependency, ImportDependency};
 
 pub struct ImportScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
+  pub warning_diagnostics: &'a mut Vec<Diagnostic>,
   pub comments: Option<&'a dyn Comments>,
   pub build_meta: &'a BuildMeta,
+  pub resource_data: &'a ResourceData,
+  pub parser_options: &'a JavascriptParserOptions,
+  unresolved_ctxt: &'a SyntaxContext,
 }
 
 impl<'a> ImportScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
     comments: Option<&'a dyn Comments>,
     build_meta: &'a BuildMeta,
+    resource_data: &'a ResourceData,
+    parser_options: &'a JavascriptParserOptions,
+    unresolved_ctxt: &'a SyntaxContext,
   ) -> Self {
     Self {
       dependencies,
+      warning_diagnostics,
       comments,
       build_meta,
+      resource_data,
+      parser_options,
+      unresolved_ctxt,
     }
   }
 
-  fn try_extract_webpack_chunk_name(&self, first_arg_span_of_import_call: &Span) -> Option<String> {
-    use swc_core::common::comments::CommentKind;
-    static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
-      regex::Regex::new(r#"webpackChunkName\s*:\s*("(?P<_1>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)"|'(?P<_2>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)'|`(?P<_3>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)`)"#)
-        .expect("invalid regex")
-    });
-    self
-      .comments
-      .with_leading(first_arg_span_of_import_call.lo, |comments| {
-        let ret = comments
-          .iter()
-          .rev()
-          .filter(|c| matches!(c.kind, CommentKind::Block))
-          .find_map(|comment| {
-            WEBPACK_CHUNK_NAME_CAPTURE_RE
-              .captures(&comment.text)
-              .and_then(|captures| {
-                if let Some(cap) = captures.name("_1") {
-                  Some(cap)
-                } else if let Some(cap) = captures.name("_2") {
-                  Some(cap)
-                } else {
-                  captures.name("_3")
-                }
-              })
-              .map(|mat| mat.as_str().to_string())
-          });
-        ret
-      })
+  /// `import(name)` and `import(`${name}.js`)` have no static prefix, so they bundle every module
+  /// in `context`, relative to the importer, which is rarely intended
+  fn warn_fully_dynamic_request(&mut self, span: Span, context: &str) {
+    let context = self
+      .resource_data
+      .resource_path
+      .parent()
+      .unwrap_or(&self.resource_data.resource_path)
+      .join(context)
+      .normalize();
+    self.warning_diagnostics.push(
+      Diagnostic::warn(
+        "Critical dependency".to_string(),
+        format!(
+          "The request of `import()` has no static prefix, every module in {} may be bundled",
+          context.display()
+        ),
+        span.real_lo() as usize,
+        span.real_hi() as usize,
+      )
+      .with_kind(DiagnosticKind::JavaScript),
+    );
   }
-}
 
-impl Visit for ImportScanner<'_> {
-  noop_visit_type!();
+  /// `import(/* webpackChunkName: "a", webpackPrefetch: true */ "./a")`
+  fn group_options(&self, pos: BytePos) -> ChunkGroupOptions {
+    ChunkGroupOptions::default()
+      .name_optional(extract_webpack_chunk_name(self.comments, pos))
+      .prefetch_order(extract_webpack_prefetch_order(self.comments, pos))
+      .preload_order(extract_webpack_preload_order(self.comments, pos))
+  }
 
-  fn visit_call_expr(&mut self, node: &CallExpr) {
-    if let Callee::Import(import_call) = node.callee {
-      if let Some(dyn_imported) = node.args.get(0) {
-        if dyn_imported.spread.is_none() {
-          match dyn_imported.expr.as_ref() {
-            Expr::Lit(Lit::Str(imported)) => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&imported.span);
-              self.dependencies.push(Box::new(ImportDependency::new(
-                node.span.real_lo(),
-                node.span.real_hi(),
-                imported.value.clone(),
-                Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
-                // TODO scan dynamic import referenced exports
-                None,
-              )));
-            }
-            Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&tpl.span);
-              let request = JsWord::from(
-                tpl
-                  .quasis
-                  .first()
-                  .expect("should have one quasis")
-                  .raw
-                  .to_string(),
-              );
-              self.dependencies.push(Box::new(ImportDependency::new(
-                node.span.real_lo(),
-                node.span.real_hi(),
-                request,
-                Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
-                None,
-              )));
+  /// `import(/* webpackExports: ["a"] */ "./a")` only references `a`, together with the exports
+  /// read in `.then()`. `then_exports` is `None` if `.then()` reads the whole namespace object.
+  fn referenced_exports(
+    &self,
+    pos: BytePos,
+    then_exports: Option<Vec<JsWord>>,
+  ) -> Option<Vec<JsWord>> {
+    let mut exports = extract_webpack_exports(self.comments, pos)?;
+    for export in then_exports? {
+      if !exports.contains(&export) {
+        exports.push(export);
+      }
+    }
+    Some(exports)
+  }
+
+  fn scan_import_call(
+    &mut self,
+    node: &CallExpr,
+    callee_span: Span,
+    dependency_type: DependencyType,
+    then_exports: Option<Vec<JsWord>>,
+  ) {
+    if let Some(dyn_imported) = node.args.get(0) {
+      // `import(/* webpackIgnore: true */ "x")` is kept as is
+      if is_webpack_ignored(self.comments, dyn_imported.span().lo) {
+        return;
+      }
+      if dyn_imported.spread.is_none() {
+        // `System.import()` always loads a chunk
+        let mode = extract_webpack_mode(self.comments, dyn_imported.span().lo)
+          .unwrap_or(self.parser_options.dynamic_import_mode);
+        let eager = mode == DynamicImportMode::Eager
+          && matches!(dependency_type, DependencyType::DynamicImport);
+        let dependency_type = if eager {
+          DependencyType::DynamicImportEager
+        } else {
+          dependency_type
+        };
+        match dyn_imported.expr.as_ref() {
+          Expr::Lit(Lit::Str(imported)) => {
+            self.dependencies.push(Box::new(ImportDependency::new(
+              node.span.real_lo(),
+              node.span.real_hi(),
+              imported.value.clone(),
+              Some(node.span.into()),
+              self.group_options(imported.span.lo),
+              self.referenced_exports(imported.span.lo, then_exports),
+              dependency_type.clone(),
+            )));
+          }
+          Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
+            let request = JsWord::from(
+              tpl
+                .quasis
+                .first()
+                .expect("should have one quasis")
+                .raw
+                .to_string(),
+            );
+            self.dependencies.push(Box::new(ImportDependency::new(
+              node.span.real_lo(),
+              node.span.real_hi(),
+              request,
+              Some(node.span.into()),
+              self.group_options(tpl.span.lo),
+              self.referenced_exports(tpl.span.lo, then_exports),
+              dependency_type,
+            )));
+          }
+          Expr::Ident(_) => {
+            if self.parser_options.expr_context_critical {
+              self.warn_fully_dynamic_request(dyn_imported.span(), ".");
             }
-            _ => {
-              if let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) {
-                self
-                  .dependencies
-                  .push(Box::new(ImportContextDependency::new(
-                    import_call.span.real_lo(),
-                    import_call.span.real_hi(),
-                    node.span.real_hi(),
-                    ContextOptions {
-                      mode: ContextMode::Lazy,
-                      recursive: true,
-                      reg_exp: RspackRegex::new(&reg).expect("reg failed"),
-                      reg_str: reg,
-                      include: None,
-                      exclude: None,
-                      category: DependencyCategory::Esm,
-                      request: context,
-                      namespace_object: if self.build_meta.strict_harmony_module {
-                        ContextNameSpaceObject::Strict
-                      } else {
-                        ContextNameSpaceObject::Bool(true)
-                      },
-                    },
-                    Some(node.span.into()),
-                  )));
+          }
+          _ => {
+            if let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) {
+              if self.parser_options.expr_context_critical && is_fully_dynamic_context(&reg) {
+                self.warn_fully_dynamic_request(dyn_imported.span(), &context);
               }
+              let chunk_name = extract_webpack_chunk_name(self.comments, dyn_imported.span().lo);
+              self
+                .dependencies
+                .push(Box::new(ImportContextDependency::new(
+                  callee_span.real_lo(),
+                  callee_span.real_hi(),
+                  node.span.real_hi(),
+                  ContextOptions {
+                    mode: if eager {
+                      ContextMode::Eager
+                    } else {
+                      ContextMode::Lazy
+                    },
+                    recursive: true,
+                    reg_exp: RspackRegex::new(&reg).expect("reg failed"),
+                    reg_str: reg,
+                    include: None,
+                    exclude: None,
+                    category: DependencyCategory::Esm,
+                    request: context,
+                    namespace_object: if self.build_meta.strict_harmony_module {
+                      ContextNameSpaceObject::Strict
+                    } else {
+                      ContextNameSpaceObject::Bool(true)
+                    },
+                    chunk_name_template: chunk_name,
+                  },
+                  Some(node.span.into()),
+                )));
             }
           }
         }
       }
+    }
+  }
+}
+
+impl Visit for ImportScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, node: &CallExpr) {
+    if let Callee::Expr(box Expr::Member(MemberExpr {
+      obj:
+        box Expr::Call(
+          import_call @ CallExpr {
+            callee: Callee::Import(import),
+            ..
+          },
+        ),
+      prop: MemberProp::Ident(then),
+      ..
+    })) = &node.callee
+      && &*then.sym == "then"
+    {
+      // `import("./a").then(m => m.b)` reads `b`
+      let then_exports = match node.args.first() {
+        Some(ExprOrSpread { spread: None, expr }) => then_callback_exports(expr),
+        Some(_) => None,
+        None => Some(vec![]),
+      };
+      self.scan_import_call(
+        import_call,
+        import.span,
+        DependencyType::DynamicImport,
+        then_exports,
+      );
+      node.args.visit_with(self);
+    } else if let Callee::Import(import_call) = node.callee {
+      self.scan_import_call(
+        node,
+        import_call.span,
+        DependencyType::DynamicImport,
+        Some(vec![]),
+      );
+    } else if is_system_import_call(node, self.unresolved_ctxt) {
+      // `System.import("./a")` is the legacy SystemJS form of `import("./a")`
+      self.scan_import_call(
+        node,
+        node.callee.span(),
+        DependencyType::SystemImport,
+        Some(vec![]),
+      );
     } else {
       node.visit_children_with(self);
     }
   }
 }
+
+/// The exports read from the namespace object by the callback of `.then()`, `None` if the
+/// namespace object is read as a whole.
+fn then_callback_exports(callback: &Expr) -> Option<Vec<JsWord>> {
+  let param = match callback {
+    Expr::Paren(paren) => return then_callback_exports(&paren.expr),
+    Expr::Arrow(arrow) => arrow.params.first(),
+    Expr::Fn(f) => f.function.params.first().map(|param| &param.pat),
+    _ => return None,
+  };
+  match param {
+    None => Some(vec![]),
+    // `({ a, b: { c } }) => {}` reads `a` and `b`
+    Some(Pat::Object(object_pat)) => {
+      let mut exports: Vec<JsWord> = vec![];
+      for path in collect_destructuring_assignment_properties(object_pat)? {
+        let export = path.into_iter().next()?;
+        if !exports.contains(&export) {
+          exports.push(export);
+        }
+      }
+      exports.sort();
+      Some(exports)
+    }
+    Some(Pat::Ident(ident)) => {
+      let mut collector = NamespaceReadCollector {
+        namespace: ident.to_id(),
+        exports: vec![],
+        read_as_whole: false,
+      };
+      match callback {
+        Expr::Arrow(arrow) => arrow.body.visit_with(&mut collector),
+        Expr::Fn(f) => f.function.body.visit_with(&mut collector),
+        _ => {}
+      }
+      (!collector.read_as_whole).then_some(collector.exports)
+    }
+    _ => None,
+  }
+}
+
+/// Collects `ns.a` and `ns["a"]` in the callback of `.then()`, any other usage of `ns` reads the
+/// whole namespace object.
+struct NamespaceReadCollector {
+  namespace: Id,
+  exports: Vec<JsWord>,
+  read_as_whole: bool,
+}
+
+impl Visit for NamespaceReadCollector {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, node: &MemberExpr) {
+    if let Expr::Ident(obj) = &*node.obj
+      && obj.to_id() == self.namespace
+    {
+      let export = match &node.prop {
+        MemberProp::Ident(prop) => Some(prop.sym.clone()),
+        MemberProp::Computed(computed) => match &*computed.expr {
+          Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+          _ => None,
+        },
+        MemberProp::PrivateName(_) => None,
+      };
+      match export {
+        Some(export) if !self.exports.contains(&export) => self.exports.push(export),
+        Some(_) => {}
+        None => self.read_as_whole = true,
+      }
+    } else {
+      node.obj.visit_with(self);
+    }
+    // `ns.ns` is not a usage of `ns`
+    if let MemberProp::Computed(computed) = &node.prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_ident(&mut self, node: &Ident) {
+    if node.to_id() == self.namespace {
+      self.read_as_whole = true;
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_core::{ExtendedReferencedExport, ModuleDependency, ModuleGraph};
+  use swc_core::ecma::parser::{EsConfig, Syntax};
+
+  use super::*;
+  use crate::visitors::parse_and_visit;
+
+  fn dynamic_import_referenced_exports(code: &str) -> Vec<Vec<JsWord>> {
+    let mut dependencies = vec![];
+    let build_meta = BuildMeta::default();
+    let resource_data = ResourceData::new("/src/main.js".into(), "/src/main.js".into());
+    parse_and_visit(
+      code,
+      Syntax::Es(EsConfig::default()),
+      |program, unresolved_ctxt| {
+        program
+          .get_inner_program()
+          .visit_with(&mut ImportScanner::new(
+            &mut dependencies,
+            &mut vec![],
+            program.comments.as_ref().map(|c| c as &dyn Comments),
+            &build_meta,
+            &resource_data,
+            &JavascriptParserOptions::default(),
+            &unresolved_ctxt,
+          ))
+      },
+    );
+    let dependency = dependencies
+      .iter()
+      .find_map(|dep| dep.downcast_ref::<ImportDependency>())
+      .expect("should have a dynamic import");
+    let mut referenced = dependency
+      .get_referenced_exports(&ModuleGraph::default(), None)
+      .into_iter()
+      .map(|export| match export {
+        ExtendedReferencedExport::Array(name) => name,
+        ExtendedReferencedExport::Export(export) => export.name,
+      })
+      .collect::<Vec<_>>();
+    referenced.sort();
+    referenced
+  }
+
+  fn names(names: &[&str]) -> Vec<Vec<JsWord>> {
+    names.iter().map(|name| vec![JsWord::from(*name)]).collect()
+  }
+
+  #[test]
+  fn webpack_exports_references_the_annotated_exports() {
+    assert_eq!(
+      dynamic_import_referenced_exports(r#"import(/* webpackExports: ["a", "b"] */ "./m");"#),
+      names(&["a", "b"])
+    );
+    assert_eq!(
+      dynamic_import_referenced_exports(r#"import(/* webpackExports: 'a' */ "./m");"#),
+      names(&["a"])
+    );
+    // without the annotation the whole module is referenced
+    assert_eq!(
+      dynamic_import_referenced_exports(r#"import("./m").then(m => m.c);"#),
+      vec![Vec::<JsWord>::new()]
+    );
+  }
+
+  #[test]
+  fn webpack_exports_is_combined_with_the_exports_read_in_then() {
+    assert_eq!(
+      dynamic_import_referenced_exports(
+        r#"import(/* webpackExports: ["a"] */ "./m").then(m => m.c + m["a"]);"#
+      ),
+      names(&["a", "c"])
+    );
+    assert_eq!(
+      dynamic_import_referenced_exports(
+        r#"import(/* webpackExports: "a" */ "./m").then(function ({ b, c: { d } }) {});"#
+      ),
+      names(&["a", "b", "c"])
+    );
+    // `m` escapes, so the whole module is referenced
+    assert_eq!(
+      dynamic_import_referenced_exports(
+        r#"import(/* webpackExports: "a" */ "./m").then(m => console.log(m));"#
+      ),
+      vec![Vec::<JsWord>::new()]
+    );
+  }
+
+  fn dynamic_import_requests(code: &str) -> Vec<String> {
+    let mut dependencies = vec![];
+    let build_meta = BuildMeta::default();
+    let resource_data = ResourceData::new("/src/main.js".into(), "/src/main.js".into());
+    parse_and_visit(
+      code,
+      Syntax::Es(EsConfig::default()),
+      |program, unresolved_ctxt| {
+        program
+          .get_inner_program()
+          .visit_with(&mut ImportScanner::new(
+            &mut dependencies,
+            &mut vec![],
+            program.comments.as_ref().map(|c| c as &dyn Comments),
+            &build_meta,
+            &resource_data,
+            &JavascriptParserOptions::default(),
+            &unresolved_ctxt,
+          ))
+      },
+    );
+    dependencies
+      .iter()
+      .filter_map(|dep| dep.downcast_ref::<ImportDependency>())
+      .map(|dep| dep.request().to_string())
+      .collect()
+  }
+
+  #[test]
+  fn system_import_of_a_local_binding_is_ignored() {
+    assert_eq!(
+      dynamic_import_requests(r#"System.import("./x");"#),
+      vec!["./x"]
+    );
+    assert!(
+      dynamic_import_requests(r#"const System = { import() {} }; System.import("./x");"#)
+        .is_empty()
+    );
+  }
+
+  fn fully_dynamic_request_warnings(code: &str) -> Vec<(String, String)> {
+    let mut warning_diagnostics = vec![];
+    let build_meta = BuildMeta::default();
+    let resource_data = ResourceData::new("/src/main.js".into(), "/src/main.js".into());
+    let parser_options = JavascriptParserOptions {
+      expr_context_critical: true,
+      ..Default::default()
+    };
+    parse_and_visit(
+      code,
+      Syntax::Es(EsConfig::default()),
+      |program, unresolved_ctxt| {
+        program
+          .get_inner_program()
+          .visit_with(&mut ImportScanner::new(
+            &mut vec![],
+            &mut warning_diagnostics,
+            program.comments.as_ref().map(|c| c as &dyn Comments),
+            &build_meta,
+            &resource_data,
+            &parser_options,
+            &unresolved_ctxt,
+          ))
+      },
+    );
+    warning_diagnostics
+      .iter()
+      .map(|diagnostic| {
+        (
+          code[diagnostic.start..diagnostic.end].to_string(),
+          diagnostic.message.clone(),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn fully_dynamic_import_request_is_warned() {
+    assert_eq!(
+      fully_dynamic_request_warnings("import(`${name}.js`); import(name + '.js'); import(name);")
+        .into_iter()
+        .map(|(request, _)| request)
+        .collect::<Vec<_>>(),
+      vec!["`${name}.js`", "name + '.js'", "name"]
+    );
+    // the directory the context resolves to is reported
+    let (_, message) = fully_dynamic_request_warnings("import(`${name}.js`)").remove(0);
+    assert_eq!(
+      message,
+      "The request of `import()` has no static prefix, every module in /src may be bundled"
+    );
+    assert!(fully_dynamic_request_warnings(
+      "import('./a'); import(`./locales/${name}.js`); import('./' + name); import(`a${b}`);"
+    )
+    .is_empty());
+  }
+}
@@ -9,20 +9,26 @@ mod harmony_detection_scanner;
 mod harmony_export_dependency_scanner;
 mod harmony_import_dependency_scanner;
 mod hot_module_replacement_scanner;
+mod import_meta_context_scanner;
 mod import_meta_scanner;
 mod import_scanner;
 mod node_stuff_scanner;
 mod require_context_scanner;
+mod require_ensure_scanner;
 mod url_scanner;
 mod util;
 mod worker_scanner;
 use rspack_core::{
   ast::javascript::Program, BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta,
-  CompilerOptions, ModuleIdentifier, ModuleType, ResourceData,
+  CompilerOptions, JavascriptParserOptions, ModuleIdentifier, ModuleType, ResourceData,
 };
+use rspack_error::Diagnostic;
+use rspack_hash::RspackHashDigest;
+use sugar_path::SugarPath;
 use swc_core::common::{comments::Comments, Mark, SyntaxContext};
 pub use util::*;
 
+pub use self::harmony_import_dependency_scanner::HarmonyImportScanCache;
 use self::{
   api_scanner::ApiScanner, common_js_export_scanner::CommonJsExportDependencyScanner,
   common_js_import_dependency_scanner::CommonJsImportDependencyScanner,
@@ -30,13 +36,19 @@ use self::{
   export_info_api_scanner::ExportInfoApiScanner,
   harmony_detection_scanner::HarmonyDetectionScanner,
   harmony_export_dependency_scanner::HarmonyExportDependencyScanner,
-  harmony_import_dependency_scanner::HarmonyImportDependencyScanner,
+  harmony_import_dependency_scanner::{debug_import_map, scan_harmony_imports},
   hot_module_replacement_scanner::HotModuleReplacementScanner,
-  import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
-  node_stuff_scanner::NodeStuffScanner, require_context_scanner::RequireContextScanner,
+  import_meta_context_scanner::ImportMetaContextScanner, import_meta_scanner::ImportMetaScanner,
+  import_scanner::ImportScanner, node_stuff_scanner::NodeStuffScanner,
+  require_context_scanner::RequireContextScanner, require_ensure_scanner::RequireEnsureScanner,
   url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
-pub type ScanDependenciesResult = (Vec<BoxDependency>, Vec<BoxDependencyTemplate>);
+use crate::dependency::{NodeModuleShimDependency, URLResolutionMode};
+pub type ScanDependenciesResult = (
+  Vec<BoxDependency>,
+  Vec<BoxDependencyTemplate>,
+  Vec<Diagnostic>,
+);
 
 #[allow(clippy::too_many_arguments)]
 pub fn scan_dependencies(
@@ -48,9 +60,13 @@ pub fn scan_dependencies(
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  parser_options: &JavascriptParserOptions,
+  // the cache and the hash of the code `program` was parsed from
+  harmony_import_scan_cache: Option<(&HarmonyImportScanCache, RspackHashDigest)>,
 ) -> ScanDependenciesResult {
   let mut dependencies: Vec<BoxDependency> = vec![];
   let mut presentational_dependencies: Vec<BoxDependencyTemplate> = vec![];
+  let mut warning_diagnostics: Vec<Diagnostic> = vec![];
   let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
   let comments = program.comments.clone();
   let mut parser_exports_state = None;
@@ -61,6 +77,18 @@ pub fn scan_dependencies(
     compiler_options.output.module,
     build_info,
   ));
+  if build_info.need_dirname || build_info.need_filename {
+    let request = resource_data
+      .resource_path
+      .relative(&compiler_options.output.path)
+      .to_string_lossy()
+      .replace('\\', "/");
+    presentational_dependencies.push(Box::new(NodeModuleShimDependency::new(
+      request,
+      build_info.need_dirname,
+      build_info.need_filename,
+    )));
+  }
 
   program.visit_with(&mut CompatibilityScanner::new(
     &mut presentational_dependencies,
@@ -82,7 +110,15 @@ pub fn scan_dependencies(
       &mut presentational_dependencies,
       &unresolved_ctxt,
     ));
-    program.visit_with(&mut RequireContextScanner::new(&mut dependencies));
+    program.visit_with(&mut RequireContextScanner::new(
+      &mut dependencies,
+      comments.as_ref().map(|c| c as &dyn Comments),
+    ));
+    program.visit_with(&mut RequireEnsureScanner::new(
+      &mut dependencies,
+      &mut warning_diagnostics,
+      &unresolved_ctxt,
+    ));
     program.visit_with(&mut CommonJsExportDependencyScanner::new(
       &mut presentational_dependencies,
       &unresolved_ctxt,
@@ -109,32 +145,51 @@ pub fn scan_dependencies(
       &mut presentational_dependencies,
     ));
     let mut import_map = Default::default();
-    program.visit_with(&mut HarmonyImportDependencyScanner::new(
-      &mut dependencies,
-      &mut presentational_dependencies,
-      &mut import_map,
-      build_info,
-    ));
+    // a cache hit has no scanner spans, so this one records that the module was scanned
+    tracing::trace_span!("harmony_import_scan", module = %module_identifier).in_scope(|| {
+      scan_harmony_imports(
+        program.get_inner_program(),
+        &mut dependencies,
+        &mut presentational_dependencies,
+        &mut import_map,
+        build_info,
+        comments.as_ref().map(|c| c as &dyn Comments),
+        &mut warning_diagnostics,
+        module_identifier,
+        harmony_import_scan_cache,
+      )
+    });
     program.visit_with(&mut HarmonyExportDependencyScanner::new(
       &mut dependencies,
       &mut presentational_dependencies,
       &mut import_map,
       build_info,
     ));
-    let mut worker_syntax_scanner = rspack_core::needs_refactor::WorkerSyntaxScanner::new(
-      rspack_core::needs_refactor::DEFAULT_WORKER_SYNTAX,
-    );
+    debug_import_map(&module_identifier, &import_map);
+    let worker_syntax = parser_options.worker_syntax();
+    let mut worker_syntax_scanner =
+      rspack_core::needs_refactor::WorkerSyntaxScanner::new(&worker_syntax);
     program.visit_with(&mut worker_syntax_scanner);
     let worker_syntax_list = &worker_syntax_scanner.into();
     let mut worker_scanner = WorkerScanner::new(
       &module_identifier,
       &compiler_options.output,
       worker_syntax_list,
+      comments.as_ref().map(|c| c as &dyn Comments),
     );
     program.visit_with(&mut worker_scanner);
     dependencies.append(&mut worker_scanner.dependencies);
     presentational_dependencies.append(&mut worker_scanner.presentational_dependencies);
-    program.visit_with(&mut UrlScanner::new(&mut dependencies, worker_syntax_list));
+    program.visit_with(&mut UrlScanner::new(
+      &mut dependencies,
+      worker_syntax_list,
+      URLResolutionMode::from_output_options(&compiler_options.output),
+    ));
+    program.visit_with(&mut ImportMetaContextScanner::new(
+      &mut dependencies,
+      &mut warning_diagnostics,
+      parser_options.dynamic_import_mode,
+    ));
     program.visit_with(&mut ImportMetaScanner::new(
       &mut presentational_dependencies,
       resource_data,
@@ -144,8 +199,12 @@ pub fn scan_dependencies(
 
   program.visit_with(&mut ImportScanner::new(
     &mut dependencies,
+    &mut warning_diagnostics,
     comments.as_ref().map(|c| c as &dyn Comments),
     build_meta,
+    resource_data,
+    parser_options,
+    &unresolved_ctxt,
   ));
 
   if compiler_options.dev_server.hot {
@@ -156,5 +215,34 @@ pub fn scan_dependencies(
     ));
   }
 
-  (dependencies, presentational_dependencies)
+  (
+    dependencies,
+    presentational_dependencies,
+    warning_diagnostics,
+  )
+}
+
+/// Parses `code` the way the parser does before scanning dependencies, including the
+/// resolver, and visits the program with its unresolved syntax context
+#[cfg(test)]
+pub(crate) fn parse_and_visit<R>(
+  code: &str,
+  syntax: swc_core::ecma::parser::Syntax,
+  visit: impl FnOnce(&Program, SyntaxContext) -> R,
+) -> R {
+  let mut ast = crate::ast::parse(code.to_string(), syntax, "main.js", &ModuleType::JsEsm)
+    .expect("should parse");
+  ast.transform(|program, context| {
+    program.visit_mut_with(&mut super::swc_visitor::resolver(
+      context.unresolved_mark,
+      context.top_level_mark,
+      syntax.typescript(),
+    ));
+  });
+  ast.visit(|program, context| {
+    visit(
+      program,
+      SyntaxContext::empty().apply_mark(context.unresolved_mark),
+    )
+  })
 }
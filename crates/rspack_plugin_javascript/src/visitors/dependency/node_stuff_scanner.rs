@@ -101,11 +101,11 @@ impl Visit for NodeStuffScanner<'_> {
           if matches!(self.node_option.global.as_str(), "true" | "warn") {
             self
               .presentational_dependencies
-              .push(Box::new(ConstDependency::new(
+              .push(Box::new(ConstDependency::with_runtime(
                 ident.span.real_lo(),
                 ident.span.real_hi(),
                 RuntimeGlobals::GLOBAL.name().into(),
-                Some(RuntimeGlobals::GLOBAL),
+                RuntimeGlobals::GLOBAL,
               )));
           }
         }
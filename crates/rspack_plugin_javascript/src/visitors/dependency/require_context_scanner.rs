@@ -2,21 +2,28 @@ use rspack_core::{
   BoxDependency, ContextMode, ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt,
 };
 use rspack_regex::RspackRegex;
-use swc_core::ecma::{
-  ast::{CallExpr, Lit},
-  visit::{noop_visit_type, Visit, VisitWith},
+use swc_core::{
+  common::comments::Comments,
+  ecma::{
+    ast::{CallExpr, Lit},
+    visit::{noop_visit_type, Visit, VisitWith},
+  },
 };
 
-use super::is_require_context_call;
+use super::{extract_webpack_chunk_name, is_require_context_call};
 use crate::dependency::RequireContextDependency;
 
 pub struct RequireContextScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
+  pub comments: Option<&'a dyn Comments>,
 }
 
 impl<'a> RequireContextScanner<'a> {
-  pub fn new(dependencies: &'a mut Vec<BoxDependency>) -> Self {
-    Self { dependencies }
+  pub fn new(dependencies: &'a mut Vec<BoxDependency>, comments: Option<&'a dyn Comments>) -> Self {
+    Self {
+      dependencies,
+      comments,
+    }
   }
 }
 
@@ -59,6 +66,8 @@ impl Visit for RequireContextScanner<'_> {
         } else {
           ContextMode::Sync
         };
+        // `require.context(/* webpackChunkName: "pages/[request]" */ "./pages", true, /\.js$/, "lazy")`
+        let chunk_name_template = extract_webpack_chunk_name(self.comments, str.span.lo);
         self
           .dependencies
           .push(Box::new(RequireContextDependency::new(
@@ -74,6 +83,7 @@ impl Visit for RequireContextScanner<'_> {
               category: DependencyCategory::CommonJS,
               request: str.value.to_string(),
               namespace_object: ContextNameSpaceObject::Unset,
+              chunk_name_template,
             },
             Some(node.span.into()),
           )));
@@ -0,0 +1,209 @@
+use rspack_core::{BoxDependency, ChunkGroupOptions, Dependency, ModuleDependency, SpanExt};
+use rspack_error::{Diagnostic, DiagnosticKind};
+use swc_core::{
+  common::{Span, Spanned, SyntaxContext},
+  ecma::{
+    ast::{CallExpr, Callee, Expr, ExprOrSpread, Id, Lit, Pat},
+    atoms::JsWord,
+    visit::{noop_visit_type, Visit, VisitWith},
+  },
+};
+
+use super::{expr_matcher, is_unresolved_member_object_ident};
+use crate::dependency::{RequireEnsureDependency, RequireEnsureItemDependency};
+
+/// `require.ensure(dependencies, callback, errorCallback?, chunkName?)`, the legacy
+/// webpack way of code splitting
+pub struct RequireEnsureScanner<'a> {
+  pub dependencies: &'a mut Vec<BoxDependency>,
+  pub warning_diagnostics: &'a mut Vec<Diagnostic>,
+  unresolved_ctxt: &'a SyntaxContext,
+}
+
+impl<'a> RequireEnsureScanner<'a> {
+  pub fn new(
+    dependencies: &'a mut Vec<BoxDependency>,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
+    unresolved_ctxt: &'a SyntaxContext,
+  ) -> Self {
+    Self {
+      dependencies,
+      warning_diagnostics,
+      unresolved_ctxt,
+    }
+  }
+
+  fn warn(&mut self, span: Span, message: String) {
+    self.warning_diagnostics.push(
+      Diagnostic::warn(
+        "Unsupported require.ensure".to_string(),
+        message,
+        span.real_lo() as usize,
+        span.real_hi() as usize,
+      )
+      .with_kind(DiagnosticKind::JavaScript),
+    );
+  }
+
+  fn scan_require_ensure(&mut self, node: &CallExpr) {
+    let Some(ExprOrSpread {
+      spread: None,
+      expr: dependencies_expr,
+    }) = node.args.get(0)
+    else {
+      return;
+    };
+    // `require.ensure("./a", cb)` is the same as `require.ensure(["./a"], cb)`
+    let requests = match &**dependencies_expr {
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+          Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+            Expr::Lit(Lit::Str(str)) => Some((str.value.clone(), str.span)),
+            _ => None,
+          },
+          _ => None,
+        })
+        .collect::<Option<Vec<_>>>(),
+      Expr::Lit(Lit::Str(str)) => Some(vec![(str.value.clone(), str.span)]),
+      _ => {
+        self.warn(
+          dependencies_expr.span(),
+          "The first argument of require.ensure should be an array of requests, the call is left as is".to_string(),
+        );
+        return;
+      }
+    };
+    let Some(requests) = requests else {
+      self.warn(
+        dependencies_expr.span(),
+        "The dependencies of require.ensure should be string literals, the call is left as is"
+          .to_string(),
+      );
+      return;
+    };
+
+    let Some((callback, (callback_span, require_param))) = node
+      .args
+      .get(1)
+      .and_then(|arg| Some((&*arg.expr, as_function(&arg.expr)?)))
+    else {
+      self.warn(
+        node.span,
+        "The second argument of require.ensure should be a function, the call is left as is"
+          .to_string(),
+      );
+      return;
+    };
+    let (error_callback, chunk_name) = match node.args.get(2).map(|arg| &*arg.expr) {
+      Some(Expr::Lit(Lit::Str(str))) => (None, Some(str.value.to_string())),
+      Some(expr) if as_function(expr).is_some() => (
+        Some(expr),
+        match node.args.get(3).map(|arg| &*arg.expr) {
+          Some(Expr::Lit(Lit::Str(str))) => Some(str.value.to_string()),
+          _ => None,
+        },
+      ),
+      _ => (None, None),
+    };
+    let group_options = ChunkGroupOptions::default().name_optional(chunk_name);
+
+    let mut items = requests
+      .into_iter()
+      .map(|(request, span)| {
+        RequireEnsureItemDependency::new(request, Some(span.into()), None, group_options.clone())
+      })
+      .collect::<Vec<_>>();
+    // `require("./b")` through the `require` parameter of the callback is loaded with the
+    // listed dependencies
+    if let Some(require) = require_param {
+      let mut collector = CallbackRequireCollector {
+        require,
+        requests: vec![],
+      };
+      callback.visit_with(&mut collector);
+      items.extend(collector.requests.into_iter().map(|(request, span)| {
+        RequireEnsureItemDependency::new(
+          request,
+          Some(span.into()),
+          Some((span.real_lo(), span.real_hi())),
+          group_options.clone(),
+        )
+      }));
+    }
+
+    self
+      .dependencies
+      .push(Box::new(RequireEnsureDependency::new(
+        node.span.real_lo(),
+        node.span.real_hi(),
+        (callback_span.real_lo(), callback_span.real_hi()),
+        error_callback.map(|expr| (expr.span().real_lo(), expr.span().real_hi())),
+        items
+          .iter()
+          .map(|item| (*item.id(), JsWord::from(item.request())))
+          .collect(),
+      )));
+    self.dependencies.extend(
+      items
+        .into_iter()
+        .map(|item| Box::new(item) as BoxDependency),
+    );
+  }
+}
+
+/// The span of a function or an arrow function, and the id of its first parameter which is
+/// `__webpack_require__` when require.ensure calls it
+fn as_function(expr: &Expr) -> Option<(Span, Option<Id>)> {
+  let first_param_id = |pat: Option<&Pat>| match pat {
+    Some(Pat::Ident(ident)) => Some(ident.to_id()),
+    _ => None,
+  };
+  match expr {
+    Expr::Paren(paren) => as_function(&paren.expr),
+    Expr::Fn(f) => Some((
+      f.span(),
+      first_param_id(f.function.params.first().map(|param| &param.pat)),
+    )),
+    Expr::Arrow(arrow) => Some((arrow.span, first_param_id(arrow.params.first()))),
+    _ => None,
+  }
+}
+
+struct CallbackRequireCollector {
+  require: Id,
+  requests: Vec<(JsWord, Span)>,
+}
+
+impl Visit for CallbackRequireCollector {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, node: &CallExpr) {
+    if let Callee::Expr(box Expr::Ident(ident)) = &node.callee
+      && ident.to_id() == self.require
+      && let [ExprOrSpread {
+        spread: None,
+        expr: box Expr::Lit(Lit::Str(str)),
+      }] = node.args.as_slice()
+    {
+      self.requests.push((str.value.clone(), str.span));
+      return;
+    }
+    node.visit_children_with(self);
+  }
+}
+
+impl Visit for RequireEnsureScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, node: &CallExpr) {
+    if let Callee::Expr(expr) = &node.callee
+      && is_unresolved_member_object_ident(expr, self.unresolved_ctxt)
+      && expr_matcher::is_require_ensure(expr)
+    {
+      self.scan_require_ensure(node);
+    }
+    node.visit_children_with(self);
+  }
+}
@@ -4,11 +4,12 @@ use swc_core::ecma::{
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
-use crate::dependency::URLDependency;
+use crate::dependency::{URLDependency, URLResolutionMode};
 
 pub struct UrlScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  mode: URLResolutionMode,
 }
 
 // new URL("./foo.png", import.meta.url);
@@ -16,10 +17,12 @@ impl<'a> UrlScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    mode: URLResolutionMode,
   ) -> Self {
     Self {
       dependencies,
       worker_syntax_list,
+      mode,
     }
   }
 }
@@ -35,12 +38,17 @@ impl Visit for UrlScanner<'_> {
       }
       return;
     }
+    // the url of a worker bound to a variable is handled by the worker scanner
+    if self.worker_syntax_list.is_worker_url(new_expr) {
+      return;
+    }
     if let Some((start, end, request)) = rspack_core::needs_refactor::match_new_url(new_expr) {
       self.dependencies.push(Box::new(URLDependency::new(
         start,
         end,
         request.into(),
         Some(new_expr.span.into()),
+        self.mode,
       )));
     } else {
       new_expr.visit_children_with(self);
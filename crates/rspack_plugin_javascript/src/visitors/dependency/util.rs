@@ -1,35 +1,227 @@
+use once_cell::sync::Lazy;
+use rspack_core::DynamicImportMode;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::{
-  common::SyntaxContext,
+  common::{
+    comments::{CommentKind, Comments},
+    BytePos, SyntaxContext,
+  },
   ecma::{
-    ast::{CallExpr, Expr, MemberExpr, ObjectPat, ObjectPatProp, PropName},
+    ast::{CallExpr, Expr, MemberExpr, ObjectPat, ObjectPatProp, Pat, PropName},
     atoms::JsWord,
   },
 };
 
+/// Whether the block comment right before `pos` is `/* webpackIgnore: true */`, comments further
+/// away belong to something else and are not considered.
+pub fn is_webpack_ignored(comments: Option<&dyn Comments>, pos: BytePos) -> bool {
+  static WEBPACK_IGNORE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r"(^|[\s,{])webpackIgnore\s*:\s*true\b").expect("invalid regex")
+  });
+  comments
+    .and_then(|comments| {
+      comments.with_leading(pos, |comments| {
+        comments
+          .last()
+          .filter(|c| matches!(c.kind, CommentKind::Block))
+          .map(|c| WEBPACK_IGNORE_RE.is_match(&c.text))
+      })
+    })
+    .unwrap_or_default()
+}
+
+/// The chunk name given by a `/* webpackChunkName: "name" */` comment right before `pos`.
+pub fn extract_webpack_chunk_name(comments: Option<&dyn Comments>, pos: BytePos) -> Option<String> {
+  static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r#"webpackChunkName\s*:\s*("(?P<_1>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)"|'(?P<_2>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)'|`(?P<_3>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)`)"#)
+      .expect("invalid regex")
+  });
+  comments.with_leading(pos, |comments| {
+    comments
+      .iter()
+      .rev()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+      .find_map(|comment| {
+        WEBPACK_CHUNK_NAME_CAPTURE_RE
+          .captures(&comment.text)
+          .and_then(|captures| {
+            if let Some(cap) = captures.name("_1") {
+              Some(cap)
+            } else if let Some(cap) = captures.name("_2") {
+              Some(cap)
+            } else {
+              captures.name("_3")
+            }
+          })
+          .map(|mat| mat.as_str().to_string())
+      })
+  })
+}
+
+/// The order given by a `/* webpackPrefetch: true */` comment right before `pos`, `true` is the
+/// same as `0` and `false` turns prefetching off.
+pub fn extract_webpack_prefetch_order(
+  comments: Option<&dyn Comments>,
+  pos: BytePos,
+) -> Option<i32> {
+  static WEBPACK_PREFETCH_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r"(^|[\s,{])webpackPrefetch\s*:\s*(?P<order>true|false|-?\d+)\b")
+      .expect("invalid regex")
+  });
+  extract_webpack_chunk_order(comments, pos, &WEBPACK_PREFETCH_CAPTURE_RE)
+}
+
+/// The order given by a `/* webpackPreload: true */` comment right before `pos`, `true` is the
+/// same as `0` and `false` turns preloading off.
+pub fn extract_webpack_preload_order(comments: Option<&dyn Comments>, pos: BytePos) -> Option<i32> {
+  static WEBPACK_PRELOAD_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r"(^|[\s,{])webpackPreload\s*:\s*(?P<order>true|false|-?\d+)\b")
+      .expect("invalid regex")
+  });
+  extract_webpack_chunk_order(comments, pos, &WEBPACK_PRELOAD_CAPTURE_RE)
+}
+
+/// The mode given by a `/* webpackMode: "eager" */` comment right before `pos`, the modes other
+/// than `lazy` and `eager` are not supported.
+pub fn extract_webpack_mode(
+  comments: Option<&dyn Comments>,
+  pos: BytePos,
+) -> Option<DynamicImportMode> {
+  static WEBPACK_MODE_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r#"(^|[\s,{])webpackMode\s*:\s*["'`](?P<mode>lazy|eager)["'`]"#)
+      .expect("invalid regex")
+  });
+  comments.with_leading(pos, |comments| {
+    comments
+      .iter()
+      .rev()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+      .find_map(|comment| {
+        WEBPACK_MODE_CAPTURE_RE
+          .captures(&comment.text)?
+          .name("mode")
+      })
+      .and_then(|mode| DynamicImportMode::try_from(mode.as_str()).ok())
+  })
+}
+
+/// The export names given by a `/* webpackExports: ["a", "b"] */` or `/* webpackExports: "a" */`
+/// comment right before `pos`.
+pub fn extract_webpack_exports(
+  comments: Option<&dyn Comments>,
+  pos: BytePos,
+) -> Option<Vec<JsWord>> {
+  static WEBPACK_EXPORTS_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(
+      r#"(^|[\s,{])webpackExports\s*:\s*(?P<exports>\[[^\]]*\]|"[^"]*"|'[^']*'|`[^`]*`)"#,
+    )
+    .expect("invalid regex")
+  });
+  static EXPORT_NAME_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r#""(?P<_1>[^"]+)"|'(?P<_2>[^']+)'|`(?P<_3>[^`]+)`"#).expect("invalid regex")
+  });
+  comments.with_leading(pos, |comments| {
+    comments
+      .iter()
+      .rev()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+      .find_map(|comment| {
+        WEBPACK_EXPORTS_CAPTURE_RE
+          .captures(&comment.text)?
+          .name("exports")
+      })
+      .map(|exports| {
+        let mut names: Vec<JsWord> = vec![];
+        for captures in EXPORT_NAME_RE.captures_iter(exports.as_str()) {
+          let Some(name) = captures
+            .name("_1")
+            .or_else(|| captures.name("_2"))
+            .or_else(|| captures.name("_3"))
+          else {
+            continue;
+          };
+          let name = JsWord::from(name.as_str());
+          if !names.contains(&name) {
+            names.push(name);
+          }
+        }
+        names
+      })
+  })
+}
+
+fn extract_webpack_chunk_order(
+  comments: Option<&dyn Comments>,
+  pos: BytePos,
+  re: &regex::Regex,
+) -> Option<i32> {
+  comments.with_leading(pos, |comments| {
+    comments
+      .iter()
+      .rev()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+      .find_map(|comment| re.captures(&comment.text)?.name("order"))
+      .and_then(|order| match order.as_str() {
+        "true" => Some(0),
+        "false" => None,
+        order => order.parse().ok(),
+      })
+  })
+}
+
+/// The property paths read by `{ a, b: c, d = 1, "e": f, g: { h } } = obj`, `None` if the whole
+/// object is read by a rest element or a computed key. A nested pattern that reads its whole
+/// object marks the path of the nested object as read.
 pub fn collect_destructuring_assignment_properties(
   object_pat: &ObjectPat,
-) -> Option<HashSet<JsWord>> {
+) -> Option<HashSet<Vec<JsWord>>> {
   let mut properties = HashSet::default();
+  if !collect_object_pat_paths(object_pat, &[], &mut properties) || properties.is_empty() {
+    return None;
+  }
+  Some(properties)
+}
 
+/// Collects the paths read by `object_pat` under `prefix`, `false` if the whole object is read
+fn collect_object_pat_paths(
+  object_pat: &ObjectPat,
+  prefix: &[JsWord],
+  properties: &mut HashSet<Vec<JsWord>>,
+) -> bool {
   for property in &object_pat.props {
-    match property {
-      ObjectPatProp::Assign(assign) => {
-        properties.insert(assign.key.sym.clone());
-      }
+    let (key, value) = match property {
+      ObjectPatProp::Assign(assign) => (assign.key.sym.clone(), None),
       ObjectPatProp::KeyValue(key_value) => {
-        if let PropName::Ident(ident) = &key_value.key {
-          properties.insert(ident.sym.clone());
-        }
+        let key = match &key_value.key {
+          PropName::Ident(ident) => ident.sym.clone(),
+          PropName::Str(str) => str.value.clone(),
+          PropName::Num(num) => JsWord::from(num.value.to_string()),
+          PropName::Computed(_) | PropName::BigInt(_) => return false,
+        };
+        (key, Some(&*key_value.value))
+      }
+      ObjectPatProp::Rest(_) => return false,
+    };
+    let mut path = prefix.to_vec();
+    path.push(key);
+    // `{ a: { b } }` and `{ a: { b } = {} }` read `a.b`
+    let nested = match value {
+      Some(Pat::Object(nested)) => Some(nested),
+      Some(Pat::Assign(assign)) => match &*assign.left {
+        Pat::Object(nested) => Some(nested),
+        _ => None,
+      },
+      _ => None,
+    };
+    if let Some(nested) = nested {
+      let len = properties.len();
+      if collect_object_pat_paths(nested, &path, properties) && properties.len() > len {
+        continue;
       }
-      ObjectPatProp::Rest(_) => {}
     }
+    properties.insert(path);
   }
-
-  if properties.is_empty() {
-    return None;
-  }
-  Some(properties)
+  true
 }
 
 pub(crate) mod expr_matcher {
@@ -83,6 +275,7 @@ pub(crate) mod expr_matcher {
     is_require_context: "require.context",
     is_require_resolve: "require.resolve",
     is_require_resolve_weak: "require.resolveWeak",
+    is_require_ensure: "require.ensure",
     is_module_hot_accept: "module.hot.accept",
     is_module_hot_decline: "module.hot.decline",
     is_module_hot: "module.hot",
@@ -95,11 +288,13 @@ pub(crate) mod expr_matcher {
     is_import_meta_webpack_hot_accept: "import.meta.webpackHot.accept",
     is_import_meta_webpack_hot_decline: "import.meta.webpackHot.decline",
     is_import_meta_url: "import.meta.url",
+    is_import_meta_webpack_context: "import.meta.webpackContext",
     is_import_meta: "import.meta",
     is_exports_esmodule: "exports.__esModule",
     is_this_esmodule: "this.__esModule",
     is_module_exports_esmodule: "module.exports.__esModule",
     is_object_define_property: "Object.defineProperty",
+    is_system_import: "System.import",
   });
 }
 
@@ -171,6 +366,25 @@ pub fn is_import_meta_hot_decline_call(node: &CallExpr) -> bool {
     .unwrap_or_default()
 }
 
+pub fn is_import_meta_context_call(node: &CallExpr) -> bool {
+  node
+    .callee
+    .as_expr()
+    .map(|expr| expr_matcher::is_import_meta_webpack_context(expr))
+    .unwrap_or_default()
+}
+
+pub fn is_system_import_call(node: &CallExpr, unresolved_ctxt: &SyntaxContext) -> bool {
+  node
+    .callee
+    .as_expr()
+    .map(|expr| {
+      is_unresolved_member_object_ident(expr, unresolved_ctxt)
+        && expr_matcher::is_system_import(expr)
+    })
+    .unwrap_or_default()
+}
+
 // Notice: Include `import.meta` itself
 pub fn is_member_expr_starts_with_import_meta(mut expr: &Expr) -> bool {
   loop {
@@ -1,17 +1,18 @@
 use std::hash::Hash;
 
 use rspack_core::{
-  BoxDependency, BoxDependencyTemplate, ConstDependency, EntryOptions, ModuleIdentifier,
-  OutputOptions, SpanExt,
+  render_chunk_name_template, BoxDependency, BoxDependencyTemplate, ConstDependency, EntryOptions,
+  ModuleIdentifier, OutputOptions, SpanExt,
 };
 use rspack_hash::RspackHash;
-use swc_core::common::Spanned;
+use swc_core::common::{comments::Comments, Spanned};
 use swc_core::ecma::ast::ObjectLit;
 use swc_core::ecma::{
   ast::{Expr, ExprOrSpread, Lit, NewExpr},
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
+use super::extract_webpack_chunk_name;
 use crate::dependency::WorkerDependency;
 
 // TODO: should created by WorkerPlugin
@@ -22,6 +23,7 @@ pub struct WorkerScanner<'a> {
   module_identifier: &'a ModuleIdentifier,
   output_options: &'a OutputOptions,
   syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  comments: Option<&'a dyn Comments>,
 }
 
 // new Worker(new URL("./foo.worker.js", import.meta.url));
@@ -30,6 +32,7 @@ impl<'a> WorkerScanner<'a> {
     module_identifier: &'a ModuleIdentifier,
     output_options: &'a OutputOptions,
     syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    comments: Option<&'a dyn Comments>,
   ) -> Self {
     Self {
       presentational_dependencies: Vec::new(),
@@ -38,6 +41,7 @@ impl<'a> WorkerScanner<'a> {
       module_identifier,
       output_options,
       syntax_list,
+      comments,
     }
   }
 
@@ -50,6 +54,7 @@ impl<'a> WorkerScanner<'a> {
     let mut hasher = RspackHash::from(self.output_options);
     self.module_identifier.hash(&mut hasher);
     self.index.hash(&mut hasher);
+    let index = self.index;
     self.index += 1;
     let digest = hasher.digest(&self.output_options.hash_digest);
     let runtime = digest
@@ -57,6 +62,16 @@ impl<'a> WorkerScanner<'a> {
       .to_owned();
     let range = parsed_options.as_ref().map(|options| options.range);
     let name = parsed_options.and_then(|options| options.name);
+    // `new Worker(/* webpackChunkName: "workers/[name]" */ new URL("./a.js", import.meta.url))`
+    let name = match &parsed_path.chunk_name_template {
+      Some(template) => Some(render_chunk_name_template(
+        template,
+        index,
+        &parsed_path.value,
+        name.as_deref(),
+      )),
+      None => name,
+    };
     let output_module = self.output_options.module;
     self.dependencies.push(Box::new(WorkerDependency::new(
       parsed_path.range.0,
@@ -102,6 +117,22 @@ impl<'a> WorkerScanner<'a> {
     }
   }
 
+  fn match_worker_url(&self, expr_or_spread: &ExprOrSpread) -> Option<(u32, u32, String)> {
+    match expr_or_spread {
+      ExprOrSpread {
+        spread: None,
+        expr: box Expr::New(new_url_expr),
+      } => rspack_core::needs_refactor::match_new_url(new_url_expr),
+      // const url = new URL("./foo.worker.js", import.meta.url);
+      // new Worker(url);
+      ExprOrSpread {
+        spread: None,
+        expr: box Expr::Ident(ident),
+      } => self.syntax_list.match_worker_url_variable(ident),
+      _ => None,
+    }
+  }
+
   fn parse_new_worker(
     &self,
     new_expr: &NewExpr,
@@ -109,11 +140,11 @@ impl<'a> WorkerScanner<'a> {
     if self.syntax_list.match_new_worker(new_expr)
     && let Some(args) = &new_expr.args
     && let Some(expr_or_spread) = args.first()
-    && let ExprOrSpread { spread: None, expr: box Expr::New(new_url_expr) } = expr_or_spread
-    && let Some((start, end, request)) = rspack_core::needs_refactor::match_new_url(new_url_expr) {
+    && let Some((start, end, request)) = self.match_worker_url(expr_or_spread) {
       let path = ParsedNewWorkerPath {
         range: (start, end),
         value: request,
+        chunk_name_template: extract_webpack_chunk_name(self.comments, expr_or_spread.span().lo),
       };
       let options = args.get(1).map(parse_new_worker_options);
       Some((path, options))
@@ -139,6 +170,7 @@ impl Visit for WorkerScanner<'_> {
 struct ParsedNewWorkerPath {
   pub range: (u32, u32),
   pub value: String,
+  pub chunk_name_template: Option<String>,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,49 @@
+use rspack_core::{
+  AsDependencyTemplate, AsModuleDependency, Dependency, DependencyId, DependencyType,
+  ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// Provides the top level keys of a json object as named exports.
+#[derive(Debug, Clone)]
+pub struct JsonExportsDependency {
+  id: DependencyId,
+  exports: Vec<JsWord>,
+}
+
+impl JsonExportsDependency {
+  pub fn new(exports: Vec<JsWord>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      exports,
+    }
+  }
+}
+
+impl Dependency for JsonExportsDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::JsonExports
+  }
+
+  fn get_exports(&self) -> Option<ExportsSpec> {
+    Some(ExportsSpec {
+      exports: ExportsOfExportsSpec::Array(
+        self
+          .exports
+          .iter()
+          .cloned()
+          .map(ExportNameOrSpec::String)
+          .collect(),
+      ),
+      ..Default::default()
+    })
+  }
+}
+
+impl AsModuleDependency for JsonExportsDependency {}
+
+impl AsDependencyTemplate for JsonExportsDependency {}
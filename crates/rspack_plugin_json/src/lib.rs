@@ -1,18 +1,24 @@
-use json::Error::{
-  ExceededDepthLimit, FailedUtf8Parsing, UnexpectedCharacter, UnexpectedEndOfJson, WrongType,
+use json::{
+  Error::{
+    ExceededDepthLimit, FailedUtf8Parsing, UnexpectedCharacter, UnexpectedEndOfJson, WrongType,
+  },
+  JsonValue,
 };
 use rspack_core::{
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
   BuildMetaDefaultObject, BuildMetaExportsType, CompilerOptions, GenerateContext, Module,
-  ParserAndGenerator, Plugin, RuntimeGlobals, SourceType,
+  ParserAndGenerator, Plugin, RuntimeGlobals, SourceType, UsageState, UsedName,
 };
 use rspack_error::{
   internal_error, DiagnosticKind, Error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
   TraceableError,
 };
 
+mod json_exports_dependency;
 mod utils;
 
+use json_exports_dependency::JsonExportsDependency;
+
 #[derive(Debug)]
 struct JsonParserAndGenerator;
 
@@ -85,16 +91,22 @@ impl ParserAndGenerator for JsonParserAndGenerator {
       }
     });
 
-    let diagnostics = if let Err(err) = parse_result {
-      err.into()
-    } else {
-      vec![]
+    let (diagnostics, dependencies) = match parse_result {
+      Ok(JsonValue::Object(object)) => {
+        let exports = object.iter().map(|(key, _)| key.into()).collect::<Vec<_>>();
+        (
+          vec![],
+          vec![Box::new(JsonExportsDependency::new(exports)) as rspack_core::BoxDependency],
+        )
+      }
+      Ok(_) => (vec![], vec![]),
+      Err(err) => (err.into(), vec![]),
     };
 
     Ok(
       rspack_core::ParseResult {
         presentational_dependencies: vec![],
-        dependencies: vec![],
+        dependencies,
         source: box_source,
         analyze_result: Default::default(),
       }
@@ -107,7 +119,7 @@ impl ParserAndGenerator for JsonParserAndGenerator {
   fn generate(
     &self,
     source: &BoxSource,
-    _module: &dyn rspack_core::Module,
+    module: &dyn rspack_core::Module,
     generate_context: &mut GenerateContext,
   ) -> Result<BoxSource> {
     match generate_context.requested_source_type {
@@ -115,10 +127,44 @@ impl ParserAndGenerator for JsonParserAndGenerator {
         generate_context
           .runtime_requirements
           .insert(RuntimeGlobals::MODULE);
+        let compilation = generate_context.compilation;
+        let source = source.source();
+        let parsed = compilation
+          .options
+          .is_new_tree_shaking()
+          .then(|| json::parse(&source).ok())
+          .flatten();
+        let json_str = if let Some(JsonValue::Object(mut object)) = parsed {
+          // Drop the top level keys that are never referenced
+          let exports_info = compilation
+            .module_graph
+            .get_exports_info(&module.identifier());
+          let unused_keys = object
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .filter(|key| {
+              exports_info.get_used(
+                UsedName::Str(key.as_str().into()),
+                None,
+                &compilation.module_graph,
+              ) == UsageState::Unused
+            })
+            .collect::<Vec<_>>();
+          if unused_keys.is_empty() {
+            source
+          } else {
+            unused_keys.iter().for_each(|key| {
+              object.remove(key);
+            });
+            JsonValue::Object(object).dump().into()
+          }
+        } else {
+          source
+        };
         Ok(
           RawSource::from(format!(
             r#"module.exports = {};"#,
-            utils::escape_json(&source.source())
+            utils::escape_json(&json_str)
           ))
           .boxed(),
         )
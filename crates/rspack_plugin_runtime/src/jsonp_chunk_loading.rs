@@ -35,6 +35,13 @@ impl Plugin for JsonpChunkLoadingPlugin {
           runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
           runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
         }
+        RuntimeGlobals::PREFETCH_CHUNK_HANDLERS | RuntimeGlobals::PRELOAD_CHUNK_HANDLERS
+          if is_enabled_for_chunk =>
+        {
+          has_jsonp_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
         RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS if is_enabled_for_chunk => {
           has_jsonp_chunk_loading = true;
           runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
@@ -98,7 +98,7 @@ impl Plugin for LazyCompilationPlugin {
     }
     if matches!(
       args.dependency_type,
-      DependencyType::DynamicImport | DependencyType::Entry
+      DependencyType::DynamicImport | DependencyType::SystemImport | DependencyType::Entry
     ) {
       return Ok(Some(Box::new(LazyCompilationProxyModule {
         module_identifier: args.indentfiler,
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkGroupOrderKey, Compilation, RuntimeModule, RuntimeModuleStage,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+/// Prefetches or preloads the children of a chunk once it is requested through
+/// `__webpack_require__.e`.
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchPreloadTriggerRuntimeModule {
+  id: Identifier,
+  order_key: ChunkGroupOrderKey,
+  chunk_map: BTreeMap<String, Vec<String>>,
+}
+
+impl ChunkPrefetchPreloadTriggerRuntimeModule {
+  pub fn new(order_key: ChunkGroupOrderKey, chunk_map: BTreeMap<String, Vec<String>>) -> Self {
+    let id = match order_key {
+      ChunkGroupOrderKey::Prefetch => "webpack/runtime/chunk_prefetch_trigger",
+      ChunkGroupOrderKey::Preload => "webpack/runtime/chunk_preload_trigger",
+    };
+    Self {
+      id: Identifier::from(id),
+      order_key,
+      chunk_map,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchPreloadTriggerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let template = match self.order_key {
+      ChunkGroupOrderKey::Prefetch => include_str!("runtime/chunk_prefetch_trigger.js"),
+      ChunkGroupOrderKey::Preload => include_str!("runtime/chunk_preload_trigger.js"),
+    };
+    let chunk_map = serde_json::to_string(&self.chunk_map).expect("invalid chunk map");
+    RawSource::from(template.replace("$CHUNK_MAP$", &chunk_map)).boxed()
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Trigger
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchPreloadTriggerRuntimeModule);
@@ -0,0 +1,52 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_javascript::runtime::stringify_array;
+
+use crate::impl_runtime_module;
+
+/// Prefetches the children of an entry chunk once the entry chunk and the chunks it depends
+/// on are loaded.
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchStartupRuntimeModule {
+  id: Identifier,
+  /// the chunks to wait for and the chunks to prefetch after
+  startup_chunks: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl ChunkPrefetchStartupRuntimeModule {
+  pub fn new(startup_chunks: Vec<(Vec<String>, Vec<String>)>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_startup"),
+      startup_chunks,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let mut source = ConcatSource::default();
+    for (chunk_ids, child_chunk_ids) in &self.startup_chunks {
+      source.add(RawSource::from(format!(
+        "{}(0, {}, function() {{\n  {}.map({});\n}}, 5);\n",
+        RuntimeGlobals::ON_CHUNKS_LOADED,
+        stringify_array(chunk_ids),
+        stringify_array(child_chunk_ids),
+        RuntimeGlobals::PREFETCH_CHUNK,
+      )));
+    }
+    source.boxed()
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Trigger
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchStartupRuntimeModule);
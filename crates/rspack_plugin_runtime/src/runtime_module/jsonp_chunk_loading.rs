@@ -79,6 +79,34 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       ));
     }
 
+    let with_prefetch = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    let with_preload = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+    if with_prefetch || with_preload {
+      let condition_map =
+        compilation
+          .chunk_graph
+          .get_chunk_condition_map(&chunk.ukey, compilation, chunk_has_js);
+      let js_matcher = render_condition_map(&condition_map);
+      // prefetched or preloaded chunks are marked as `null` in installedChunks, so they are
+      // neither fetched again nor skipped when they are loaded for real
+      if with_prefetch {
+        source.add(RawSource::from(
+          include_str!("runtime/jsonp_chunk_loading_with_prefetch.js")
+            .replace("JS_MATCHER", &js_matcher),
+        ));
+      }
+      if with_preload {
+        source.add(RawSource::from(
+          include_str!("runtime/jsonp_chunk_loading_with_preload.js")
+            .replace("JS_MATCHER", &js_matcher),
+        ));
+      }
+    }
+
     if self
       .runtime_requirements
       .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS)
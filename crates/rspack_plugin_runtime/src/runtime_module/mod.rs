@@ -1,6 +1,9 @@
 mod async_module;
 mod auto_public_path;
 mod base_uri;
+mod chunk_prefetch_preload_function;
+mod chunk_prefetch_preload_trigger;
+mod chunk_prefetch_startup;
 mod compat_get_default_export;
 mod create_fake_namespace_object;
 mod create_script_url;
@@ -35,6 +38,9 @@ mod utils;
 pub use async_module::AsyncRuntimeModule;
 pub use auto_public_path::AutoPublicPathRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
+pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
+pub use chunk_prefetch_preload_trigger::ChunkPrefetchPreloadTriggerRuntimeModule;
+pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
 pub use compat_get_default_export::CompatGetDefaultExportRuntimeModule;
 pub use create_fake_namespace_object::CreateFakeNamespaceObjectRuntimeModule;
 pub use create_script_url::CreateScriptUrlRuntimeModule;
@@ -0,0 +1,15 @@
+__webpack_require__.F.j = function (chunkId) {
+	// a chunk that is loading or loaded is not fetched a second time
+	if (
+		(!__webpack_require__.o(installedChunks, chunkId) ||
+			installedChunks[chunkId] === undefined) &&
+		JS_MATCHER
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		link.rel = "prefetch";
+		link.as = "script";
+		link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
+		document.head.appendChild(link);
+	}
+};
@@ -1,22 +1,23 @@
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
 use async_trait::async_trait;
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, ChunkLoading, JsChunkHashArgs, Plugin,
-  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
-  PublicPath, RuntimeGlobals, RuntimeModuleExt, SourceType,
+  AdditionalChunkRuntimeRequirementsArgs, ChunkGroupOrderKey, ChunkLoading, ChunkUkey, Compilation,
+  JsChunkHashArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext,
+  PluginJsChunkHashHookOutput, PublicPath, RuntimeGlobals, RuntimeModuleExt, SourceType,
 };
 
 use crate::runtime_module::{
   is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule, BaseUriRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule,
-  LoadChunkWithModuleRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
-  NodeModuleDecoratorRuntimeModule, NormalRuntimeModule, OnChunkLoadedRuntimeModule,
-  PublicPathRuntimeModule,
+  ChunkPrefetchPreloadFunctionRuntimeModule, ChunkPrefetchPreloadTriggerRuntimeModule,
+  ChunkPrefetchStartupRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadChunkWithModuleRuntimeModule, LoadScriptRuntimeModule,
+  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
 };
 
 #[derive(Debug)]
@@ -28,11 +29,75 @@ impl Plugin for RuntimePlugin {
     "RuntimePlugin"
   }
 
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(args.chunk)
+      == 0
+    {
+      return Ok(());
+    }
+    // `import(/* webpackPrefetch: true */ "./a")` in an entry chunk prefetches `./a` right
+    // after startup
+    let startup_chunks = get_startup_prefetch_chunks(args.chunk, compilation);
+    if !startup_chunks.is_empty() {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK | RuntimeGlobals::ON_CHUNKS_LOADED);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchStartupRuntimeModule::new(startup_chunks).boxed(),
+      );
+    }
+    Ok(())
+  }
+
   fn additional_tree_runtime_requirements(
     &self,
     _ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    for (order_key, runtime_function) in [
+      (ChunkGroupOrderKey::Prefetch, RuntimeGlobals::PREFETCH_CHUNK),
+      (ChunkGroupOrderKey::Preload, RuntimeGlobals::PRELOAD_CHUNK),
+    ] {
+      let chunk_map = args
+        .chunk()
+        .get_child_ids_by_orders_map(false, &order_key, args.compilation)
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+      if !chunk_map.is_empty() {
+        args.runtime_requirements.insert(runtime_function);
+        args.compilation.add_runtime_module(
+          args.chunk,
+          ChunkPrefetchPreloadTriggerRuntimeModule::new(order_key, chunk_map).boxed(),
+        );
+      }
+    }
+    // the handlers are required here as the chunk loading plugins only look at the runtime
+    // requirements once
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK)
+    {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    }
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK)
+    {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+    }
+
     let compilation = &args.compilation;
     let chunk = args.chunk();
     if args
@@ -247,6 +312,24 @@ impl Plugin for RuntimePlugin {
         RuntimeGlobals::NODE_MODULE_DECORATOR => {
           compilation.add_runtime_module(chunk, NodeModuleDecoratorRuntimeModule::default().boxed())
         }
+        RuntimeGlobals::PREFETCH_CHUNK => compilation.add_runtime_module(
+          chunk,
+          ChunkPrefetchPreloadFunctionRuntimeModule::new(
+            "prefetch",
+            RuntimeGlobals::PREFETCH_CHUNK,
+            RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+          )
+          .boxed(),
+        ),
+        RuntimeGlobals::PRELOAD_CHUNK => compilation.add_runtime_module(
+          chunk,
+          ChunkPrefetchPreloadFunctionRuntimeModule::new(
+            "preload",
+            RuntimeGlobals::PRELOAD_CHUNK,
+            RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+          )
+          .boxed(),
+        ),
         _ => {}
       }
     }
@@ -275,3 +358,49 @@ impl Plugin for RuntimePlugin {
     Ok(())
   }
 }
+
+/// The chunks of the entry chunk groups paired with the chunks they prefetch, entry chunks
+/// are the last chunk of their groups.
+fn get_startup_prefetch_chunks(
+  chunk_ukey: &ChunkUkey,
+  compilation: &Compilation,
+) -> Vec<(Vec<String>, Vec<String>)> {
+  let chunk_ids = |chunks: &[ChunkUkey]| {
+    chunks
+      .iter()
+      .filter_map(|ukey| compilation.chunk_by_ukey.get(ukey)?.id.clone())
+      .collect::<Vec<_>>()
+  };
+  let chunk = compilation
+    .chunk_by_ukey
+    .get(chunk_ukey)
+    .expect("should have chunk");
+  let mut startup_chunks = vec![];
+  for group in chunk
+    .groups
+    .iter()
+    .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+    .filter(|group| group.chunks.last() == Some(chunk_ukey))
+  {
+    for child_group in group
+      .get_children_by_order(
+        &ChunkGroupOrderKey::Prefetch,
+        &compilation.chunk_group_by_ukey,
+      )
+      .iter()
+      .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+    {
+      let child_chunks = child_group
+        .chunks
+        .iter()
+        .filter(|ukey| !group.chunks.contains(ukey))
+        .copied()
+        .collect::<Vec<_>>();
+      let child_chunk_ids = chunk_ids(&child_chunks);
+      if !child_chunk_ids.is_empty() {
+        startup_chunks.push((chunk_ids(&group.chunks), child_chunk_ids));
+      }
+    }
+  }
+  startup_chunks
+}
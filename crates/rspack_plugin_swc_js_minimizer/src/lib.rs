@@ -1,22 +1,21 @@
 mod minify;
 
-use std::{
-  collections::HashMap,
-  hash::Hash,
-  sync::{mpsc, Mutex},
-};
+use std::{collections::HashMap, hash::Hash};
 
 use async_recursion::async_recursion;
 use async_trait::async_trait;
-use minify::{match_object, minify};
+use minify::{match_object, minify, MAX_PASSES};
+pub use minify::{minify_no_io, MinifyTimings};
+use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
 use rspack_core::{
   rspack_sources::{
     MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, SourceMapSourceOptions,
   },
-  AssetInfo, CompilationAsset, JsChunkHashArgs, Plugin, PluginContext, PluginJsChunkHashHookOutput,
-  PluginProcessAssetsOutput, ProcessAssetsArgs,
+  AssetInfo, CompilationAsset, Filename, JsChunkHashArgs, PathData, Plugin, PluginContext,
+  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, ProcessAssetsArgs,
 };
-use rspack_error::{internal_error, Diagnostic};
+use rspack_error::{internal_error, Diagnostic, Result};
+use rspack_plugin_javascript::ExtractedCommentsInfo;
 use rspack_regex::RspackRegex;
 use rspack_util::try_any;
 use swc_config::config_types::BoolOrDataConfig;
@@ -25,19 +24,88 @@ use swc_ecma_minifier::option::{
   MangleOptions,
 };
 
-#[derive(Debug, Clone, Default, Hash)]
+#[derive(Debug, Clone, Default)]
 pub struct SwcJsMinimizerRspackPluginOptions {
   pub passes: usize,
   pub drop_console: bool,
+  /// `None` keeps the default of swc, which drops `debugger` statements
+  pub drop_debugger: Option<bool>,
   pub keep_class_names: bool,
   pub keep_fn_names: bool,
   pub pure_funcs: Vec<String>,
   pub extract_comments: Option<String>,
-  pub ascii_only: bool,
+  pub ascii_only: String,
+  /// Prints readable output without mangling, for debugging production builds
+  pub beautify: bool,
   pub comments: String,
   pub test: Option<SwcJsMinimizerRules>,
   pub include: Option<SwcJsMinimizerRules>,
   pub exclude: Option<SwcJsMinimizerRules>,
+  /// The source maps of the matched assets reference their sources without inlining the content
+  pub external_sources: Option<SwcJsMinimizerRules>,
+  /// Number of threads used to minify assets, defaults to the number of CPUs.
+  pub parallelism: Option<usize>,
+  /// Prepended to every minified chunk file, `[name]` and `[chunkhash]` are resolved per chunk.
+  pub banner: Option<String>,
+  /// Overrides the top level compression and mangling derived from `output.module`
+  pub top_level: Option<bool>,
+  /// Identifiers the mangler must never rename, in any scope
+  pub reserved: Vec<String>,
+  /// Source maps only inline the original sources of files up to this many bytes
+  pub inline_sources_content_max_bytes: Option<usize>,
+  /// The ecma version of the output, defaults to swc's
+  pub ecma: Option<usize>,
+  /// Lowers `a?.b` and `a ?? b` when `ecma` is below ES2020
+  pub downlevel: bool,
+}
+
+impl Hash for SwcJsMinimizerRspackPluginOptions {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    // `parallelism` is left out as it never affects the output
+    let Self {
+      passes,
+      drop_console,
+      drop_debugger,
+      keep_class_names,
+      keep_fn_names,
+      pure_funcs,
+      extract_comments,
+      ascii_only,
+      beautify,
+      comments,
+      test,
+      include,
+      exclude,
+      external_sources,
+      parallelism: _,
+      banner,
+      top_level,
+      reserved,
+      inline_sources_content_max_bytes,
+      ecma,
+      downlevel,
+    } = self;
+    passes.hash(state);
+    drop_console.hash(state);
+    drop_debugger.hash(state);
+    keep_class_names.hash(state);
+    keep_fn_names.hash(state);
+    pure_funcs.hash(state);
+    extract_comments.hash(state);
+    ascii_only.hash(state);
+    beautify.hash(state);
+    comments.hash(state);
+    test.hash(state);
+    include.hash(state);
+    exclude.hash(state);
+    external_sources.hash(state);
+    banner.hash(state);
+    top_level.hash(state);
+    reserved.hash(state);
+    inline_sources_content_max_bytes.hash(state);
+    ecma.hash(state);
+    downlevel.hash(state);
+  }
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -77,11 +145,19 @@ impl SwcJsMinimizerRules {
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: SwcJsMinimizerRspackPluginOptions,
+  /// Built once for `parallelism`, the global pool is used without it
+  pool: Option<rayon::ThreadPool>,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: SwcJsMinimizerRspackPluginOptions) -> Self {
-    Self { options }
+    let pool = options.parallelism.and_then(|parallelism| {
+      rayon::ThreadPoolBuilder::new()
+        .num_threads(parallelism)
+        .build()
+        .ok()
+    });
+    Self { options, pool }
   }
 }
 
@@ -105,14 +181,9 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
       .as_ref()
       .is_some_and(|library| library.library_type == "module");
 
-    let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
-    // collect all extracted comments info
-    let all_extracted_comments = Mutex::new(HashMap::new());
     let extract_comments_option = &minify_options.extract_comments.clone();
     let emit_source_map_columns = !compilation.options.devtool.cheap();
     let compress = TerserCompressorOptions {
-      passes: minify_options.passes,
-      drop_console: minify_options.drop_console,
       pure_funcs: minify_options.pure_funcs.clone(),
       ..Default::default()
     };
@@ -130,13 +201,23 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
       _ => JsMinifyCommentOption::False,
     };
 
+    let ascii_only = match minify_options.ascii_only.as_str() {
+      "true" => JsMinifyAsciiOnlyOption::True,
+      "smart" => JsMinifyAsciiOnlyOption::Smart,
+      _ => JsMinifyAsciiOnlyOption::False,
+    };
+
     let format = JsMinifyFormatOptions {
-      ascii_only: minify_options.ascii_only,
+      ascii_only,
+      beautify: minify_options.beautify,
       comments,
       ..Default::default()
     };
 
-    for (filename, original) in compilation.assets_mut() {
+    // matching is async, so pick the assets to minify before going parallel
+    // matched filename -> whether the sources are inlined into its source map
+    let mut matched_filenames = HashMap::new();
+    for (filename, original) in compilation.assets() {
       if !(filename.ends_with(".js") || filename.ends_with(".cjs") || filename.ends_with(".mjs")) {
         continue;
       }
@@ -145,78 +226,146 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
         .await
         .unwrap_or(false);
 
-      if !is_matched || original.get_info().minimized {
-        continue;
-      }
-
-      if let Some(original_source) = original.get_source() {
-        let input = original_source.source().to_string();
-        let input_source_map = original_source.map(&MapOptions::default());
-        let js_minify_options = JsMinifyOptions {
-          compress: BoolOrDataConfig::from_obj(compress.clone()),
-          mangle: BoolOrDataConfig::from_obj(mangle.clone()),
-          format: format.clone(),
-          source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
-          inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
-          emit_source_map_columns,
-          module: is_module,
-          ..Default::default()
+      if is_matched && !original.get_info().minimized {
+        let external_sources = match &minify_options.external_sources {
+          Some(condition) => condition.try_match(filename).await.unwrap_or(false),
+          None => false,
         };
+        matched_filenames.insert(filename.clone(), !external_sources);
+      }
+    }
 
-        let output = match minify(
-          &js_minify_options,
-          input,
-          filename,
-          &all_extracted_comments,
-          extract_comments_option,
-        ) {
-          Ok(r) => r,
-          Err(e) => {
-            tx.send(e.into())
-              .map_err(|e| internal_error!(e.to_string()))?;
-            continue;
+    // placeholders are resolved against the chunk that emitted the file
+    let mut banners = HashMap::new();
+    if let Some(banner) = &minify_options.banner {
+      for chunk in compilation.chunk_by_ukey.values() {
+        for file in &chunk.files {
+          if matched_filenames.contains_key(file) {
+            let banner = compilation.get_path(
+              &Filename::from(banner.clone()),
+              PathData::default().chunk(chunk),
+            );
+            banners.insert(file.clone(), banner);
           }
-        };
-        let source = if let Some(map) = &output.map {
-          SourceMapSource::new(SourceMapSourceOptions {
-            value: output.code,
-            name: filename,
-            source_map: SourceMap::from_json(map).map_err(|e| internal_error!(e.to_string()))?,
-            original_source: None,
-            inner_source_map: input_source_map,
-            remove_original_source: true,
-          })
-          .boxed()
-        } else {
-          RawSource::from(output.code).boxed()
-        };
-        original.set_source(Some(source));
-        original.get_info_mut().minimized = true;
+        }
       }
     }
 
-    drop(tx);
-
-    compilation.push_batch_diagnostic(rx.into_iter().flatten().collect::<Vec<_>>());
-
-    // write all extracted comments to assets
-    all_extracted_comments
-      .lock()
-      .expect("all_extracted_comments lock failed")
-      .clone()
-      .into_iter()
-      .for_each(|(_, comments)| {
-        compilation.emit_asset(
-          comments.comments_file_name,
-          CompilationAsset {
-            source: Some(comments.source),
-            info: AssetInfo {
-              minimized: true,
-              ..Default::default()
-            },
+    let minify_assets = || {
+      compilation
+        .assets_mut()
+        .par_iter_mut()
+        .filter_map(|(filename, original)| {
+          let inline_sources_content = *matched_filenames.get(filename.as_str())?;
+          Some((filename, original, inline_sources_content))
+        })
+        .map(|(filename, original, inline_sources_content)| -> Result<MinifyAssetResult> {
+          let Some(original_source) = original.get_source() else {
+            return Ok(MinifyAssetResult::default());
+          };
+          let input = original_source.source();
+          let input_source_map = original_source.map(&MapOptions::default()).map(|mut map| {
+            // the content of the original sources ends up in the composed map through the input
+            // map, the sources are kept as is and resolved by the devtool plugin later
+            if !inline_sources_content {
+              for content in map.sources_content_mut() {
+                *content = String::default();
+              }
+            }
+            map
+          });
+          let js_minify_options = JsMinifyOptions {
+            compress: BoolOrDataConfig::from_obj(compress.clone()),
+            mangle: BoolOrDataConfig::from_obj(mangle.clone()),
+            format: format.clone(),
+            source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
+            inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
+            emit_source_map_columns,
+            module: is_module,
+            drop_console: Some(minify_options.drop_console),
+            drop_debugger: minify_options.drop_debugger,
+            passes: Some(minify_options.passes),
+            banner: banners.get(filename.as_str()).cloned(),
+            top_level: minify_options.top_level,
+            reserved: minify_options.reserved.clone(),
+            inline_sources_content_max_bytes: minify_options.inline_sources_content_max_bytes,
+            ecma: minify_options.ecma.map(TerserEcmaVersion::Num).unwrap_or_default(),
+            downlevel: minify_options.downlevel,
+            ..Default::default()
+          };
+
+          let (output, extracted_comments) =
+            match minify(&js_minify_options, input, filename, extract_comments_option) {
+              Ok(r) => r,
+              Err(e) => {
+                return Ok(MinifyAssetResult {
+                  diagnostics: e.into(),
+                  extracted_comments: None,
+                })
+              }
+            };
+          let source = if let Some(map) = &output.map {
+            SourceMapSource::new(SourceMapSourceOptions {
+              value: output.code,
+              name: filename,
+              source_map: SourceMap::from_json(map).map_err(|e| internal_error!(e.to_string()))?,
+              original_source: None,
+              inner_source_map: input_source_map,
+              remove_original_source: true,
+            })
+            .boxed()
+          } else {
+            RawSource::from(output.code).boxed()
+          };
+          original.set_source(Some(source));
+          original.get_info_mut().minimized = true;
+          Ok(MinifyAssetResult {
+            diagnostics: vec![],
+            extracted_comments,
+          })
+        })
+        .collect::<Result<Vec<_>>>()
+    };
+    let results = match &self.pool {
+      Some(pool) => pool.install(minify_assets),
+      None => minify_assets(),
+    }?;
+
+    let mut diagnostics = vec![];
+    if minify_options.passes > MAX_PASSES {
+      diagnostics.push(Diagnostic::warn(
+        "Minify Warning".to_string(),
+        format!(
+          "`passes` is {}, only {MAX_PASSES} passes will be run",
+          minify_options.passes
+        ),
+        0,
+        0,
+      ));
+    }
+    // collect all extracted comments info
+    let mut all_extracted_comments = vec![];
+    for result in results {
+      diagnostics.extend(result.diagnostics);
+      all_extracted_comments.extend(result.extracted_comments);
+    }
+
+    compilation.push_batch_diagnostic(diagnostics);
+
+    // write all extracted comments to assets, sorted so the emit order doesn't depend on threads
+    all_extracted_comments.sort_by(|a, b| a.comments_file_name.cmp(&b.comments_file_name));
+    all_extracted_comments.into_iter().for_each(|comments| {
+      compilation.emit_asset(
+        comments.comments_file_name,
+        CompilationAsset {
+          source: Some(comments.source),
+          info: AssetInfo {
+            minimized: true,
+            ..Default::default()
           },
-        )
-      });
+        },
+      )
+    });
 
     Ok(())
   }
@@ -232,6 +381,21 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
   }
 }
 
+#[derive(Default)]
+struct MinifyAssetResult {
+  diagnostics: Vec<Diagnostic>,
+  extracted_comments: Option<ExtractedCommentsInfo>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum JsMinifyAsciiOnlyOption {
+  #[default]
+  False,
+  True,
+  /// Only escape files that still contain non-ASCII identifiers after minification
+  Smart,
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum JsMinifyCommentOption {
   #[default]
@@ -246,15 +410,33 @@ pub struct JsMinifyOptions {
   pub mangle: BoolOrDataConfig<MangleOptions>,
   pub format: JsMinifyFormatOptions,
   pub ecma: TerserEcmaVersion,
+  /// Lowers `a?.b` and `a ?? b` when `ecma` is below ES2020, the syntax is kept as is otherwise
+  pub downlevel: bool,
   pub keep_class_names: bool,
   pub keep_fn_names: bool,
   pub module: bool,
   pub safari10: bool,
-  pub toplevel: bool,
+  /// Overrides the top level compression and mangling derived from `module`
+  pub top_level: Option<bool>,
+  /// Identifiers the mangler must never rename, in any scope
+  pub reserved: Vec<String>,
   pub source_map: BoolOrDataConfig<TerserSourceMapOption>,
+  /// The source map of the input, composed with the generated one so the output maps back to
+  /// the original sources
+  pub input_source_map: Option<String>,
   pub output_path: Option<String>,
   pub inline_sources_content: bool,
+  /// Sources larger than this are not inlined into `sourcesContent`, no limit when `None`
+  pub inline_sources_content_max_bytes: Option<usize>,
   pub emit_source_map_columns: bool,
+  /// Removes all `console.*` calls, overrides the one in `compress`
+  pub drop_console: Option<bool>,
+  /// Removes `debugger` statements, overrides the one in `compress` which defaults to `true`
+  pub drop_debugger: Option<bool>,
+  /// Number of compress passes, overrides the one in `compress`, clamped to `MAX_PASSES`
+  pub passes: Option<usize>,
+  /// Prepended to the output, the source map is shifted down by the lines it takes
+  pub banner: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -267,7 +449,8 @@ pub struct TerserSourceMapOption {
 
 #[derive(Debug, Clone, Default)]
 pub struct JsMinifyFormatOptions {
-  pub ascii_only: bool,
+  pub ascii_only: JsMinifyAsciiOnlyOption,
+  /// Indents the output and turns mangling off, comments are still handled by `comments`
   pub beautify: bool,
   pub braces: bool,
   pub comments: JsMinifyCommentOption,
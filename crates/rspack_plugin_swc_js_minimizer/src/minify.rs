@@ -1,6 +1,7 @@
 use std::{
-  collections::HashMap,
-  sync::{mpsc, Arc, Mutex},
+  borrow::Cow,
+  sync::{mpsc, Arc},
+  time::{Duration, Instant},
 };
 
 use async_recursion::async_recursion;
@@ -24,14 +25,17 @@ use swc_core::{
     BytePos, FileName, Mark, SourceMap, GLOBALS,
   },
   ecma::{
-    ast::Ident,
+    ast::{EsVersion, Expr, ExprStmt, Ident, Lit, ModuleItem, Program, Stmt},
     atoms::JsWord,
     parser::{EsConfig, Syntax},
-    transforms::base::{
-      fixer::fixer,
-      helpers::{self, Helpers},
-      hygiene::hygiene,
-      resolver,
+    transforms::{
+      base::{
+        fixer::fixer,
+        helpers::{self, Helpers},
+        hygiene::hygiene,
+        resolver,
+      },
+      compat,
     },
     visit::{noop_visit_type, FoldWith, Visit, VisitMutWith, VisitWith},
   },
@@ -41,7 +45,13 @@ use swc_ecma_minifier::{
   option::{MinifyOptions, TopLevelOptions},
 };
 
-use crate::{JsMinifyCommentOption, JsMinifyOptions, SwcJsMinimizerRspackPluginOptions};
+use crate::{
+  JsMinifyAsciiOnlyOption, JsMinifyCommentOption, JsMinifyOptions,
+  SwcJsMinimizerRspackPluginOptions,
+};
+
+/// Upper bound for `compress.passes`, further passes rarely shrink the output
+pub const MAX_PASSES: usize = 10;
 
 #[async_recursion]
 pub async fn match_object(obj: &SwcJsMinimizerRspackPluginOptions, str: &str) -> Result<bool> {
@@ -97,22 +107,67 @@ pub(crate) fn minify_file_comments(
   }
 }
 
+/// Wall time spent in each phase of [minify_no_io]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MinifyTimings {
+  pub parse: Duration,
+  pub resolver: Duration,
+  pub optimize: Duration,
+  /// Zero when the mangler is enabled, which renames the identifiers itself
+  pub hygiene: Duration,
+  pub fixer: Duration,
+  pub print: Duration,
+}
+
 pub fn minify(
   opts: &JsMinifyOptions,
-  input: String,
+  input: Cow<'_, str>,
+  filename: &str,
+  extract_comments: &Option<String>,
+) -> Result<(TransformOutput, Option<ExtractedCommentsInfo>)> {
+  minify_with_timings(
+    opts,
+    input,
+    filename,
+    extract_comments,
+    &mut MinifyTimings::default(),
+  )
+}
+
+/// Minifies without extracting comments, and reports the time spent in each phase for
+/// benchmarking.
+pub fn minify_no_io(
+  opts: &JsMinifyOptions,
+  input: Cow<'_, str>,
+  filename: &str,
+) -> Result<(TransformOutput, MinifyTimings)> {
+  let mut timings = MinifyTimings::default();
+  let (output, _) = minify_with_timings(opts, input, filename, &None, &mut timings)?;
+  Ok((output, timings))
+}
+
+fn minify_with_timings(
+  opts: &JsMinifyOptions,
+  input: Cow<'_, str>,
   filename: &str,
-  all_extract_comments: &Mutex<HashMap<String, ExtractedCommentsInfo>>,
   extract_comments: &Option<String>,
-) -> Result<TransformOutput> {
+  timings: &mut MinifyTimings,
+) -> Result<(TransformOutput, Option<ExtractedCommentsInfo>)> {
   let cm: Arc<SourceMap> = Default::default();
-  GLOBALS.set(&Default::default(), || -> Result<TransformOutput> {
+  GLOBALS.set(&Default::default(), || {
     with_rspack_error_handler(
       "Minify Error".to_string(),
       DiagnosticKind::JavaScript,
       cm.clone(),
       |handler| {
-        let fm = cm.new_source_file(FileName::Custom(filename.to_string()), input);
-        let target = opts.ecma.clone().into();
+        // large sources are left out of `sourcesContent` to keep the map small
+        let inline_sources_content = opts.inline_sources_content
+          && opts
+            .inline_sources_content_max_bytes
+            .map_or(true, |max_bytes| input.len() <= max_bytes);
+        // an owned input is moved into the source file instead of being copied
+        let fm = cm.new_source_file(FileName::Custom(filename.to_string()), input.into_owned());
+        let target: EsVersion = opts.ecma.clone().into();
 
         let source_map = opts
           .source_map
@@ -145,10 +200,44 @@ pub fn minify(
           ..Default::default()
         };
 
+        if let Some(compress) = &mut min_opts.compress {
+          if let Some(drop_console) = opts.drop_console {
+            compress.drop_console = drop_console;
+          }
+          if let Some(drop_debugger) = opts.drop_debugger {
+            compress.drop_debugger = drop_debugger;
+          }
+          if let Some(passes) = opts.passes {
+            compress.passes = passes.min(MAX_PASSES);
+          }
+        }
+
+        // readable output keeps the original names
+        if opts.format.beautify {
+          min_opts.mangle = None;
+        }
+
+        if let Some(mangle) = &mut min_opts.mangle {
+          mangle
+            .reserved
+            .extend(opts.reserved.iter().map(|name| JsWord::from(name.as_str())));
+        }
+
         // top_level defaults to true if module is true
 
         // https://github.com/swc-project/swc/issues/2254
-        if opts.module {
+        if let Some(top_level) = opts.top_level {
+          // an explicit `top_level` wins over the module-derived default
+          if let Some(opts) = &mut min_opts.compress {
+            opts.top_level = Some(TopLevelOptions {
+              functions: top_level,
+            });
+          }
+
+          if let Some(opts) = &mut min_opts.mangle {
+            opts.top_level = Some(top_level);
+          }
+        } else if opts.module {
           if let Some(opts) = &mut min_opts.compress {
             if opts.top_level.is_none() {
               opts.top_level = Some(TopLevelOptions { functions: true });
@@ -160,8 +249,17 @@ pub fn minify(
           }
         }
 
+        let input_source_map = match &opts.input_source_map {
+          Some(map) if source_map.enabled() => Some(
+            sourcemap::SourceMap::from_slice(map.as_bytes())
+              .map_err(|e| internal_error!(e.to_string()))?,
+          ),
+          _ => None,
+        };
+
         let comments = SingleThreadedComments::default();
 
+        let start = Instant::now();
         let program = parse_js(
           fm.clone(),
           target,
@@ -182,6 +280,7 @@ pub fn minify(
               .collect::<Vec<_>>(),
           )
         })?;
+        timings.parse = start.elapsed();
 
         let source_map_names = if source_map.enabled() {
           let mut v = IdentCollector {
@@ -195,6 +294,8 @@ pub fn minify(
           Default::default()
         };
 
+        let directives = leading_directives(&program);
+
         let unresolved_mark = Mark::new();
         let top_level_mark = Mark::new();
 
@@ -202,8 +303,21 @@ pub fn minify(
 
         let program = helpers::HELPERS.set(&Helpers::new(false), || {
           HANDLER.set(handler, || {
-            let program = program.fold_with(&mut resolver(unresolved_mark, top_level_mark, false));
+            let mut program = program;
+            let start = Instant::now();
+            program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+            timings.resolver = start.elapsed();
+
+            // the minifier keeps the syntax as is, `a?.b` and `a ?? b` are lowered for the
+            // targets without them on request
+            if opts.downlevel && target < EsVersion::Es2020 {
+              program = program.fold_with(&mut compat::es2020::es2020(compat::es2020::Config {
+                nullish_coalescing: Default::default(),
+                optional_chaining: Default::default(),
+              }));
+            }
 
+            let start = Instant::now();
             let mut program = swc_ecma_minifier::optimize(
               program,
               cm.clone(),
@@ -215,14 +329,22 @@ pub fn minify(
                 top_level_mark,
               },
             );
+            timings.optimize = start.elapsed();
 
             if !is_mangler_enabled {
-              program.visit_mut_with(&mut hygiene())
+              let start = Instant::now();
+              program.visit_mut_with(&mut hygiene());
+              timings.hygiene = start.elapsed();
             }
-            program.fold_with(&mut fixer(Some(&comments as &dyn Comments)))
+            let start = Instant::now();
+            program.visit_mut_with(&mut fixer(Some(&comments as &dyn Comments)));
+            timings.fixer = start.elapsed();
+            hoist_directives(&mut program, directives);
+            program
           })
         });
 
+        let mut extracted_comments_info = None;
         if let Some(extract_comments) = extract_comments {
           let comments_file_name = filename.to_string() + ".LICENSE.txt";
           let reg = if extract_comments.eq("true") {
@@ -268,40 +390,137 @@ pub fn minify(
 
           // if not matched comments, we don't need to emit .License.txt file
           if !extracted_comments.is_empty() {
-            all_extract_comments
-              .lock()
-              .expect("all_extract_comments lock failed")
-              .insert(
-                filename.to_string(),
-                ExtractedCommentsInfo {
-                  source: RawSource::Source(extracted_comments.join("\n\n")).boxed(),
-                  comments_file_name,
-                },
-              );
+            extracted_comments_info = Some(ExtractedCommentsInfo {
+              source: RawSource::Source(extracted_comments.join("\n\n")).boxed(),
+              comments_file_name,
+            });
           }
         }
 
         minify_file_comments(&comments, opts.format.comments.to_owned());
 
-        print(
+        let ascii_only = match opts.format.ascii_only {
+          JsMinifyAsciiOnlyOption::False => false,
+          JsMinifyAsciiOnlyOption::True => true,
+          JsMinifyAsciiOnlyOption::Smart => {
+            let mut finder = NonAsciiIdentFinder::default();
+            program.visit_with(&mut finder);
+            finder.found
+          }
+        };
+
+        let start = Instant::now();
+        let output = print(
           &program,
           cm.clone(),
           target,
           SourceMapConfig {
             enable: source_map.enabled(),
-            inline_sources_content: opts.inline_sources_content,
+            inline_sources_content,
             emit_columns: opts.emit_source_map_columns,
             names: source_map_names,
           },
-          true,
+          input_source_map.as_ref(),
+          !opts.format.beautify,
           Some(&comments),
-          opts.format.ascii_only,
-        )
+          ascii_only,
+        );
+        timings.print = start.elapsed();
+        output
+          .and_then(|output| match &opts.banner {
+            Some(banner) => prepend_banner(output, banner),
+            None => Ok(output),
+          })
+          .map(|output| (output, extracted_comments_info))
       },
     )
   })
 }
 
+/// Puts `banner` on its own lines above `output.code`, every generated line in the
+/// source map moves down by the number of lines the banner takes.
+fn prepend_banner(output: TransformOutput, banner: &str) -> Result<TransformOutput> {
+  let TransformOutput { code, map } = output;
+  let code = format!("{banner}\n{code}");
+  let map = map
+    .map(|map| -> Result<String> {
+      let mut map: serde_json::Value =
+        serde_json::from_str(&map).map_err(|e| internal_error!(e.to_string()))?;
+      if let Some(mappings) = map.get_mut("mappings") {
+        let shifted =
+          ";".repeat(banner.matches('\n').count() + 1) + mappings.as_str().unwrap_or_default();
+        *mappings = serde_json::Value::String(shifted);
+      }
+      serde_json::to_string(&map).map_err(|e| internal_error!(e.to_string()))
+    })
+    .transpose()?;
+  Ok(TransformOutput { code, map })
+}
+
+/// Directives that frameworks read from the top of a file, e.g. for React Server Components
+const PRESERVED_DIRECTIVES: [&str; 3] = ["use client", "use server", "use strict"];
+
+fn directive_value(stmt: &Stmt) -> Option<&JsWord> {
+  match stmt {
+    Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+      Expr::Lit(Lit::Str(str)) => Some(&str.value),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn is_preserved_directive(stmt: &Stmt) -> bool {
+  directive_value(stmt).is_some_and(|value| PRESERVED_DIRECTIVES.contains(&&**value))
+}
+
+/// Collects the preserved directives from the prologue, i.e. the string literal
+/// statements the program starts with.
+fn leading_directives(program: &Program) -> Vec<Stmt> {
+  let stmts: Box<dyn Iterator<Item = &Stmt>> = match program {
+    Program::Module(module) => Box::new(module.body.iter().map_while(ModuleItem::as_stmt)),
+    Program::Script(script) => Box::new(script.body.iter()),
+  };
+  stmts
+    .take_while(|stmt| directive_value(stmt).is_some())
+    .filter(|stmt| is_preserved_directive(stmt))
+    .cloned()
+    .collect()
+}
+
+/// The minifier is free to drop or move string literal statements, so the directives
+/// are taken out wherever they ended up and put back at the very top.
+fn hoist_directives(program: &mut Program, directives: Vec<Stmt>) {
+  if directives.is_empty() {
+    return;
+  }
+  match program {
+    Program::Module(module) => {
+      let body = std::mem::take(&mut module.body);
+      module.body = directives
+        .into_iter()
+        .map(ModuleItem::Stmt)
+        .chain(body.into_iter().filter(|item| {
+          item
+            .as_stmt()
+            .map_or(true, |stmt| !is_preserved_directive(stmt))
+        }))
+        .collect();
+    }
+    Program::Script(script) => {
+      let body = std::mem::take(&mut script.body);
+      script.body = directives
+        .into_iter()
+        .chain(
+          body
+            .into_iter()
+            .filter(|stmt| !is_preserved_directive(stmt)),
+        )
+        .collect();
+    }
+  }
+}
+
 pub struct IdentCollector {
   names: AHashMap<BytePos, JsWord>,
 }
@@ -314,6 +533,21 @@ impl Visit for IdentCollector {
   }
 }
 
+#[derive(Default)]
+struct NonAsciiIdentFinder {
+  found: bool,
+}
+
+impl Visit for NonAsciiIdentFinder {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if !ident.sym.is_ascii() {
+      self.found = true;
+    }
+  }
+}
+
 // keep this private to make sure with_rspack_error_handler is safety
 struct RspackErrorEmitter {
   tx: mpsc::Sender<rspack_error::Error>,
@@ -378,3 +612,316 @@ where
     ret
   }
 }
+
+#[cfg(test)]
+mod test {
+  use swc_config::config_types::BoolOrDataConfig;
+  use swc_ecma_minifier::option::terser::TerserEcmaVersion;
+
+  use super::*;
+  use crate::JsMinifyFormatOptions;
+
+  #[test]
+  fn parse_error_points_at_the_offending_source() {
+    let input = "console.log(1);\nvar a = ;\n".to_string();
+    let Err(Error::BatchErrors(errors)) =
+      minify(&JsMinifyOptions::default(), input.into(), "main.js", &None)
+    else {
+      panic!("should fail to parse");
+    };
+    let Some(Error::TraceableError(error)) = errors.first() else {
+      panic!("should be a traceable error");
+    };
+    assert_eq!(error.file_path, "main.js");
+    assert_eq!(&error.file_src[error.start..], ";\n");
+    assert!(error.end >= error.start && error.end <= error.file_src.len());
+  }
+
+  #[test]
+  fn input_source_map_is_composed() {
+    // `a.ts` compiled to js, every line of the js comes from two lines further down in `a.ts`
+    let input = "var answer = 42;\nconsole.log(answer);\n".to_string();
+    let mut builder = sourcemap::SourceMapBuilder::new(None);
+    builder.add(0, 0, 2, 0, Some("a.ts"), None);
+    builder.add(1, 0, 3, 0, Some("a.ts"), None);
+    let mut input_source_map = vec![];
+    builder
+      .into_sourcemap()
+      .to_writer(&mut input_source_map)
+      .expect("should write map");
+    let opts = JsMinifyOptions {
+      compress: BoolOrDataConfig::from_bool(false),
+      mangle: BoolOrDataConfig::from_bool(false),
+      source_map: BoolOrDataConfig::from_bool(true),
+      input_source_map: Some(String::from_utf8(input_source_map).expect("should be utf8")),
+      ..Default::default()
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    let map = sourcemap::SourceMap::from_slice(output.map.expect("should have map").as_bytes())
+      .expect("should parse map");
+    assert_eq!(map.sources().collect::<Vec<_>>(), vec!["a.ts"]);
+    let console_col = output.code.find("console").expect("should have console") as u32;
+    let token = map
+      .lookup_token(0, console_col)
+      .expect("should map console");
+    assert_eq!(token.get_source(), Some("a.ts"));
+    assert_eq!(token.get_src_line(), 3);
+  }
+
+  #[test]
+  fn minify_no_io_reports_the_phases() {
+    let input = "function add(first, second) { return first + second; } add(1, 2);".to_string();
+    let (output, timings) =
+      minify_no_io(&JsMinifyOptions::default(), input.into(), "main.js").expect("should minify");
+    assert!(!output.code.contains("second"));
+    // the mangler renames the identifiers, the hygiene pass is skipped
+    assert_eq!(timings.hygiene, Duration::ZERO);
+
+    let opts = JsMinifyOptions {
+      mangle: BoolOrDataConfig::from_bool(false),
+      ..Default::default()
+    };
+    let input = "console.log(1);".to_string();
+    let (output, _) = minify_no_io(&opts, input.into(), "main.js").expect("should minify");
+    assert!(output.code.contains("console.log(1)"));
+  }
+
+  #[test]
+  fn optional_chaining_is_lowered_below_es2020() {
+    let input = "console.log(a?.b, a ?? b);".to_string();
+    let opts = JsMinifyOptions {
+      ecma: TerserEcmaVersion::Num(2015),
+      downlevel: true,
+      ..Default::default()
+    };
+    let (output, _) =
+      minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+    assert!(!output.code.contains("?."));
+    assert!(!output.code.contains("??"));
+
+    let opts = JsMinifyOptions {
+      ecma: TerserEcmaVersion::Num(2020),
+      ..opts
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(output.code.contains("a?.b"));
+    assert!(output.code.contains("a??b"));
+  }
+
+  #[test]
+  fn beautify_prints_readable_output() {
+    let input =
+      "/*! license */\nfunction add(first, second) { return first + second; }\nconsole.log(add(1, 2));"
+        .to_string();
+    let opts = JsMinifyOptions {
+      compress: BoolOrDataConfig::from_bool(false),
+      format: JsMinifyFormatOptions {
+        beautify: true,
+        comments: JsMinifyCommentOption::PreserveSomeComments,
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(output.code.contains("function add(first, second) {\n"));
+    assert!(output.code.contains("    return first + second;\n"));
+    assert!(output.code.contains("/*! license */"));
+  }
+
+  #[test]
+  fn top_level_mangles_non_module_input() {
+    let input = "var longTopLevelName = 1; console.log(longTopLevelName);".to_string();
+    let opts = JsMinifyOptions {
+      compress: BoolOrDataConfig::from_bool(false),
+      mangle: BoolOrDataConfig::from_bool(true),
+      top_level: Some(true),
+      ..Default::default()
+    };
+    let (output, _) =
+      minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+    assert!(!output.code.contains("longTopLevelName"));
+
+    let opts = JsMinifyOptions {
+      top_level: None,
+      ..opts
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(output.code.contains("longTopLevelName"));
+  }
+
+  #[test]
+  fn inline_sources_content_by_size() {
+    let input = "console.log('hello');".to_string();
+    let opts = JsMinifyOptions {
+      source_map: BoolOrDataConfig::from_bool(true),
+      inline_sources_content: true,
+      inline_sources_content_max_bytes: Some(input.len()),
+      ..Default::default()
+    };
+    let (output, _) =
+      minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+    assert!(output
+      .map
+      .expect("should have map")
+      .contains("sourcesContent"));
+
+    let opts = JsMinifyOptions {
+      inline_sources_content_max_bytes: Some(input.len() - 1),
+      ..opts
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    let map = output.map.expect("should have map");
+    assert!(map.contains("\"sources\":[\"main.js\"]"));
+    assert!(!map.contains("hello"));
+  }
+
+  #[test]
+  fn reserved_names_survive_mangling() {
+    let input = r#"
+      var __KEEP__ = 1;
+      function run(longParamName) {
+        var __KEEP__ = longParamName;
+        var longLocalName = globalThis["__KEEP__"];
+        return function inner(anotherLongName) {
+          return anotherLongName + __KEEP__ + longLocalName;
+        };
+      }
+      console.log(run(__KEEP__));
+    "#
+    .to_string();
+    let opts = JsMinifyOptions {
+      compress: BoolOrDataConfig::from_bool(false),
+      mangle: BoolOrDataConfig::from_bool(true),
+      top_level: Some(true),
+      reserved: vec!["__KEEP__".to_string()],
+      ..Default::default()
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(output.code.contains("var __KEEP__=1"));
+    assert!(output.code.contains("var __KEEP__="));
+    assert!(!output.code.contains("longParamName"));
+    assert!(!output.code.contains("longLocalName"));
+    assert!(!output.code.contains("anotherLongName"));
+  }
+
+  #[test]
+  fn drop_console_and_drop_debugger_toggle_independently() {
+    let input = r#"
+      console.log("statement");
+      debugger;
+      globalThis.answer = 1 + console.log("expression");
+    "#
+    .to_string();
+    let opts = JsMinifyOptions {
+      mangle: BoolOrDataConfig::from_bool(false),
+      drop_console: Some(true),
+      drop_debugger: Some(false),
+      ..Default::default()
+    };
+    let (output, _) =
+      minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+    assert!(!output.code.contains("console"));
+    assert!(output.code.contains("debugger"));
+    // the call inside a larger expression is dropped, the expression itself is kept
+    assert!(output.code.contains("globalThis.answer"));
+
+    let opts = JsMinifyOptions {
+      drop_console: Some(false),
+      drop_debugger: None,
+      ..opts
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(output.code.contains("console.log(\"statement\")"));
+    assert!(output.code.contains("console.log(\"expression\")"));
+    assert!(!output.code.contains("debugger"));
+  }
+
+  #[test]
+  fn more_passes_never_grow_the_output() {
+    let input = r#"
+      function outer() {
+        var a = 1;
+        var b = a + 2;
+        function inner() {
+          var c = b * 3;
+          return c + a;
+        }
+        return inner();
+      }
+      globalThis.answer = outer();
+    "#
+    .to_string();
+    let minify_with_passes = |passes| {
+      let opts = JsMinifyOptions {
+        mangle: BoolOrDataConfig::from_bool(false),
+        passes: Some(passes),
+        ..Default::default()
+      };
+      let (output, _) =
+        minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+      output.code
+    };
+    let one_pass = minify_with_passes(1);
+    let three_passes = minify_with_passes(3);
+    assert!(three_passes.len() <= one_pass.len());
+    // passes above the limit behave like the limit itself
+    assert_eq!(
+      minify_with_passes(MAX_PASSES + 5),
+      minify_with_passes(MAX_PASSES)
+    );
+  }
+
+  #[test]
+  fn multi_line_banner_shifts_every_mapping() {
+    let input =
+      "function add(first, second) {\n  return first + second;\n}\nconsole.log(add(1, 2));\n"
+        .to_string();
+    let opts = JsMinifyOptions {
+      source_map: BoolOrDataConfig::from_bool(true),
+      ..Default::default()
+    };
+    let (plain, _) = minify(&opts, input.as_str().into(), "main.js", &None).expect("should minify");
+    let opts = JsMinifyOptions {
+      banner: Some("/*!\n * banner\n */".to_string()),
+      ..opts
+    };
+    let (bannered, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert_eq!(
+      bannered.code,
+      format!("/*!\n * banner\n */\n{}", plain.code)
+    );
+
+    let mappings = |map: &Option<String>| {
+      let map: serde_json::Value =
+        serde_json::from_str(map.as_deref().expect("should have map")).expect("valid map");
+      map["mappings"]
+        .as_str()
+        .expect("should have mappings")
+        .to_string()
+    };
+    let plain_mappings = mappings(&plain.map);
+    assert!(!plain_mappings.is_empty());
+    assert_eq!(mappings(&bannered.map), format!(";;;{plain_mappings}"));
+  }
+
+  #[test]
+  fn use_client_stays_the_first_statement() {
+    let input = r#""use client";
+      import { useState } from "react";
+      export function Counter() {
+        return useState(0);
+      }
+    "#
+    .to_string();
+    let opts = JsMinifyOptions {
+      module: true,
+      ..Default::default()
+    };
+    let (output, _) = minify(&opts, input.into(), "main.js", &None).expect("should minify");
+    assert!(
+      output.code.starts_with(r#""use client";import"#),
+      "{}",
+      output.code
+    );
+  }
+}
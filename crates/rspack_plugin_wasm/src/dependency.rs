@@ -3,9 +3,45 @@ use rspack_core::{
   ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
 };
 use swc_core::ecma::atoms::JsWord;
+use wasmparser::{TypeRef, ValType};
 
 use crate::WasmNode;
 
+/// What a WASM module imports, which decides how the value is passed in the imports object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmImportKind {
+  Func,
+  Table,
+  Memory,
+  Global {
+    /// The `WebAssembly.Global` value type, `None` for types JS can't construct
+    value_type: Option<&'static str>,
+    mutable: bool,
+  },
+  Tag,
+}
+
+impl From<&WasmNode> for WasmImportKind {
+  fn from(node: &WasmNode) -> Self {
+    match node {
+      TypeRef::Func(_) => Self::Func,
+      TypeRef::Table(_) => Self::Table,
+      TypeRef::Memory(_) => Self::Memory,
+      TypeRef::Global(global) => Self::Global {
+        value_type: match global.content_type {
+          ValType::I32 => Some("i32"),
+          ValType::I64 => Some("i64"),
+          ValType::F32 => Some("f32"),
+          ValType::F64 => Some("f64"),
+          _ => None,
+        },
+        mutable: global.mutable,
+      },
+      TypeRef::Tag(_) => Self::Tag,
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct WasmImportDependency {
   id: DependencyId,
@@ -32,6 +68,10 @@ impl WasmImportDependency {
   pub fn name(&self) -> &str {
     &self.name
   }
+
+  pub fn kind(&self) -> WasmImportKind {
+    WasmImportKind::from(&self.desc)
+  }
 }
 
 impl Dependency for WasmImportDependency {
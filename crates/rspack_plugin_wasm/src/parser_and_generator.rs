@@ -13,7 +13,7 @@ use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnostic
 use rspack_identifier::Identifier;
 use wasmparser::{Import, Parser, Payload};
 
-use crate::dependency::WasmImportDependency;
+use crate::dependency::{WasmImportDependency, WasmImportKind};
 use crate::ModuleIdToFileName;
 
 #[derive(Debug)]
@@ -143,7 +143,8 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         runtime_requirements.insert(RuntimeGlobals::INSTANTIATE_WASM);
 
         let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
-        let mut wasm_deps_by_request = IndexMap::<&str, Vec<(Identifier, String)>>::new();
+        let mut wasm_deps_by_request =
+          IndexMap::<&str, Vec<(Identifier, String, WasmImportKind)>>::new();
         let mut promises: Vec<String> = vec![];
 
         let module_graph = &compilation.module_graph;
@@ -182,7 +183,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
 
                 let dep_name = serde_json::to_string(dep.name()).expect("should be ok.");
                 let request = dep.request();
-                let val = (mgm.module_identifier, dep_name);
+                let val = (mgm.module_identifier, dep_name, dep.kind());
                 if let Some(deps) = wasm_deps_by_request.get_mut(&request) {
                   deps.push(val);
                 } else {
@@ -203,10 +204,10 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
           .map(|(request, deps)| {
             let deps = deps
               .into_iter()
-              .map(|(id, name)| {
+              .map(|(id, name, kind)| {
                 let import_var = dep_modules.get(&id).expect("should be ok");
                 let import_var = &import_var.0;
-                format!("{name}: {import_var}[{name}]")
+                render_import_obj_item(&name, import_var, kind)
               })
               .collect::<Vec<_>>()
               .join(",\n");
@@ -292,6 +293,27 @@ fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
+fn render_import_obj_item(name: &str, import_var: &str, kind: WasmImportKind) -> String {
+  match kind {
+    // a mutable global has to be imported as a `WebAssembly.Global`, sharing the same object
+    // keeps the binding live in both directions, plain values are wrapped into a new one
+    WasmImportKind::Global {
+      value_type: Some(value_type),
+      mutable: true,
+    } => {
+      let value = if value_type == "i64" {
+        "BigInt(value)"
+      } else {
+        "value"
+      };
+      format!(
+        "{name}: (function (value) {{ return value instanceof WebAssembly.Global ? value : new WebAssembly.Global({{ value: \"{value_type}\", mutable: true }}, {value}); }})({import_var}[{name}])"
+      )
+    }
+    _ => format!("{name}: {import_var}[{name}]"),
+  }
+}
+
 fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
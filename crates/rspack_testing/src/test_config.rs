@@ -442,6 +442,7 @@ impl TestConfig {
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
         provided_exports: self.optimization.provided_exports,
         used_exports: c::UsedExportsOption::from(self.optimization.used_exports.as_str()),
+        exports_usage_report: None,
       },
       profile: false,
     };
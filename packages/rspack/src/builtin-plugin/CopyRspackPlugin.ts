@@ -1,12 +1,18 @@
 import { RawCopyPattern, RawCopyRspackPluginOptions } from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 
+export type CopyTransformFunction = (
+	input: Buffer,
+	absoluteFilename: string
+) => string | Buffer | Promise<string | Buffer>;
+
 export type CopyRspackPluginOptions = {
 	patterns: (
 		| string
 		| ({
 				from: string;
-		  } & Partial<RawCopyPattern>)
+				transform?: CopyTransformFunction;
+		  } & Partial<Omit<RawCopyPattern, "transform">>)
 	)[];
 };
 
@@ -27,7 +33,13 @@ export const CopyRspackPlugin = create(
 			pattern.priority ??= 0;
 			pattern.globOptions ??= {};
 
-			return pattern as RawCopyPattern;
+			const { transform, ...rest } = pattern;
+			return {
+				...rest,
+				transform: transform
+					? ({ input, absoluteFilename }) => transform(input, absoluteFilename)
+					: undefined
+			} as RawCopyPattern;
 		});
 
 		return ret;
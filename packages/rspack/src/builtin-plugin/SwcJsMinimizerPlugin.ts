@@ -10,15 +10,25 @@ type MinifyConditions = MinifyCondition | MinifyCondition[];
 export type SwcJsMinimizerRspackPluginOptions = {
 	passes?: number;
 	dropConsole?: boolean;
+	dropDebugger?: boolean;
 	keepClassNames?: boolean;
 	keepFnNames?: boolean;
 	pureFuncs?: Array<string>;
 	extractComments?: boolean | RegExp;
 	comments?: false | "all" | "some";
-	asciiOnly?: boolean;
+	asciiOnly?: boolean | "smart";
+	beautify?: boolean;
 	test?: MinifyConditions;
 	exclude?: MinifyConditions;
 	include?: MinifyConditions;
+	externalSources?: MinifyConditions;
+	parallelism?: number;
+	banner?: string;
+	topLevel?: boolean;
+	reserved?: Array<string>;
+	inlineSourcesContentMaxBytes?: number;
+	ecma?: number;
+	downlevel?: boolean;
 };
 
 function getRawSwcJsMinimizerRule(
@@ -54,6 +64,13 @@ function getRawSwcJsMinimizerRules(
 	return getRawSwcJsMinimizerRule(condition);
 }
 
+function getRawAsciiOnly(
+	asciiOnly: SwcJsMinimizerRspackPluginOptions["asciiOnly"]
+): RawSwcJsMinimizerRspackPluginOptions["asciiOnly"] {
+	if (asciiOnly === "smart") return "smart";
+	return asciiOnly ? "true" : "false";
+}
+
 export const SwcJsMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcJsMinimizerRspackPlugin,
 	(
@@ -62,17 +79,27 @@ export const SwcJsMinimizerRspackPlugin = create(
 		return {
 			passes: options?.passes ?? 1,
 			dropConsole: options?.dropConsole ?? false,
+			dropDebugger: options?.dropDebugger ?? true,
 			keepClassNames: options?.keepClassNames ?? false,
 			keepFnNames: options?.keepFnNames ?? false,
 			pureFuncs: options?.pureFuncs ?? [],
 			comments: options?.comments ? options.comments : "false",
-			asciiOnly: options?.asciiOnly ?? false,
+			asciiOnly: getRawAsciiOnly(options?.asciiOnly),
+			beautify: options?.beautify ?? false,
 			extractComments: options?.extractComments
 				? String(options.extractComments)
 				: undefined,
 			test: getRawSwcJsMinimizerRules(options?.test),
 			include: getRawSwcJsMinimizerRules(options?.include),
-			exclude: getRawSwcJsMinimizerRules(options?.exclude)
+			exclude: getRawSwcJsMinimizerRules(options?.exclude),
+			externalSources: getRawSwcJsMinimizerRules(options?.externalSources),
+			parallelism: options?.parallelism,
+			banner: options?.banner,
+			topLevel: options?.topLevel,
+			reserved: options?.reserved ?? [],
+			inlineSourcesContentMaxBytes: options?.inlineSourcesContentMaxBytes,
+			ecma: options?.ecma,
+			downlevel: options?.downlevel ?? false
 		};
 	}
 );
@@ -8,6 +8,7 @@ import type {
 	RawAssetGeneratorOptions,
 	RawParserOptions,
 	RawAssetParserOptions,
+	RawJavascriptParserOptions,
 	RawAssetParserDataUrl,
 	RawAssetGeneratorDataUrl,
 	RawAssetInlineGeneratorOptions,
@@ -45,6 +46,7 @@ import {
 	AssetResourceGeneratorOptions,
 	AssetParserDataUrl,
 	AssetParserOptions,
+	JavascriptParserOptions,
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	IncrementalRebuildOptions,
@@ -535,11 +537,27 @@ function getRawParserOptions(
 			asset: getRawAssetParserOptions(parser)
 		};
 	}
+	if (type === "javascript" || type.startsWith("javascript/")) {
+		return {
+			type: "javascript",
+			javascript: getRawJavascriptParserOptions(parser)
+		};
+	}
 	return {
 		type: "unknown"
 	};
 }
 
+function getRawJavascriptParserOptions(
+	parser: JavascriptParserOptions
+): RawJavascriptParserOptions {
+	return {
+		dynamicImportMode: parser.dynamicImportMode,
+		worker: parser.worker,
+		exprContextCritical: parser.exprContextCritical
+	};
+}
+
 function getRawAssetParserOptions(
 	parser: AssetParserOptions
 ): RawAssetParserOptions {
@@ -648,6 +666,7 @@ function getRawOptimization(
 	assert(
 		!isNil(optimization.moduleIds) &&
 			!isNil(optimization.chunkIds) &&
+			!isNil(optimization.workerChunkIds) &&
 			!isNil(optimization.removeAvailableModules) &&
 			!isNil(optimization.removeEmptyChunks) &&
 			!isNil(optimization.sideEffects) &&
@@ -658,6 +677,7 @@ function getRawOptimization(
 	);
 	return {
 		chunkIds: optimization.chunkIds,
+		workerChunkIds: optimization.workerChunkIds,
 		splitChunks: toRawSplitChunksOptions(optimization.splitChunks),
 		moduleIds: optimization.moduleIds,
 		removeAvailableModules: optimization.removeAvailableModules,
@@ -665,7 +685,8 @@ function getRawOptimization(
 		sideEffects: String(optimization.sideEffects),
 		realContentHash: optimization.realContentHash,
 		usedExports: String(optimization.usedExports),
-		providedExports: optimization.providedExports
+		providedExports: optimization.providedExports,
+		exportsUsageReport: optimization.exportsUsageReport
 	};
 }
 
@@ -683,6 +683,7 @@ const applyOptimizationDefaults = (
 		return "named";
 	});
 	F(optimization, "chunkIds", (): "named" | "deterministic" => "named");
+	D(optimization, "workerChunkIds", "default");
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "providedExports", true);
 	D(optimization, "usedExports", production);
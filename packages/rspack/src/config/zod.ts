@@ -444,8 +444,19 @@ const assetParserOptions = z.strictObject({
 });
 export type AssetParserOptions = z.infer<typeof assetParserOptions>;
 
+const javascriptParserOptions = z.strictObject({
+	dynamicImportMode: z.enum(["lazy", "eager"]).optional(),
+	worker: z.array(z.string()).optional(),
+	exprContextCritical: z.boolean().optional()
+});
+export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
+
 const parserOptionsByModuleTypeKnown = z.strictObject({
-	asset: assetParserOptions.optional()
+	asset: assetParserOptions.optional(),
+	javascript: javascriptParserOptions.optional(),
+	"javascript/auto": javascriptParserOptions.optional(),
+	"javascript/dynamic": javascriptParserOptions.optional(),
+	"javascript/esm": javascriptParserOptions.optional()
 });
 export type ParserOptionsByModuleTypeKnown = z.infer<
 	typeof parserOptionsByModuleTypeKnown
@@ -911,6 +922,7 @@ export type OptimizationSplitChunksOptions = z.infer<
 const optimization = z.strictObject({
 	moduleIds: z.enum(["named", "deterministic"]).optional(),
 	chunkIds: z.enum(["named", "deterministic"]).optional(),
+	workerChunkIds: z.enum(["default", "url"]).optional(),
 	minimize: z.boolean().optional(),
 	minimizer: z.literal("...").or(plugin).array().optional(),
 	splitChunks: optimizationSplitChunksOptions.optional(),
@@ -920,7 +932,8 @@ const optimization = z.strictObject({
 	realContentHash: z.boolean().optional(),
 	sideEffects: z.enum(["flag"]).or(z.boolean()).optional(),
 	providedExports: z.boolean().optional(),
-	usedExports: z.enum(["global"]).or(z.boolean()).optional()
+	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
+	exportsUsageReport: z.string().optional()
 });
 export type Optimization = z.infer<typeof optimization>;
 //#endregion
@@ -151,3 +151,21 @@ it("should emit warnings for resolve failure in esm", async () => {
 		}
 	`);
 });
+
+it("should point at the import for a failed named import", async () => {
+	const { errors } = await compile({
+		entry: "./resolve-fail-named-import"
+	});
+	expect(errors).toHaveLength(1);
+	expect(errors[0].message).toContain("Failed to resolve missing");
+	expect(errors[0].formatted).toContain(
+		"tests/fixtures/errors/resolve-fail-named-import/index.js:2:1"
+	);
+	// the whole import declaration is underlined, up to and including the semicolon
+	expect(errors[0].formatted).toContain(
+		[
+			'2 │ import { x } from "missing";',
+			`  │ ${"^".repeat(28)} Failed to resolve missing`
+		].join("\n")
+	);
+});
@@ -0,0 +1 @@
+module.exports = "ENSURED_A";
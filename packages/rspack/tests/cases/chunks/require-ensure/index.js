@@ -0,0 +1,29 @@
+it("should load the dependencies of require.ensure in a separate chunk", function (done) {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	require.ensure(["./a"], function (require) {
+		expect(require("./a")).toBe("ENSURED" + "_A");
+		expect(require("./b")).toBe("b");
+		expect(content).not.toContain("ENSURED" + "_A");
+		done();
+	});
+});
+
+it("should accept a single request, an arrow function and a chunk name", function (done) {
+	require.ensure(
+		"./c",
+		require => {
+			expect(require("./c")).toBe("c");
+			done();
+		},
+		function (err) {
+			done(err);
+		},
+		"ensured"
+	);
+});
+
+it("should call the callback of an empty require.ensure", function (done) {
+	require.ensure([], function () {
+		done();
+	});
+});
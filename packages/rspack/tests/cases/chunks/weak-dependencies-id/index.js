@@ -0,0 +1,6 @@
+it("should resolve the id of a module only referenced weakly", function () {
+	var id = require.resolveWeak("./a");
+	expect(id).not.toBe(null);
+	expect(typeof id === "string" || typeof id === "number").toBe(true);
+	expect(__webpack_modules__[id]).toBe(undefined);
+});
@@ -0,0 +1,10 @@
+function load(lang, region) {
+	return import(`./locales/${lang}-${region}.json`);
+}
+
+it("should load a module of a context with several interpolations", async () => {
+	const en = await load("en", "US");
+	expect(en.hello).toBe("Hello");
+	const de = await load("de", "DE");
+	expect(de.hello).toBe("Hallo");
+});
@@ -1,3 +1,23 @@
+it("should create a context from import.meta.webpackContext", function() {
+	const contextRequire = import.meta.webpackContext("./dir");
+	expect(contextRequire.keys()).toContain("./four.js");
+	expect(contextRequire("./four.js")).toBe(4);
+});
+
+it("should respect recursive and regExp", function() {
+	const contextRequire = import.meta.webpackContext(".", {
+		recursive: false,
+		regExp: /two/
+	});
+	const keys = contextRequire.keys();
+	expect(keys).toContain("./two.js");
+	expect(keys).toContain("./two-three.js");
+	expect(keys).not.toContain("./index.js");
+	expect(keys).not.toContain("./dir/four.js");
+	expect(contextRequire("./two")).toBe(2);
+	expect(contextRequire("./two-three")).toBe(3);
+});
+
 // it("should allow prefetch/preload", function() {
 // 	const contextRequire = import.meta.webpackContext("./dir", {
 // 		prefetch: true,
@@ -6,17 +26,17 @@
 // 	expect(contextRequire("./four")).toBe(4);
 // });
 
-// it("should allow include/exclude", function() {
-// 	const contextRequire = import.meta.webpackContext(".", {
-// 		recursive: false,
-// 		regExp: /two/,
-// 		mode: "weak",
-// 		exclude: /three/
-// 	});
-// 	expect(function() {
-// 		contextRequire("./two-three")
-// 	}).toThrowError(/Cannot find module/);
-// });
+it("should allow include/exclude", function() {
+	const contextRequire = import.meta.webpackContext(".", {
+		recursive: false,
+		regExp: /two/,
+		mode: "weak",
+		exclude: /three/
+	});
+	expect(function() {
+		contextRequire("./two-three")
+	}).toThrowError(/Cannot find module/);
+});
 
 // it("should allow chunkName", function() {
 // 	const contextRequire = import.meta.webpackContext(".", {
@@ -0,0 +1,9 @@
+it("should only include modules matching the context regExp", function () {
+	var ctx = require.context("./dir", true, /\.spec\.js$/);
+	expect(ctx.keys().sort()).toEqual(["./a.spec.js", "./nested/b.spec.js"]);
+	expect(ctx("./a.spec.js")).toBe("a");
+	expect(ctx("./nested/b.spec.js")).toBe("b");
+	expect(function () {
+		ctx("./a.js");
+	}).toThrowError();
+});
@@ -0,0 +1,23 @@
+const { pathToFileURL } = require("url");
+const url = pathToFileURL(
+	require("path").resolve("./tests/cases/esm/import-meta-url-standalone/index.js")
+).toString();
+
+it("should rewrite import.meta.url stored in a variable", () => {
+	const base = import.meta.url;
+	expect(base).toBe(url);
+	expect(new URL("./other.js", base).href).toBe(
+		url.replace(/index\.js$/, "other.js")
+	);
+});
+
+it("should rewrite import.meta.url passed alone to new URL", () => {
+	expect(new URL(import.meta.url).href).toBe(url);
+});
+
+it("should rewrite import.meta.url used with a dynamic request", () => {
+	const name = "other.js";
+	expect(new URL(name, import.meta.url).href).toBe(
+		url.replace(/index\.js$/, "other.js")
+	);
+});
@@ -0,0 +1,14 @@
+import "./shared";
+import { a } from "./shared";
+import * as ns from "./shared";
+export { b } from "./shared";
+
+it("should require a module imported in several ways only once", function () {
+	expect(a).toBe("a");
+	expect(ns.b).toBe("b");
+	const source = require("fs").readFileSync(__filename, "utf-8");
+	const requires = source.match(
+		new RegExp("= __webpack_require__\\(/\\*! \\./" + "shared \\*/", "g")
+	);
+	expect(requires).toHaveLength(1);
+});
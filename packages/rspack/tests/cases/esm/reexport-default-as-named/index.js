@@ -0,0 +1,5 @@
+import { Foo } from "./reexport";
+
+it("should re-export the default export under a new name", () => {
+	expect(Foo).toBe("default");
+});
@@ -0,0 +1 @@
+export { default as Foo } from "./m";
@@ -0,0 +1,18 @@
+import D, { named } from "./reexport";
+import * as ns from "./reexport";
+import Imported from "./reexport-imported";
+import Local from "./local";
+
+it("should re-export a named export as the default export", () => {
+	expect(D).toBe("x");
+	expect(ns.default).toBe("x");
+	expect(named).toBe("named");
+});
+
+it("should export an imported binding as the default export", () => {
+	expect(Imported).toBe("y");
+});
+
+it("should export a local binding as the default export", () => {
+	expect(Local).toBe("z");
+});
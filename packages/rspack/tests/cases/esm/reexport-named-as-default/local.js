@@ -0,0 +1,3 @@
+const z = "z";
+
+export { z as default };
@@ -0,0 +1,3 @@
+export default "default";
+export const x = "x";
+export const y = "y";
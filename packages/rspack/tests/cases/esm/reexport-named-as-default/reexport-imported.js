@@ -0,0 +1,3 @@
+import { y } from "./m";
+
+export { y as default };
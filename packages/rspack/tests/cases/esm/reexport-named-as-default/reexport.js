@@ -0,0 +1,2 @@
+export { x as default } from "./m";
+export const named = "named";
@@ -0,0 +1,16 @@
+import data from "./data.json" assert { type: "json" };
+import dataWith, { name, nested } from "./data.json" with { type: "json" };
+
+it("should import json with import assertions", function () {
+	expect(data).toEqual({
+		name: "rspack",
+		version: "1.0.0",
+		nested: { value: 42 }
+	});
+});
+
+it("should import json with import attributes", function () {
+	expect(dataWith).toBe(data);
+	expect(name).toBe("rspack");
+	expect(nested.value).toBe(42);
+});
@@ -0,0 +1,6 @@
+const lib = require("./lib");
+
+it("should decorate the commonjs module that reads module.id", function () {
+	expect(lib.id).toBe(require.resolve("./lib"));
+	expect(typeof __webpack_require__.nmd).toBe("function");
+});
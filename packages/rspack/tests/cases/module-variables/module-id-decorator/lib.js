@@ -0,0 +1 @@
+exports.id = module.id;
@@ -0,0 +1,5 @@
+it("should add the public path runtime for __webpack_public_path__", function () {
+	expect(__webpack_public_path__).toBe("/assets/");
+	const source = require("fs").readFileSync(__filename, "utf-8");
+	expect(source).toContain("__webpack_require__.p = " + '"/assets/"');
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	output: {
+		publicPath: "/assets/"
+	}
+};
@@ -0,0 +1,15 @@
+import { tag, styled } from "./tag";
+
+it("should call an imported tag without the namespace as this", function () {
+	expect(tag`a`).toEqual([undefined, "a"]);
+});
+
+it("should call a member tag on its object", function () {
+	const [self, value] = styled.div`c`;
+	expect(self).toBe(styled);
+	expect(value).toBe("c");
+});
+
+it("should handle nested tagged templates", function () {
+	expect(tag`d${tag`e`[1]}f`).toEqual([undefined, "df"]);
+});
@@ -0,0 +1,9 @@
+export function tag(strings) {
+	return [this, strings.join("")];
+}
+
+export const styled = {
+	div(strings) {
+		return [this, strings.join("")];
+	}
+};
@@ -0,0 +1,12 @@
+it("should load a module with System.import like import()", function () {
+	return System.import("./a").then(function (a) {
+		expect(a.default).toBe("a");
+	});
+});
+
+it("should load a context with a dynamic System.import", function () {
+	var name = "b";
+	return System.import("./" + name).then(function (b) {
+		expect(b.default).toBe("b");
+	});
+});
@@ -0,0 +1,13 @@
+it("should keep import() with webpackIgnore untouched", function () {
+	const load = () => import(/* webpackIgnore: true */ "./does-not-exist.js");
+	expect(load.toString()).toContain("import(");
+	expect(load.toString()).toContain("./does-not-exist.js");
+});
+
+it("should only respect webpackIgnore right before the request", function () {
+	return import(/* webpackIgnore: true */ /* webpackChunkName: "other" */ "./other").then(
+		function (other) {
+			expect(other.default).toBe("other");
+		}
+	);
+});
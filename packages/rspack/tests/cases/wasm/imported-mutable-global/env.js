@@ -0,0 +1,2 @@
+export const counter = new WebAssembly.Global({ value: "i32", mutable: true }, 1);
+export const start = 5;
@@ -0,0 +1,9 @@
+import { counter } from "./env.js";
+
+it("should import mutable globals as live bindings", function () {
+	return import("./module.wat").then(function ({ inc, getStart }) {
+		inc();
+		expect(counter.value).toBe(2);
+		expect(getStart()).toBe(6);
+	});
+});
@@ -0,0 +1,13 @@
+(module
+  (import "./env.js" "counter" (global (mut i32)))
+  (import "./env.js" "start" (global (mut i32)))
+
+  (func (export "inc")
+    (set_global 0 (i32.add (get_global 0) (i32.const 1)))
+    (set_global 1 (i32.add (get_global 1) (i32.const 1)))
+  )
+
+  (func (export "getStart") (result i32)
+    (get_global 1)
+  )
+)
@@ -0,0 +1 @@
+module.exports = "你好，world!";
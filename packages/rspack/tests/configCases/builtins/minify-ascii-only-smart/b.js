@@ -0,0 +1 @@
+globalThis.问候 = "你好，world!";
@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+
+it("[minify-ascii-only-smart]: keep unicode strings when identifiers are ascii", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.js"), "utf-8");
+
+	expect(content).toContain("你好，world!");
+});
+
+it("[minify-ascii-only-smart]: escape files with non-ascii identifiers", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "b.js"), "utf-8");
+
+	expect(content).toContain("\\u95EE\\u5019");
+	expect(content).toContain("\\u4F60\\u597D\\uFF0Cworld!");
+});
@@ -0,0 +1,15 @@
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b",
+		main: "./index"
+	},
+	builtins: {
+		minifyOptions: {
+			asciiOnly: "smart"
+		}
+	},
+	optimization: {
+		minimize: true
+	}
+};
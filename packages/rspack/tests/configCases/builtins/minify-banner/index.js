@@ -0,0 +1,6 @@
+const fs = require("fs");
+
+it("should prepend the banner with placeholders resolved to the chunk", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.startsWith("/*! chunk: main */\n")).toBe(true);
+});
@@ -0,0 +1,11 @@
+module.exports = {
+	builtins: {
+		minifyOptions: {
+			banner: "/*! chunk: [name] */",
+			comments: "some"
+		}
+	},
+	optimization: {
+		minimize: true
+	}
+};
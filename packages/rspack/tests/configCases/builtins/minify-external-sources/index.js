@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should only inline the sources of the files not matched by externalSources", () => {
+	const readMap = name =>
+		JSON.parse(fs.readFileSync(path.resolve(__dirname, name), "utf-8"));
+
+	const vendorMap = readMap("vendor.js.map");
+	expect(vendorMap.sources.some(source => source.endsWith("vendor.js"))).toBe(
+		true
+	);
+	const vendorContents = vendorMap.sourcesContent || [];
+	expect(vendorContents.every(content => !content)).toBe(true);
+
+	const mainMap = readMap("main.js.map");
+	const index = mainMap.sources.findIndex(source =>
+		source.endsWith("index.js")
+	);
+	expect(mainMap.sourcesContent[index]).toContain("externalSources");
+});
@@ -0,0 +1,3 @@
+module.exports = function vendor() {
+	return "vendor";
+};
@@ -0,0 +1,15 @@
+module.exports = {
+	entry: {
+		main: "./index",
+		vendor: "./vendor"
+	},
+	devtool: "source-map",
+	builtins: {
+		minifyOptions: {
+			externalSources: /vendor/
+		}
+	},
+	optimization: {
+		minimize: true
+	}
+};
@@ -0,0 +1,19 @@
+const fs = require("fs");
+
+console.debug("test-console");
+console.info("test-console");
+
+function getTestLogLevels(content) {
+	const regex = /console\.(\S+?)\("test-console"/g;
+	const logs = [];
+	let match;
+	while ((match = regex.exec(content)) !== null) {
+		logs.push(match[1]);
+	}
+	return logs;
+}
+
+it("should drop calls to pure funcs whose results are unused", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(getTestLogLevels(content)).toEqual(["info"]);
+});
@@ -0,0 +1,10 @@
+module.exports = {
+	builtins: {
+		minifyOptions: {
+			pureFuncs: ["console.debug"]
+		}
+	},
+	optimization: {
+		minimize: true
+	}
+};
@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the assets marked as minimized byte-identical", () => {
+	const content = fs.readFileSync(
+		path.resolve(__dirname, "vendor.js"),
+		"utf-8"
+	);
+	expect(content).toBe(
+		"/* already minified */\nfunction vendor(longParameterName) {\n\treturn longParameterName;\n}\n"
+	);
+});
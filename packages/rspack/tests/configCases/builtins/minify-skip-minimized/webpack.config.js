@@ -0,0 +1,35 @@
+const { RawSource } = require("webpack-sources");
+
+const VENDOR = `/* already minified */
+function vendor(longParameterName) {
+	return longParameterName;
+}
+`;
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.compilation.tap("Test", compilation => {
+					compilation.hooks.processAssets.tap(
+						{
+							name: "Test",
+							stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_ADDITIONAL
+						},
+						() => {
+							compilation.emitAsset("vendor.js", new RawSource(VENDOR), {
+								minimized: true
+							});
+						}
+					);
+				});
+			}
+		}
+	],
+	optimization: {
+		minimize: true
+	}
+};
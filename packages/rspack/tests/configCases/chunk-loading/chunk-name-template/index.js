@@ -0,0 +1,25 @@
+import fs from "fs";
+import { Worker } from "worker_threads";
+
+it("should name lazy context chunks by the chunk name template", function () {
+	var ctx = require.context(
+		/* webpackChunkName: "page-[request]" */ "./pages",
+		false,
+		/\.js$/,
+		"lazy"
+	);
+	return Promise.all([ctx("./a.js"), ctx("./b.js")]).then(function (modules) {
+		expect(modules.map(m => m.default)).toEqual(["a", "b"]);
+		var files = fs.readdirSync(__dirname);
+		expect(files).toContain("page-a-js.js");
+		expect(files).toContain("page-b-js.js");
+	});
+});
+
+it("should name worker chunks by the chunk name template", function () {
+	new Worker(
+		/* webpackChunkName: "worker-[name]" */ new URL("./worker.js", import.meta.url),
+		{ name: "calc" }
+	);
+	expect(fs.readdirSync(__dirname)).toContain("worker-calc.js");
+});
@@ -0,0 +1,10 @@
+/**
+ * @type {import('@rspack/cli').Configuration}
+ */
+module.exports = {
+	target: "node",
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	}
+};
@@ -0,0 +1,3 @@
+onmessage = event => {
+	postMessage("ok");
+};
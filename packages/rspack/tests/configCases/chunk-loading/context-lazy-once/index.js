@@ -0,0 +1,14 @@
+import fs from "fs";
+
+it("should put all lazy-once context elements into one chunk", function () {
+	var ctx = require.context("./dir", false, /\.js$/, "lazy-once");
+	return Promise.all([ctx("./a.js"), ctx("./b.js"), ctx("./c.js")]).then(
+		function (modules) {
+			expect(modules.map(m => m.default)).toEqual(["a", "b", "c"]);
+			var chunks = fs
+				.readdirSync(__dirname)
+				.filter(file => file.endsWith(".chunk.js"));
+			expect(chunks).toHaveLength(1);
+		}
+	);
+});
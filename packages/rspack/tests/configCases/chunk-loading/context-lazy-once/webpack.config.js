@@ -0,0 +1,8 @@
+/**
+ * @type {import('@rspack/cli').Configuration}
+ */
+module.exports = {
+	output: {
+		chunkFilename: "[id].chunk.js"
+	}
+};
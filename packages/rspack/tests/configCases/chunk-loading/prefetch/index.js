@@ -0,0 +1,52 @@
+const prefetchLinks = () =>
+	document.head._children
+		.filter(element => element._type === "link" && element.rel === "prefetch")
+		.map(link => link.href);
+const scripts = () =>
+	document.head._children
+		.filter(element => element._type === "script")
+		.map(script => script.src);
+
+it("should prefetch the children of the entry chunk at startup", () => {
+	expect(prefetchLinks().sort()).toEqual([
+		"https://test.cases/path/prefetched.js",
+		"https://test.cases/path/shared.js"
+	]);
+	expect(document.head._children.every(link => link.as === "script")).toBe(
+		true
+	);
+	expect(scripts()).toEqual([]);
+});
+
+it("should load a prefetched chunk with a script when it is imported", () => {
+	const promise = import(
+		/* webpackChunkName: "prefetched", webpackPrefetch: true */ "./prefetched"
+	);
+	expect(scripts()).toEqual(["https://test.cases/path/prefetched.js"]);
+	__non_webpack_require__("./prefetched.js");
+	return promise.then(({ default: value }) => {
+		expect(value).toBe("prefetched");
+	});
+});
+
+it("should not prefetch a chunk again when another chunk prefetches it", () => {
+	// `prefetched` prefetches `shared` once loaded, it was already prefetched at startup
+	return new Promise(resolve => setTimeout(resolve, 0)).then(() => {
+		expect(prefetchLinks()).toHaveLength(2);
+	});
+});
+
+it("should still load a chunk that was prefetched", () => {
+	const promise = import(
+		/* webpackChunkName: "shared", webpackPrefetch: true */ "./shared"
+	);
+	expect(scripts()).toEqual([
+		"https://test.cases/path/prefetched.js",
+		"https://test.cases/path/shared.js"
+	]);
+	__non_webpack_require__("./shared.js");
+	return promise.then(({ default: value }) => {
+		expect(value).toBe("shared");
+		expect(prefetchLinks()).toHaveLength(2);
+	});
+});
@@ -0,0 +1,4 @@
+export default "prefetched";
+
+export const loadShared = () =>
+	import(/* webpackChunkName: "shared", webpackPrefetch: true */ "./shared");
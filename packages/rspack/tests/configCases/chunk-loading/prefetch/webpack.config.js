@@ -0,0 +1,9 @@
+/**
+ * @type {import('@rspack/cli').Configuration}
+ */
+module.exports = {
+	target: "web",
+	output: {
+		chunkFilename: "[name].js"
+	}
+};
@@ -0,0 +1,4 @@
+it("should resolve new URL() against the url of the chunk", () => {
+	const { href } = new URL("./a.txt", import.meta.url);
+	expect(href).toMatch(/^file:\/\/.*\/public\/a\.txt$/);
+});
@@ -0,0 +1,24 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		assetModuleFilename: "[name][ext]",
+		publicPath: "public/",
+		chunkFormat: "module",
+		chunkLoading: "import",
+		library: {
+			type: "module"
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		outputModule: true
+	}
+};
@@ -0,0 +1,19 @@
+import { filename, dirname } from "./sub/file";
+
+it("should point __filename to the source module", () => {
+	expect(__filename.replace(/\\/g, "/")).toMatch(
+		/configCases\/output-module\/node-dirname\/index\.js$/
+	);
+	expect(filename.replace(/\\/g, "/")).toMatch(
+		/configCases\/output-module\/node-dirname\/sub\/file\.js$/
+	);
+});
+
+it("should point __dirname to the directory of the source module", () => {
+	expect(dirname.replace(/\\/g, "/")).toMatch(
+		/configCases\/output-module\/node-dirname\/sub$/
+	);
+	expect(__dirname.replace(/\\/g, "/")).toMatch(
+		/configCases\/output-module\/node-dirname$/
+	);
+});
@@ -0,0 +1,2 @@
+export const filename = __filename;
+export const dirname = __dirname;
@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "[name].js"
+	},
+	experiments: {
+		outputModule: true
+	}
+};
@@ -0,0 +1,17 @@
+import fs from "fs";
+import path from "path";
+
+it("should resolve import() without loading a chunk", async () => {
+	const { value } = await import("./a");
+	expect(value).toBe("a");
+
+	const name = "b";
+	const { value: contextValue } = await import(`./dir/${name}`);
+	expect(contextValue).toBe("b");
+});
+
+it("should emit a single chunk", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "chunks"))).toBe(false);
+	const main = fs.readFileSync(path.resolve(__dirname, "main.js"), "utf-8");
+	expect(main).not.toContain("__webpack_require__" + ".e(");
+});
@@ -0,0 +1,17 @@
+import fs from "fs";
+import path from "path";
+
+it("should load a chunk for import() with a lazy webpackMode comment", async () => {
+	const { value } = await import(
+		/* webpackMode: "lazy", webpackChunkName: "lazy" */ "./lazy"
+	);
+	expect(value).toBe("lazy");
+	expect(fs.readdirSync(path.resolve(__dirname, "chunks"))).toEqual([
+		"lazy.js"
+	]);
+});
+
+it("should keep import() without a comment in the main chunk", async () => {
+	const { value } = await import("./eager");
+	expect(value).toBe("eager");
+});
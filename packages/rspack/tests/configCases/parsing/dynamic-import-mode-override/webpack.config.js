@@ -0,0 +1,15 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	target: "node",
+	output: {
+		filename: "[name].js",
+		chunkFilename: "chunks/[name].js"
+	},
+	module: {
+		parser: {
+			javascript: {
+				dynamicImportMode: "eager"
+			}
+		}
+	}
+};
@@ -0,0 +1,5 @@
+import * as ns from "./reexport";
+
+it("should hide names that are exported by more than one export star", () => {
+	expect(ns.foo).toBeUndefined();
+});
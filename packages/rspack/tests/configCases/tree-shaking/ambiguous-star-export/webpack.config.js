@@ -0,0 +1,12 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	optimization: {
+		providedExports: true
+	}
+};
@@ -0,0 +1,12 @@
+import * as styles from "./style.module.css";
+
+it("should export the values of :export blocks", () => {
+	expect(styles.primaryColor).toBe("red");
+	expect(styles["secondary-color"]).toBe("#00f");
+	expect(typeof styles.title).toBe("string");
+});
+
+it("should drop the :export values that are never referenced", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("__UNUSED" + "_CSS_EXPORT__");
+});
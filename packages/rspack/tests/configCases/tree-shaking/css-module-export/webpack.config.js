@@ -0,0 +1,9 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	}
+};
@@ -0,0 +1,8 @@
+import data from "./data.json";
+
+it("should only keep the json keys accessed through the default import", () => {
+	expect(data.used).toBe(1);
+	expect(data.nested.deep).toBe(2);
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("__UNUSED" + "_JSON_VALUE__");
+});
@@ -0,0 +1,9 @@
+export default "library";
+
+export const named = "named";
+
+it("should only keep the exports exposed by the library", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).toContain("'default'" + ": function()");
+	expect(content).not.toContain("'named'" + ": function()");
+});
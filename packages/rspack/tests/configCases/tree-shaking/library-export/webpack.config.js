@@ -0,0 +1,16 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	target: "node",
+	output: {
+		library: {
+			type: "commonjs2",
+			export: "default"
+		}
+	},
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	}
+};
@@ -0,0 +1,19 @@
+import { used } from "./reexport";
+import { shared } from "./pure/shared";
+
+const fs = require("fs");
+
+// the markers are built at runtime so this file doesn't contain them itself
+const marker = name => `${name}_REEXPORT_SOURCE`;
+
+it("should prune side-effect-free sources of unused re-exports", () => {
+	expect(used).toBe(marker("USED"));
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain(marker("UNUSED"));
+});
+
+it("should keep a re-exported source that is also imported elsewhere", () => {
+	expect(shared).toBe(marker("SHARED"));
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(marker("SHARED"));
+});
@@ -0,0 +1 @@
+export const shared = "SHARED_REEXPORT_SOURCE";
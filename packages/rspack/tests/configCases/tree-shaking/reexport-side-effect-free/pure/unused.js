@@ -0,0 +1 @@
+export const unused = "UNUSED_REEXPORT_SOURCE";
@@ -0,0 +1 @@
+export const used = "USED_REEXPORT_SOURCE";
@@ -0,0 +1,3 @@
+export { unused } from "./pure/unused";
+export { shared } from "./pure/shared";
+export { used } from "./pure/used";
@@ -0,0 +1,22 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /pure/,
+				sideEffects: false
+			}
+		]
+	},
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	optimization: {
+		sideEffects: true,
+		providedExports: true,
+		usedExports: true
+	}
+};
@@ -0,0 +1,9 @@
+import { MyWorker } from "./my-worker";
+
+it("should create a worker chunk for a custom worker syntax", () => {
+	const worker = new MyWorker(new URL("./a.js", import.meta.url));
+	worker.terminate();
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/worker import \*\//);
+});
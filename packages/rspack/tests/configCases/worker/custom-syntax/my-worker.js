@@ -0,0 +1,3 @@
+import { Worker } from "worker_threads";
+
+export class MyWorker extends Worker {}
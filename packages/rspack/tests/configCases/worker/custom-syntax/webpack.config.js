@@ -0,0 +1,11 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	target: "node",
+	module: {
+		parser: {
+			javascript: {
+				worker: ["...", "MyWorker from ./my-worker"]
+			}
+		}
+	}
+};
@@ -0,0 +1,9 @@
+import { Worker } from "node:worker_threads";
+
+it("should recognize Worker imported from node:worker_threads", () => {
+	const worker = new Worker(new URL("./a.js", import.meta.url));
+	worker.terminate();
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/worker import \*\//);
+});
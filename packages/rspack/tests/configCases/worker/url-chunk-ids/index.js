@@ -0,0 +1,11 @@
+import { Worker } from "worker_threads";
+
+it("should derive worker chunk ids from the url request", () => {
+	new Worker(new URL("./a.js", import.meta.url));
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const match = /worker import \*\/.*?\("(\w+)"\)/.exec(source);
+	expect(match).not.toBe(null);
+	// numberHash("./a.js" + salt 10, range 1000), independent of the module graph
+	expect(match[1]).toBe("272");
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function (i) {
+		return `./bundle${i}.js`;
+	}
+};
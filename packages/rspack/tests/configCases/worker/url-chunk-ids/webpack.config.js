@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		target: "node",
+		output: {
+			filename: "bundle0.js",
+			chunkFilename: "[id].bundle0.js"
+		},
+		optimization: {
+			chunkIds: "named",
+			workerChunkIds: "url"
+		}
+	},
+	{
+		// unrelated modules and another chunk id algorithm must not move the worker chunk id
+		target: "node",
+		entry: ["./unrelated.js", "./index.js"],
+		output: {
+			filename: "bundle1.js",
+			chunkFilename: "[id].bundle1.js"
+		},
+		optimization: {
+			chunkIds: "deterministic",
+			workerChunkIds: "url"
+		}
+	}
+];
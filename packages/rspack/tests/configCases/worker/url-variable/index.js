@@ -0,0 +1,22 @@
+import { Worker } from "worker_threads";
+
+it("should create a worker chunk for a url bound to a variable", () => {
+	const url = new URL("./a.js", import.meta.url);
+	const worker = new Worker(url);
+	worker.terminate();
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/const url = new URL\(\/\* worker import \*\//);
+});
+
+it("should not treat a reassigned url as a worker", () => {
+	function createWorker() {
+		let url = new URL("./a.js", import.meta.url);
+		url = new URL("./b.js", import.meta.url);
+		return new Worker(url);
+	}
+	expect(typeof createWorker).toBe("function");
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/let url = new URL\(\/\* asset import \*\//);
+});
@@ -1,5 +1,6 @@
 import path from "path";
 import fs from "fs";
+import os from "os";
 
 import { rspack } from "../../src";
 
@@ -96,6 +97,38 @@ describe("CopyPlugin", () => {
 				.catch(done);
 		});
 
+		it("should copy files using glob with alternatives", done => {
+			runEmit({
+				expectedAssetKeys: [
+					"directory/nested/deep-nested/deepnested.txt",
+					"directory/nested/nestedfile.txt"
+				],
+				patterns: [
+					{
+						from: "directory/{nested,nested/deep-nested,missing}/*.txt"
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should copy files using glob with negation", done => {
+			runEmit({
+				expectedAssetKeys: [
+					"directory/directoryfile.txt",
+					"directory/nested/deep-nested/deepnested.txt"
+				],
+				patterns: [
+					{
+						from: "directory/**/!(nestedfile.txt)"
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
 		it("should copy files using glob to new directory", done => {
 			runEmit({
 				expectedAssetKeys: [
@@ -375,6 +408,306 @@ describe("CopyPlugin", () => {
 				.catch(done);
 		});
 
+		it("should transform file content", done => {
+			runEmit({
+				expectedAssetKeys: ["file.txt"],
+				expectedAssetContent: {
+					"file.txt": "newchanged"
+				},
+				patterns: [
+					{
+						from: "file.txt",
+						transform(input, absoluteFilename) {
+							expect(absoluteFilename).toBe(
+								path.join(FIXTURES_DIR, "file.txt")
+							);
+							return `${input.toString()}changed`;
+						}
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should transform file content asynchronously", done => {
+			runEmit({
+				expectedAssetKeys: ["file.txt"],
+				expectedAssetContent: {
+					"file.txt": "newchanged!"
+				},
+				patterns: [
+					{
+						from: "file.txt",
+						async transform(input) {
+							return Buffer.from(`${input.toString()}changed!`);
+						}
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		(process.platform === "win32" ? it.skip : it)(
+			"should copy files from symlinked directories",
+			done => {
+				runEmit({
+					symlink: true,
+					expectedAssetKeys: [
+						"directory-ln/file.txt",
+						"directory-ln/nested-directory/file-in-nested-directory.txt",
+						"directory/file.txt",
+						"directory/nested-directory/file-in-nested-directory.txt",
+						"file-ln.txt",
+						"file.txt"
+					],
+					patterns: [
+						{
+							from: "symlink",
+							globOptions: {
+								followSymbolicLinks: true
+							}
+						}
+					]
+				})
+					.then(done)
+					.catch(done);
+			}
+		);
+
+		(process.platform === "win32" ? it.skip : it)(
+			"should not copy files from symlinked directories when followSymbolicLinks is false",
+			done => {
+				runEmit({
+					symlink: true,
+					expectedAssetKeys: [
+						"directory/file.txt",
+						"directory/nested-directory/file-in-nested-directory.txt",
+						"file-ln.txt",
+						"file.txt"
+					],
+					patterns: [
+						{
+							from: "symlink",
+							globOptions: {
+								followSymbolicLinks: false
+							}
+						}
+					]
+				})
+					.then(done)
+					.catch(done);
+			}
+		);
+
+		it("should copy files to a destination returned by the 'to' function", done => {
+			runEmit({
+				expectedAssetKeys: [
+					"flattened/deepnested.txt",
+					"flattened/nestedfile.txt"
+				],
+				patterns: [
+					{
+						from: "directory/nested",
+						to({ context, absoluteFilename }) {
+							expect(context).toBe(path.join(FIXTURES_DIR, "directory/nested"));
+							return `flattened/${path.basename(absoluteFilename)}`;
+						}
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should reject a destination returned by the 'to' function containing '..'", async () => {
+			const compiler = getCompiler({
+				builtins: {
+					copy: {
+						patterns: [
+							{
+								from: "file.txt",
+								to() {
+									return "../file.txt";
+								}
+							}
+						]
+					}
+				}
+			});
+
+			const { stats } = await compile(compiler);
+			const { errors } = stats.toJson({ errors: true });
+
+			expect(errors).toHaveLength(1);
+			expect(errors[0].message).toContain("is outside of");
+			expect(Object.keys(readAssets(compiler, stats))).not.toContain(
+				"file.txt"
+			);
+		});
+
+		it("should reject an absolute destination returned by the 'to' function outside of the output path", async () => {
+			const compiler = getCompiler({
+				builtins: {
+					copy: {
+						patterns: [
+							{
+								from: "file.txt",
+								to() {
+									return path.join(os.tmpdir(), "file.txt");
+								}
+							}
+						]
+					}
+				}
+			});
+
+			const { stats } = await compile(compiler);
+			const { errors } = stats.toJson({ errors: true });
+
+			expect(errors).toHaveLength(1);
+			expect(errors[0].message).toContain("is outside of");
+		});
+
+		it("should warn when an existing directory has nothing to copy", async () => {
+			const emptyDir = fs.mkdtempSync(
+				path.join(os.tmpdir(), "copy-plugin-empty-")
+			);
+			const compiler = getCompiler({
+				builtins: {
+					copy: {
+						patterns: [{ from: emptyDir }]
+					}
+				}
+			});
+
+			const { stats } = await compile(compiler);
+			const { errors, warnings } = stats.toJson({
+				errors: true,
+				warnings: true
+			});
+
+			expect(errors).toHaveLength(0);
+			expect(warnings).toHaveLength(1);
+			expect(warnings[0].message).toContain("matched no files");
+		});
+
+		it("should error when the directory to copy is missing", async () => {
+			const compiler = getCompiler({
+				builtins: {
+					copy: {
+						patterns: [{ from: "assets" }, { from: "assets/**/*.png" }]
+					}
+				}
+			});
+
+			const { stats } = await compile(compiler);
+			const { errors, warnings } = stats.toJson({
+				errors: true,
+				warnings: true
+			});
+
+			expect(errors).toHaveLength(2);
+			expect(errors[0].message).toContain("unable to locate");
+			expect(warnings).toHaveLength(0);
+		});
+
+		it("should neither error nor warn with 'noErrorOnMissing'", done => {
+			runEmit({
+				expectedAssetKeys: [],
+				patterns: [
+					{
+						from: "assets",
+						noErrorOnMissing: true
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should resolve patterns of equal priority by their source paths", async () => {
+			const patterns = [
+				{
+					from: "file.txt",
+					to: "same.txt",
+					force: true
+				},
+				{
+					from: "directory/.dottedfile",
+					to: "same.txt",
+					force: true
+				}
+			];
+
+			// `file.txt` comes last, whatever the order of the patterns
+			for (const ordered of [patterns, [...patterns].reverse()]) {
+				await runEmit({
+					expectedAssetKeys: ["same.txt"],
+					expectedAssetContent: {
+						"same.txt": "new"
+					},
+					patterns: ordered.map(pattern => ({ ...pattern }))
+				});
+			}
+		});
+
+		it("should let the pattern with the higher priority win", done => {
+			runEmit({
+				expectedAssetKeys: ["same.txt"],
+				expectedAssetContent: {
+					"same.txt": "dottedfile contents"
+				},
+				patterns: [
+					{
+						from: "directory/.dottedfile",
+						to: "same.txt",
+						force: true,
+						priority: 10
+					},
+					{
+						from: "file.txt",
+						to: "same.txt",
+						force: true
+					}
+				]
+			})
+				.then(done)
+				.catch(done);
+		});
+
+		it("should not minify a copied asset marked as minimized", async () => {
+			const compiler = getCompiler({
+				mode: "production",
+				builtins: {
+					copy: {
+						patterns: [
+							{
+								from: "vendor/vendor.js",
+								to: "minimized.js",
+								info: { minimized: true }
+							},
+							{
+								from: "vendor/vendor.js",
+								to: "unminimized.js"
+							}
+						]
+					}
+				}
+			});
+
+			const { stats } = await compile(compiler);
+			const assets = readAssets(compiler, stats);
+			const original = fs.readFileSync(
+				path.join(FIXTURES_DIR, "vendor/vendor.js"),
+				"utf-8"
+			);
+
+			expect(assets["minimized.js"]).toBe(original);
+			expect(assets["unminimized.js"]).not.toBe(original);
+		});
+
 		it("should work with multi compiler mode", async () => {
 			const compiler = rspack([
 				{
@@ -678,6 +1011,42 @@ describe("CopyPlugin", () => {
 				.then(done)
 				.catch(done);
 		});
+
+		it("should only write the copied files whose source changed", async () => {
+			const sourceDir = fs.mkdtempSync(
+				path.join(os.tmpdir(), "copy-plugin-source-")
+			);
+			const outputDir = fs.mkdtempSync(
+				path.join(os.tmpdir(), "copy-plugin-output-")
+			);
+			const readOutput = file =>
+				fs.readFileSync(path.join(outputDir, file), "utf-8");
+			fs.writeFileSync(path.join(sourceDir, "a.txt"), "a");
+			fs.writeFileSync(path.join(sourceDir, "b.txt"), "b");
+			const compiler = getCompiler({
+				output: { path: outputDir, clean: true },
+				builtins: {
+					copy: {
+						patterns: [{ from: sourceDir }]
+					}
+				}
+			});
+
+			await compile(compiler);
+			expect(readOutput("a.txt")).toBe("a");
+			expect(readOutput("b.txt")).toBe("b");
+
+			// Tamper with the output, an unchanged source must not write it again
+			fs.writeFileSync(path.join(outputDir, "a.txt"), "untouched");
+			fs.writeFileSync(path.join(sourceDir, "b.txt"), "changed");
+			await compile(compiler);
+			expect(readOutput("a.txt")).toBe("untouched");
+			expect(readOutput("b.txt")).toBe("changed");
+
+			fs.unlinkSync(path.join(sourceDir, "b.txt"));
+			await compile(compiler);
+			expect(fs.existsSync(path.join(outputDir, "b.txt"))).toBe(false);
+		});
 	});
 
 	describe.skip("cache", () => {
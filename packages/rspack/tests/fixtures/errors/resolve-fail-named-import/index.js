@@ -0,0 +1,4 @@
+// the import below can not be resolved
+import { x } from "missing";
+
+x();